@@ -0,0 +1,26 @@
+use std::{env, str::FromStr, time::Duration};
+
+use anyhow::{Context, Result};
+use twitch_oauth_token::{RedirectUrl, TwitchOauth};
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    dotenvy::dotenv()?;
+
+    let client_id = env::var("CLIENT_ID").context("CLIENT_ID environment variable not set")?;
+    let client_secret =
+        env::var("CLIENT_SECRET").context("CLIENT_SECRET environment variable not set")?;
+    let redirect_uri =
+        env::var("REDIRECT_URI").context("REDIRECT_URI environment variable not set")?;
+
+    let oauth = TwitchOauth::new(client_id, client_secret)
+        .with_redirect_uri(RedirectUrl::from_str(&redirect_uri)?);
+
+    let mut login = oauth.interactive_login();
+    login.scopes_mut().chat_api();
+
+    let token = login.with_timeout(Duration::from_secs(120)).run().await?;
+
+    println!("{token:?}");
+    Ok(())
+}
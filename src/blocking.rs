@@ -0,0 +1,296 @@
+//! Synchronous API (requires `blocking` feature)
+//!
+//! [`TwitchOauth`] and [`DeviceAuth`] mirror their async counterparts and return
+//! the same tokens and [`Error`]s. Requests run on a shared single-threaded tokio
+//! runtime, so the caller doesn't need one.
+//!
+//! The blocking methods must not be called from within an async runtime; they
+//! panic there, like [`tokio::runtime::Runtime::block_on`].
+//!
+//! ```no_run
+//! use twitch_oauth_token::blocking::TwitchOauth;
+//!
+//! fn main() -> Result<(), twitch_oauth_token::Error> {
+//!     let oauth = TwitchOauth::new("client_id", "client_secret");
+//!
+//!     let token = oauth.app_access_token()?;
+//!     let info = oauth.validate_access_token(&token.access_token)?;
+//!     println!("Expires in: {} seconds", info.expires_in);
+//!
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Configure with the async builder, then convert:
+//!
+//! ```no_run
+//! use std::str::FromStr;
+//! use twitch_oauth_token::{RedirectUrl, TwitchOauth};
+//!
+//! # fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let oauth = TwitchOauth::new("client_id", "client_secret")
+//!     .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000/auth/callback")?)
+//!     .into_blocking();
+//!
+//! let mut auth_request = oauth.authorization_url();
+//! auth_request.scopes_mut().chat_api();
+//! println!("Visit: {}", auth_request.url());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{future::Future, sync::OnceLock};
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{
+    oauth::OauthFlow,
+    scope::{Scopes, ScopesMut},
+    AccessToken, AppAuth, AppToken, AuthorizationCode, AuthrozationRequest, ClientId,
+    DeviceAuthResponse, Error, RedirectUrl, RefreshToken, TokenInfo, UserAuth, UserToken,
+};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+    RUNTIME
+        .get_or_init(|| {
+            Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build blocking runtime")
+        })
+        .block_on(future)
+}
+
+/// Blocking [`crate::TwitchOauth`]
+#[derive(Debug)]
+pub struct TwitchOauth<Flow = AppAuth>
+where
+    Flow: OauthFlow,
+{
+    inner: crate::TwitchOauth<Flow>,
+}
+
+impl<Flow> TwitchOauth<Flow>
+where
+    Flow: OauthFlow,
+{
+    pub fn client_id(&self) -> &ClientId {
+        self.inner.client_id()
+    }
+
+    /// The async client this wraps
+    pub fn inner(&self) -> &crate::TwitchOauth<Flow> {
+        &self.inner
+    }
+
+    pub fn into_async(self) -> crate::TwitchOauth<Flow> {
+        self.inner
+    }
+
+    /// See [`crate::TwitchOauth::refresh_access_token`]
+    pub fn refresh_access_token(&self, refresh_token: RefreshToken) -> Result<UserToken, Error> {
+        block_on(self.inner.refresh_access_token(refresh_token))
+    }
+
+    /// See [`crate::TwitchOauth::revoke_access_token`]
+    pub fn revoke_access_token(&self, access_token: &AccessToken) -> Result<(), Error> {
+        block_on(self.inner.revoke_access_token(access_token))
+    }
+
+    /// See [`crate::TwitchOauth::app_access_token`]
+    pub fn app_access_token(&self) -> Result<AppToken, Error> {
+        block_on(self.inner.app_access_token())
+    }
+
+    /// See [`crate::TwitchOauth::validate_access_token`]
+    pub fn validate_access_token(&self, access_token: &AccessToken) -> Result<TokenInfo, Error> {
+        block_on(self.inner.validate_access_token(access_token))
+    }
+
+    /// See [`crate::TwitchOauth::device_flow`]
+    pub fn device_flow(&self) -> DeviceAuth {
+        DeviceAuth::from(self.inner.device_flow())
+    }
+}
+
+impl TwitchOauth<AppAuth> {
+    pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+        Self::from(crate::TwitchOauth::new(client_id, client_secret))
+    }
+
+    pub fn with_redirect_uri(self, redirect_uri: RedirectUrl) -> TwitchOauth<UserAuth> {
+        TwitchOauth::from(self.inner.with_redirect_uri(redirect_uri))
+    }
+
+    pub fn device_auth(client_id: ClientId) -> DeviceAuth {
+        DeviceAuth::new(client_id)
+    }
+}
+
+impl TwitchOauth<UserAuth> {
+    /// See [`crate::TwitchOauth::authorization_url`]
+    pub fn authorization_url(&self) -> AuthrozationRequest<'_> {
+        self.inner.authorization_url()
+    }
+
+    /// See [`crate::TwitchOauth::exchange_code`]
+    pub fn exchange_code(
+        &self,
+        code: AuthorizationCode,
+        state: String,
+    ) -> Result<UserToken, Error> {
+        block_on(self.inner.exchange_code(code, state))
+    }
+}
+
+impl<Flow> From<crate::TwitchOauth<Flow>> for TwitchOauth<Flow>
+where
+    Flow: OauthFlow,
+{
+    fn from(inner: crate::TwitchOauth<Flow>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<Flow> Clone for TwitchOauth<Flow>
+where
+    Flow: OauthFlow,
+    crate::TwitchOauth<Flow>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+/// Blocking [`crate::DeviceAuth`]
+#[derive(Debug, Clone)]
+pub struct DeviceAuth {
+    inner: crate::DeviceAuth,
+}
+
+impl DeviceAuth {
+    pub fn new(client_id: impl Into<ClientId>) -> Self {
+        Self::from(crate::DeviceAuth::new(client_id))
+    }
+
+    pub fn with_scopes(self, scopes: impl Into<Scopes>) -> Self {
+        Self::from(self.inner.with_scopes(scopes))
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        self.inner.client_id()
+    }
+
+    pub fn scopes_mut(&mut self) -> ScopesMut<'_> {
+        self.inner.scopes_mut()
+    }
+
+    pub fn into_async(self) -> crate::DeviceAuth {
+        self.inner
+    }
+
+    /// See [`crate::DeviceAuth::request`]
+    pub fn request(&self) -> Result<DeviceAuthResponse, Error> {
+        block_on(self.inner.request())
+    }
+
+    /// Blocks until the user authorizes or the code expires
+    ///
+    /// See [`crate::DeviceAuth::poll`].
+    pub fn poll(&self, response: DeviceAuthResponse) -> Result<UserToken, Error> {
+        block_on(self.inner.poll(response))
+    }
+
+    /// See [`crate::DeviceAuth::resume`]
+    pub fn resume(&self, response: DeviceAuthResponse) -> Result<UserToken, Error> {
+        block_on(self.inner.resume(response))
+    }
+
+    /// See [`crate::DeviceAuth::refresh_access_token`]
+    pub fn refresh_access_token(&self, refresh_token: RefreshToken) -> Result<UserToken, Error> {
+        block_on(self.inner.refresh_access_token(refresh_token))
+    }
+}
+
+impl From<crate::DeviceAuth> for DeviceAuth {
+    fn from(inner: crate::DeviceAuth) -> Self {
+        Self { inner }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpRequest, HttpResponse},
+        ClientId, ClientType, RefreshToken,
+    };
+
+    use super::{DeviceAuth, TwitchOauth};
+
+    fn respond(request: HttpRequest) -> Result<HttpResponse, BoxError> {
+        let body = String::from_utf8(request.body().clone())?;
+        let (status, json) = match request.uri().path() {
+            "/oauth2/device" => (
+                StatusCode::OK,
+                r#"{"device_code":"ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91","expires_in":1800,"interval":0,"user_code":"ABCDEFGH","verification_uri":"https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH"}"#,
+            ),
+            "/oauth2/token" if body.contains("grant_type=client_credentials") => (
+                StatusCode::OK,
+                r#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#,
+            ),
+            "/oauth2/token" => (
+                StatusCode::OK,
+                r#"{"access_token":"rfx2uswqe8l4g1mkagrvg5tv0ks3","expires_in":14124,"refresh_token":"5b93chm6hdve3mycz05zfzatkfdenfspp1h1ar2xxdalen01","scope":["user:read:email"],"token_type":"bearer"}"#,
+            ),
+            _ => (StatusCode::NOT_FOUND, "{}"),
+        };
+
+        let mut response = HttpResponse::new(json.as_bytes().to_vec());
+        *response.status_mut() = status;
+        Ok(response)
+    }
+
+    fn transport() -> impl crate::transport::HttpTransport + 'static {
+        from_fn(|request| async move { respond(request) })
+    }
+
+    #[test]
+    fn app_token() {
+        let oauth = crate::TwitchOauth::new("client_id", "client_secret")
+            .with_transport(transport())
+            .into_blocking();
+
+        let token = oauth.app_access_token().unwrap();
+        assert_eq!(token.access_token.secret(), "jostpf5q0puzmxmkba9iyug38kjtg");
+
+        let err = oauth.revoke_access_token(&token.access_token).unwrap_err();
+        assert_eq!(err.status_code(), Some(404));
+    }
+
+    #[test]
+    fn device_flow() {
+        let device = DeviceAuth::from(
+            crate::DeviceAuth::new(ClientId::from("client_id")).with_transport(transport()),
+        );
+
+        let response = device.request().unwrap();
+        assert_eq!(response.user_code, "ABCDEFGH");
+
+        let token = device.poll(response).unwrap();
+        assert_eq!(token.client_type, ClientType::Public);
+
+        let token = device
+            .refresh_access_token(RefreshToken::from("refresh"))
+            .unwrap();
+        assert_eq!(token.access_token.secret(), "rfx2uswqe8l4g1mkagrvg5tv0ks3");
+
+        let oauth = TwitchOauth::from(crate::TwitchOauth::new("client_id", "client_secret"));
+        assert_eq!(oauth.device_flow().client_id().as_str(), "client_id");
+    }
+}
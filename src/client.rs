@@ -62,14 +62,20 @@
 //! # }
 //!
 //! ```
-use std::sync::OnceLock;
+//!
+//! # Custom Transport
+//!
+//! To send requests through something other than reqwest, configure an
+//! [`HttpTransport`] with [`setup_transport()`] instead. See [`crate::transport`].
+use std::sync::{Arc, OnceLock};
 
 use asknothingx2_util::api::preset;
 use reqwest::Client;
 
-use crate::{error, Error};
+use crate::{error, transport::HttpTransport, Error};
 
 static CLIENT: OnceLock<Client> = OnceLock::new();
+static TRANSPORT: OnceLock<Arc<dyn HttpTransport>> = OnceLock::new();
 
 /// Configure the global HTTP client used for all OAuth requests
 ///
@@ -99,7 +105,7 @@ static CLIENT: OnceLock<Client> = OnceLock::new();
 /// # }
 /// ```
 pub fn setup(client: reqwest::Client) -> Result<(), Error> {
-    if CLIENT.get().is_some() {
+    if CLIENT.get().is_some() || TRANSPORT.get().is_some() {
         return Err(error::client_setup::already_initialized());
     }
 
@@ -122,3 +128,26 @@ pub fn get() -> &'static Client {
         .expect("failed to build default http client")
     })
 }
+
+/// Configure the global transport used for all OAuth requests
+///
+/// Replaces the reqwest client, so it can't be combined with [`setup()`].
+pub fn setup_transport<T>(transport: T) -> Result<(), Error>
+where
+    T: HttpTransport + 'static,
+{
+    if CLIENT.get().is_some() {
+        return Err(error::client_setup::already_initialized());
+    }
+
+    TRANSPORT
+        .set(Arc::new(transport))
+        .map_err(|_| error::client_setup::already_initialized())?;
+
+    Ok(())
+}
+
+/// Get the global transport (the [`get()`] client if not configured)
+pub fn transport() -> Arc<dyn HttpTransport> {
+    TRANSPORT.get_or_init(|| Arc::new(get().clone())).clone()
+}
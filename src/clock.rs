@@ -0,0 +1,144 @@
+//! Clock skew correction
+//!
+//! Token expiry, CSRF state and device code deadlines are computed from
+//! [`now()`], which is local time plus the offset of the global [`ClockSkew`].
+//! The offset is `0` unless a [`TwitchOauth`](crate::TwitchOauth) configured with
+//! [`with_clock_skew(true)`](crate::TwitchOauth::with_clock_skew) has seen a
+//! server `Date` header that disagrees with the local clock.
+//!
+//! ```
+//! use twitch_oauth_token::{clock::ClockSkew, TwitchOauth};
+//!
+//! let oauth = TwitchOauth::new("client_id", "client_secret").with_clock_skew(true);
+//!
+//! // After some requests
+//! println!("local clock is off by {}s", ClockSkew::global().offset());
+//! ```
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Utc};
+
+/// Estimated difference between server and local time
+///
+/// Only [`ClockSkew::global`] affects [`now()`]. `Date` headers have a
+/// resolution of one second and arrive after some network latency, so
+/// differences of up to a second are ignored.
+#[derive(Debug, Default)]
+pub struct ClockSkew {
+    offset: AtomicI64,
+}
+
+static GLOBAL: ClockSkew = ClockSkew::new();
+
+impl ClockSkew {
+    pub const fn new() -> Self {
+        Self {
+            offset: AtomicI64::new(0),
+        }
+    }
+
+    /// The estimator used by [`now()`]
+    pub fn global() -> &'static ClockSkew {
+        &GLOBAL
+    }
+
+    /// Server time minus local time, in seconds
+    pub fn offset(&self) -> i64 {
+        self.offset.load(Ordering::Relaxed)
+    }
+
+    pub fn set_offset(&self, offset: i64) {
+        self.offset.store(offset, Ordering::Relaxed);
+    }
+
+    /// Update the estimate from a server timestamp received just now
+    pub fn observe(&self, server_time: DateTime<Utc>) {
+        let sample = server_time.timestamp() - Utc::now().timestamp();
+        if (sample - self.offset()).abs() > 1 {
+            self.set_offset(sample);
+        }
+    }
+
+    /// Corrected unix timestamp
+    pub fn now(&self) -> i64 {
+        Utc::now().timestamp() + self.offset()
+    }
+}
+
+/// Current unix timestamp, corrected by [`ClockSkew::global`]
+pub fn now() -> i64 {
+    GLOBAL.now()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use chrono::{TimeDelta, Utc};
+    use http::header::DATE;
+
+    use crate::{
+        csrf::CsrfConfig,
+        transport::{from_fn, BoxError, HttpResponse},
+        AuthorizationCode, RedirectUrl, TwitchOauth, UserToken,
+    };
+
+    use super::ClockSkew;
+
+    #[test]
+    fn ignores_jitter() {
+        let skew = ClockSkew::new();
+        skew.observe(Utc::now() - TimeDelta::seconds(1));
+        assert_eq!(skew.offset(), 0);
+
+        skew.observe(Utc::now() + TimeDelta::seconds(90));
+        assert!((89..=91).contains(&skew.offset()));
+        assert!((skew.now() - Utc::now().timestamp() - 90).abs() <= 1);
+    }
+
+    #[tokio::test]
+    async fn skew_corrects_expiry_and_csrf() {
+        let date = (Utc::now() + TimeDelta::minutes(2))
+            .format("%a, %d %b %Y %H:%M:%S GMT")
+            .to_string();
+
+        let oauth = TwitchOauth::new("client_id", "client_secret")
+            .with_clock_skew(true)
+            .with_csrf_config(CsrfConfig::new(0, 60))
+            .with_transport(from_fn(move |_| {
+                let mut resp = HttpResponse::new(b"{}".to_vec());
+                resp.headers_mut().insert(DATE, date.parse().unwrap());
+                async { Ok::<_, BoxError>(resp) }
+            }))
+            .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000").unwrap());
+
+        let token: UserToken = serde_json::from_value(serde_json::json!({
+            "access_token": "jostpf5q0puzmxmkba9iyug38kjtg",
+            "refresh_token": "",
+            "expires_in": 60,
+            "token_type": "bearer",
+            "created_at": Utc::now().timestamp(),
+        }))
+        .unwrap();
+        let (_, state) = oauth.authorization_url().url_with_state();
+        assert!(!token.is_expired());
+
+        oauth.revoke_access_token(&"token".into()).await.unwrap();
+        let offset = ClockSkew::global().offset();
+        let expired = token.is_expired();
+        let result = oauth
+            .exchange_code(AuthorizationCode::from("code"), state)
+            .await;
+        ClockSkew::global().set_offset(0);
+
+        assert!((119..=121).contains(&offset), "{offset}");
+        assert!(expired);
+        let err = result.unwrap_err();
+        assert!(err.is_oauth_error());
+        assert_eq!(
+            err.message(),
+            Some("CSRF token validation failed - possible security issue")
+        );
+    }
+}
@@ -0,0 +1,475 @@
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+};
+
+use tokio::sync::mpsc;
+
+use crate::{
+    error, runtime, transport::BoxFuture, DeviceAuth, DeviceAuthResponse, Error, Scopes, UserToken,
+};
+
+/// Result of a device authorization session, delivered when its poll loop ends
+#[derive(Debug)]
+pub struct DeviceSessionEvent {
+    pub id: String,
+    pub result: Result<UserToken, Error>,
+}
+
+type Callback = Arc<dyn Fn(DeviceSessionEvent) + Send + Sync>;
+
+type Sessions = Arc<Mutex<HashMap<String, Slot>>>;
+
+/// A session and the [`DeviceSessionManager::start`] call that created it
+///
+/// Ids can be reused after a cancel, so a stale task only touches the slot if
+/// the generation still matches.
+struct Slot {
+    generation: u64,
+    session: Session,
+}
+
+enum Session {
+    /// Slot reserved while the device code is being requested
+    Requesting,
+    Polling {
+        response: DeviceAuthResponse,
+        handle: AbortHandle,
+    },
+}
+
+/// Runs multiple device code grant flows in parallel
+///
+/// Each session is identified by an id chosen by the caller, gets its own
+/// [`DeviceAuthResponse`] and poll loop, and counts towards `max_concurrent`
+/// until it finishes or is cancelled. Resulting tokens are delivered through a
+/// channel ([`DeviceSessionManager::new`]) or a callback
+/// ([`DeviceSessionManager::with_callback`]).
+///
+/// Poll loops are spawned on the [`runtime`]; dropping the manager cancels them.
+///
+/// ```no_run
+/// # use twitch_oauth_token::{DeviceSessionManager, TwitchOauth};
+/// # async fn run(oauth: TwitchOauth) -> Result<(), twitch_oauth_token::Error> {
+/// let (manager, mut events) = DeviceSessionManager::new(oauth.device_flow(), 10);
+///
+/// let mut device = oauth.device_flow();
+/// device.scopes_mut().chat_api();
+///
+/// let resp = manager.start("streamer-1", device).await?;
+/// println!("Visit: {}", resp.verification_uri);
+///
+/// while let Some(event) = events.recv().await {
+///     match event.result {
+///         Ok(token) => println!("{} authorized", event.id),
+///         Err(e) => eprintln!("{} failed: {e}", event.id),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct DeviceSessionManager {
+    device: DeviceAuth,
+    max_concurrent: usize,
+    sessions: Sessions,
+    generation: AtomicU64,
+    callback: Callback,
+}
+
+impl DeviceSessionManager {
+    /// Create a manager that delivers results through the returned channel
+    ///
+    /// `device` provides the default configuration and scopes for
+    /// [`DeviceSessionManager::start_with_scopes`].
+    pub fn new(
+        device: DeviceAuth,
+        max_concurrent: usize,
+    ) -> (Self, mpsc::UnboundedReceiver<DeviceSessionEvent>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let manager = Self::with_callback(device, max_concurrent, move |event| {
+            let _ = tx.send(event);
+        });
+
+        (manager, rx)
+    }
+
+    /// Create a manager that delivers results to `callback`
+    ///
+    /// The callback runs on the session's poll task.
+    pub fn with_callback<F>(device: DeviceAuth, max_concurrent: usize, callback: F) -> Self
+    where
+        F: Fn(DeviceSessionEvent) + Send + Sync + 'static,
+    {
+        Self {
+            device,
+            max_concurrent,
+            sessions: Arc::new(Mutex::new(HashMap::new())),
+            generation: AtomicU64::new(0),
+            callback: Arc::new(callback),
+        }
+    }
+
+    pub fn max_concurrent(&self) -> usize {
+        self.max_concurrent
+    }
+
+    /// Request a device code and start polling for it in the background
+    ///
+    /// Returns the [`DeviceAuthResponse`] to show to the user. Fails if `id` is
+    /// already in use, `max_concurrent` sessions are running, or the session is
+    /// cancelled while the device code is being requested.
+    pub async fn start(
+        &self,
+        id: impl Into<String>,
+        device: DeviceAuth,
+    ) -> Result<DeviceAuthResponse, Error> {
+        let id = id.into();
+        let generation = self.reserve(&id)?;
+        // Frees the slot on error and when this future is dropped mid-request.
+        let reservation = Reservation {
+            sessions: &self.sessions,
+            id: &id,
+            generation,
+        };
+
+        let response = device.request().await?;
+
+        self.spawn(id.clone(), generation, device, response.clone())?;
+        reservation.disarm();
+        Ok(response)
+    }
+
+    /// Start a session using the manager's [`DeviceAuth`] with different scopes
+    pub async fn start_with_scopes(
+        &self,
+        id: impl Into<String>,
+        scopes: impl Into<Scopes>,
+    ) -> Result<DeviceAuthResponse, Error> {
+        self.start(id, self.device.clone().with_scopes(scopes))
+            .await
+    }
+
+    /// Continue polling a persisted session with the manager's [`DeviceAuth`]
+    ///
+    /// See [`DeviceAuth::resume`].
+    pub fn resume(&self, id: impl Into<String>, response: DeviceAuthResponse) -> Result<(), Error> {
+        let id = id.into();
+        if response.is_expired() {
+            return Err(error::device_code::timeout());
+        }
+
+        let generation = self.reserve(&id)?;
+        self.spawn(id, generation, self.device.clone(), response)
+    }
+
+    /// Stop a session's poll loop
+    ///
+    /// Returns `false` if no session with this id is running. No event is
+    /// delivered for cancelled sessions.
+    pub fn cancel(&self, id: &str) -> bool {
+        let slot = self.sessions.lock().unwrap().remove(id);
+        match slot.map(|slot| slot.session) {
+            Some(Session::Polling { handle, .. }) => {
+                handle.abort();
+                true
+            }
+            Some(Session::Requesting) => true,
+            None => false,
+        }
+    }
+
+    /// Cancel every running session
+    pub fn cancel_all(&self) {
+        let sessions = std::mem::take(&mut *self.sessions.lock().unwrap());
+        for slot in sessions.into_values() {
+            if let Session::Polling { handle, .. } = slot.session {
+                handle.abort();
+            }
+        }
+    }
+
+    /// Running sessions and the device codes they are waiting on
+    pub fn list(&self) -> Vec<(String, DeviceAuthResponse)> {
+        self.sessions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|(id, slot)| match &slot.session {
+                Session::Polling { response, .. } => Some((id.clone(), response.clone())),
+                Session::Requesting => None,
+            })
+            .collect()
+    }
+
+    pub fn get(&self, id: &str) -> Option<DeviceAuthResponse> {
+        match self
+            .sessions
+            .lock()
+            .unwrap()
+            .get(id)
+            .map(|slot| &slot.session)
+        {
+            Some(Session::Polling { response, .. }) => Some(response.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.sessions.lock().unwrap().contains_key(id)
+    }
+
+    /// Number of sessions counting towards `max_concurrent`
+    pub fn len(&self) -> usize {
+        self.sessions.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn reserve(&self, id: &str) -> Result<u64, Error> {
+        let mut sessions = self.sessions.lock().unwrap();
+
+        if sessions.contains_key(id) {
+            return Err(error::device_code::session_exists(id));
+        }
+
+        if sessions.len() >= self.max_concurrent {
+            return Err(error::device_code::session_limit(self.max_concurrent));
+        }
+
+        let generation = self.generation.fetch_add(1, Ordering::Relaxed);
+        sessions.insert(
+            id.to_string(),
+            Slot {
+                generation,
+                session: Session::Requesting,
+            },
+        );
+        Ok(generation)
+    }
+
+    fn spawn(
+        &self,
+        id: String,
+        generation: u64,
+        device: DeviceAuth,
+        response: DeviceAuthResponse,
+    ) -> Result<(), Error> {
+        let handle = AbortHandle::default();
+
+        match self.sessions.lock().unwrap().get_mut(&id) {
+            Some(slot) if slot.generation == generation => {
+                slot.session = Session::Polling {
+                    response: response.clone(),
+                    handle: handle.clone(),
+                };
+            }
+            // Cancelled while the device code was being requested.
+            _ => return Err(error::device_code::session_cancelled(&id)),
+        }
+
+        let sessions = self.sessions.clone();
+        let callback = self.callback.clone();
+
+        // Spawned without holding the lock, runtimes may poll the task inline.
+        runtime::get().spawn(Box::pin(Abortable {
+            task: Box::pin(async move {
+                let result = device.poll(response).await;
+                if remove(&sessions, &id, generation) {
+                    callback(DeviceSessionEvent { id, result });
+                }
+            }),
+            handle,
+        }));
+
+        Ok(())
+    }
+}
+
+/// Remove the session if it still belongs to `generation`
+fn remove(sessions: &Sessions, id: &str, generation: u64) -> bool {
+    let mut sessions = sessions.lock().unwrap();
+    match sessions.get(id) {
+        Some(slot) if slot.generation == generation => {
+            sessions.remove(id);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Removes a reserved slot when dropped, unless disarmed once the session started
+struct Reservation<'a> {
+    sessions: &'a Sessions,
+    id: &'a str,
+    generation: u64,
+}
+
+impl Reservation<'_> {
+    fn disarm(self) {
+        std::mem::forget(self);
+    }
+}
+
+impl Drop for Reservation<'_> {
+    fn drop(&mut self) {
+        remove(self.sessions, self.id, self.generation);
+    }
+}
+
+impl Drop for DeviceSessionManager {
+    fn drop(&mut self) {
+        self.cancel_all();
+    }
+}
+
+/// Stops a spawned poll loop, independent of the runtime it runs on
+#[derive(Clone, Default)]
+struct AbortHandle(Arc<AbortState>);
+
+#[derive(Default)]
+struct AbortState {
+    aborted: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl AbortHandle {
+    fn abort(&self) {
+        self.0.aborted.store(true, Ordering::Release);
+        if let Some(waker) = self.0.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    fn is_aborted(&self) -> bool {
+        self.0.aborted.load(Ordering::Acquire)
+    }
+}
+
+struct Abortable {
+    task: BoxFuture<'static, ()>,
+    handle: AbortHandle,
+}
+
+impl Future for Abortable {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.handle.is_aborted() {
+            return Poll::Ready(());
+        }
+        *self.handle.0.waker.lock().unwrap() = Some(cx.waker().clone());
+        // Aborted before the waker was stored.
+        if self.handle.is_aborted() {
+            return Poll::Ready(());
+        }
+        self.task.as_mut().poll(cx)
+    }
+}
+
+impl Debug for DeviceSessionManager {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("DeviceSessionManager")
+            .field("device", &self.device)
+            .field("max_concurrent", &self.max_concurrent)
+            .field("sessions", &self.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use http::StatusCode;
+    use tokio::sync::Notify;
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpResponse},
+        ClientId, DeviceAuth,
+    };
+
+    use super::DeviceSessionManager;
+
+    /// Device flow whose device code request waits for `release`, then stays pending
+    fn device(release: Arc<Notify>) -> DeviceAuth {
+        DeviceAuth::new(ClientId::from("client_id")).with_transport(from_fn(move |request| {
+            let release = release.clone();
+            async move {
+                let (status, json) = if request.uri().path() == "/oauth2/device" {
+                    release.notified().await;
+                    (
+                        StatusCode::OK,
+                        r#"{"device_code":"ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91","expires_in":1800,"interval":1,"user_code":"ABCDEFGH","verification_uri":"https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH"}"#,
+                    )
+                } else {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        r#"{"status":400,"message":"authorization_pending"}"#,
+                    )
+                };
+
+                let mut response = HttpResponse::new(json.as_bytes().to_vec());
+                *response.status_mut() = status;
+                Ok::<_, BoxError>(response)
+            }
+        }))
+    }
+
+    #[tokio::test]
+    async fn cancel_while_requesting() {
+        let first = Arc::new(Notify::new());
+        let second = Arc::new(Notify::new());
+        let (manager, _events) = DeviceSessionManager::new(device(first.clone()), 2);
+        let manager = Arc::new(manager);
+
+        let cancelled = tokio::spawn({
+            let manager = manager.clone();
+            let device = device(first.clone());
+            async move { manager.start("streamer-1", device).await }
+        });
+        while !manager.contains("streamer-1") {
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+        assert!(manager.cancel("streamer-1"));
+
+        second.notify_one();
+        manager
+            .start("streamer-1", device(second.clone()))
+            .await
+            .unwrap();
+
+        first.notify_one();
+        let err = cancelled.await.unwrap().unwrap_err();
+        assert!(err.is_device_code_error());
+        assert!(manager.get("streamer-1").is_some());
+    }
+
+    #[tokio::test]
+    async fn drop_pending_start() {
+        let never = Arc::new(Notify::new());
+        let release = Arc::new(Notify::new());
+        let (manager, _events) = DeviceSessionManager::new(device(never.clone()), 1);
+
+        let pending = tokio::time::timeout(
+            Duration::from_millis(20),
+            manager.start("streamer-1", device(never)),
+        )
+        .await;
+        assert!(pending.is_err());
+        assert!(!manager.contains("streamer-1"));
+        assert!(manager.is_empty());
+
+        release.notify_one();
+        manager.start("streamer-1", device(release)).await.unwrap();
+        assert!(manager.get("streamer-1").is_some());
+    }
+}
@@ -0,0 +1,343 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+
+mod manager;
+#[cfg(feature = "qrcode")]
+mod qr;
+
+pub use manager::{DeviceSessionEvent, DeviceSessionManager};
+
+use asknothingx2_util::oauth::ClientId;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    clock, error,
+    interceptor::{Interceptor, Operation},
+    oauth::{decode_response, Core},
+    redact::fingerprint,
+    request::{DeviceCodeRequest, DeviceTokenRequest, RefreshRequest},
+    scope::{scopes_mut, Scopes, ScopesMut},
+    telemetry,
+    tokens::{default_created_at, ClientType},
+    transport::HttpTransport,
+    DeviceCode, DeviceUrl, Error, RefreshToken, TokenUrl, UserToken,
+};
+
+/// **Device Authentication** (Device Code Grant Flow)
+///
+/// Created with [`TwitchOauth::device_flow`] (sharing that client's configuration)
+/// or [`TwitchOauth::device_auth`]. Requests are sent like
+/// [`TwitchOauth::send`], so the HTTP client, endpoints and error handling are the
+/// same as for the other grants. Device flow clients are public, so no client
+/// secret is kept.
+///
+/// [`TwitchOauth::device_flow`]: crate::TwitchOauth::device_flow
+/// [`TwitchOauth::device_auth`]: crate::TwitchOauth::device_auth
+/// [`TwitchOauth::send`]: crate::TwitchOauth::send
+#[derive(Debug, Clone)]
+pub struct DeviceAuth {
+    core: Core,
+    scopes: Scopes,
+}
+
+impl DeviceAuth {
+    pub fn new(client_id: impl Into<ClientId>) -> Self {
+        Self::from_core(Core::new(client_id.into()))
+    }
+
+    pub(crate) fn from_core(core: Core) -> Self {
+        Self {
+            core,
+            scopes: Scopes::new(),
+        }
+    }
+
+    pub fn with_scopes(mut self, scopes: impl Into<Scopes>) -> Self {
+        self.scopes = scopes.into();
+        self
+    }
+
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.core.set_transport(Arc::new(client));
+        self
+    }
+
+    pub fn with_transport<T>(mut self, transport: T) -> Self
+    where
+        T: HttpTransport + 'static,
+    {
+        self.core.set_transport(Arc::new(transport));
+        self
+    }
+
+    /// See [`TwitchOauth::with_interceptor`](crate::TwitchOauth::with_interceptor)
+    pub fn with_interceptor<T>(mut self, interceptor: T) -> Self
+    where
+        T: Interceptor + 'static,
+    {
+        self.core.add_interceptor(Arc::new(interceptor));
+        self
+    }
+
+    pub fn with_device_url(mut self, device_url: DeviceUrl) -> Self {
+        self.core.set_device_url(device_url);
+        self
+    }
+
+    pub fn with_token_url(mut self, token_url: TokenUrl) -> Self {
+        self.core.set_token_url(token_url);
+        self
+    }
+
+    pub fn client_id(&self) -> &ClientId {
+        self.core.client_id()
+    }
+
+    pub fn scopes_mut(&mut self) -> ScopesMut<'_> {
+        scopes_mut(&mut self.scopes)
+    }
+
+    /// Request a device code
+    ///
+    /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#device-code-grant-flow>
+    pub async fn request(&self) -> Result<DeviceAuthResponse, Error> {
+        telemetry::observe(Operation::DeviceCode, self.core.client_id(), None, async {
+            let resp = self
+                .core
+                .send(DeviceCodeRequest::new(
+                    self.core.client_id(),
+                    &self.scopes,
+                    self.core.device_url(),
+                ))
+                .await?;
+
+            decode_response(resp)
+        })
+        .await
+    }
+
+    /// Poll for the user token
+    ///
+    /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#device-code-grant-flow>
+    pub async fn poll(&self, response: DeviceAuthResponse) -> Result<UserToken, Error> {
+        telemetry::observe(
+            Operation::DeviceToken,
+            self.core.client_id(),
+            None,
+            self.poll_until_done(response),
+        )
+        .await
+    }
+
+    async fn poll_until_done(&self, response: DeviceAuthResponse) -> Result<UserToken, Error> {
+        use std::time::Duration;
+        use tracing::{debug, trace, warn};
+
+        let client_id = self.core.client_id();
+        let deadline = response.expires_at();
+
+        debug!(
+            client_id = %client_id,
+            expires_in = response.expires_in,
+            interval = response.interval,
+            created_at = %format_timestamp(response.created_at),
+            deadline = %format_timestamp(deadline),
+            "starting device code poll"
+        );
+
+        let mut poll_count: u32 = 0;
+        loop {
+            crate::runtime::get()
+                .sleep(Duration::from_secs(response.interval))
+                .await;
+
+            if clock::now() >= deadline {
+                debug!(
+                    client_id = %client_id,
+                    poll_count,
+                    "device code expired"
+                );
+                return Err(error::device_code::timeout());
+            }
+
+            poll_count += 1;
+
+            let result = self
+                .core
+                .send(DeviceTokenRequest::new(
+                    client_id,
+                    &response.device_code,
+                    &self.scopes,
+                    self.core.token_url(),
+                ))
+                .await;
+
+            let err = match result {
+                Ok(resp) => {
+                    debug!(
+                        client_id = %client_id,
+                        poll_count,
+                        "device code token obtained"
+                    );
+                    let token: UserToken = decode_response(resp)?;
+                    return Ok(token.with_client_type(ClientType::Public));
+                }
+                Err(e) => match DeviceErrorResponse::from_error(&e) {
+                    Some(err) => err,
+                    None => return Err(e),
+                },
+            };
+
+            if err.is_pending() {
+                trace!(
+                    client_id = %client_id,
+                    poll_count,
+                    "authorization pending"
+                );
+                continue;
+            }
+
+            warn!(
+                client_id = %client_id,
+                status = err.status,
+                message = %err.message,
+                poll_count,
+                "device code flow error"
+            );
+            return Err(err.into_error());
+        }
+    }
+
+    /// Resume polling for a persisted device authorization session
+    ///
+    /// Use this after a restart with a [`DeviceAuthResponse`] that was serialized
+    /// while the user was still entering the code. Polling continues against the
+    /// original `device_code` and deadline, so the user doesn't have to start over.
+    ///
+    /// Returns a device code error if the session has already expired.
+    pub async fn resume(&self, response: DeviceAuthResponse) -> Result<UserToken, Error> {
+        if response.is_expired() {
+            tracing::debug!(
+                client_id = %self.core.client_id(),
+                deadline = %format_timestamp(response.expires_at()),
+                "persisted device code expired"
+            );
+            return Err(error::device_code::timeout());
+        }
+
+        self.poll(response).await
+    }
+
+    /// Refresh a token obtained through the device code grant flow
+    ///
+    /// Device flow clients are public clients, so the request is sent without a
+    /// `client_secret`. Public-client refresh tokens are single-use: the
+    /// `refresh_token` is consumed here, and the returned [`UserToken`] carries the
+    /// replacement, which expires 30 days after issuance
+    /// (see [`UserToken::refresh_token_expires_at`]).
+    ///
+    /// <https://dev.twitch.tv/docs/authentication/refresh-tokens/#how-to-use-a-refresh-token>
+    pub async fn refresh_access_token(
+        &self,
+        refresh_token: RefreshToken,
+    ) -> Result<UserToken, Error> {
+        telemetry::observe(
+            Operation::RefreshToken,
+            self.core.client_id(),
+            Some(fingerprint(refresh_token.secret())),
+            async {
+                let resp = self
+                    .core
+                    .send(RefreshRequest::public(
+                        self.core.client_id(),
+                        refresh_token,
+                        self.core.token_url(),
+                    ))
+                    .await?;
+
+                let token: UserToken = decode_response(resp)?;
+                Ok(token.with_client_type(ClientType::Public))
+            },
+        )
+        .await
+    }
+}
+
+/// Device authorization session returned by [`DeviceAuth::request`]
+///
+/// Serializable together with `created_at`, so a session can be persisted and
+/// continued with [`DeviceAuth::resume`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceAuthResponse {
+    pub device_code: DeviceCode,
+    pub expires_in: u64,
+    pub interval: u64,
+    pub user_code: String,
+    pub verification_uri: Url,
+    #[serde(default = "default_created_at")]
+    pub created_at: i64,
+}
+
+impl DeviceAuthResponse {
+    /// When the device code expires, as a unix timestamp
+    pub fn expires_at(&self) -> i64 {
+        self.created_at + self.expires_in as i64
+    }
+
+    pub fn is_expired(&self) -> bool {
+        clock::now() >= self.expires_at()
+    }
+
+    pub fn verification_uri_without_code(&self) -> Url {
+        let mut url = self.verification_uri.clone();
+        url.set_query(None);
+        url
+    }
+}
+
+impl Display for DeviceAuthResponse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "DeviceAuthResponse(verification_uri: {}, user_code: {})",
+            self.verification_uri, self.user_code
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct DeviceErrorResponse {
+    status: u16,
+    message: String,
+}
+
+impl DeviceErrorResponse {
+    fn from_error(err: &Error) -> Option<Self> {
+        if err.status_code() != Some(400) {
+            return None;
+        }
+
+        err.raw().and_then(|raw| serde_json::from_str(raw).ok())
+    }
+
+    pub fn into_error(self) -> Error {
+        error::device_code::flow_error(self.status, self.message)
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.message == "authorization_pending"
+    }
+}
+
+fn format_timestamp(timestamp: i64) -> String {
+    use chrono::TimeZone;
+    chrono::Local
+        .timestamp_opt(timestamp, 0)
+        .single()
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| timestamp.to_string())
+}
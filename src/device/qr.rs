@@ -0,0 +1,139 @@
+use qrcode::{
+    render::{svg, unicode::Dense1x2},
+    Color, QrCode,
+};
+
+use crate::{error, DeviceAuthResponse, Error};
+
+/// Modules of light border around the code, as recommended by the QR specification
+const QUIET_ZONE: usize = 4;
+
+/// Largest width and height of a rendered PNG, in pixels
+const MAX_PNG_SIZE: usize = 8192;
+
+/// QR code rendering of the verification URI
+///
+/// The encoded URI is [`DeviceAuthResponse::verification_uri`], which already embeds
+/// the user code, so scanning it takes the user straight to the confirmation page.
+impl DeviceAuthResponse {
+    /// Render the verification URI as UTF-8 half-block characters
+    ///
+    /// Two rows of modules are packed into each line. The output is drawn for
+    /// terminals with a dark background: light modules are printed as blocks.
+    ///
+    /// ```no_run
+    /// # use twitch_oauth_token::DeviceAuthResponse;
+    /// # fn run(resp: DeviceAuthResponse) -> Result<(), twitch_oauth_token::Error> {
+    /// println!("{}", resp.verification_qr_terminal()?);
+    /// println!("Or visit {} and enter {}", resp.verification_uri_without_code(), resp.user_code);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn verification_qr_terminal(&self) -> Result<String, Error> {
+        Ok(self
+            .verification_qr()?
+            .render::<Dense1x2>()
+            .dark_color(Dense1x2::Light)
+            .light_color(Dense1x2::Dark)
+            .build())
+    }
+
+    /// Render the verification URI as an SVG document
+    ///
+    /// `min_size` is the minimum width and height of the image in pixels.
+    pub fn verification_qr_svg(&self, min_size: u32) -> Result<String, Error> {
+        Ok(self
+            .verification_qr()?
+            .render::<svg::Color<'_>>()
+            .min_dimensions(min_size, min_size)
+            .build())
+    }
+
+    /// Render the verification URI as an 8-bit grayscale PNG
+    ///
+    /// Each module is drawn as a `module_size` x `module_size` pixel square.
+    /// Fails if the image would be wider than 8192 pixels.
+    pub fn verification_qr_png(&self, module_size: u32) -> Result<Vec<u8>, Error> {
+        let code = self.verification_qr()?;
+        let module_size = module_size.max(1) as usize;
+        let modules = code.width() + QUIET_ZONE * 2;
+        let size = modules
+            .checked_mul(module_size)
+            .filter(|size| *size <= MAX_PNG_SIZE)
+            .ok_or_else(|| error::qr_code::too_large(MAX_PNG_SIZE))?;
+
+        let colors = code.to_colors();
+        let mut pixels = Vec::with_capacity(size * size);
+        for y in 0..size {
+            let my = y / module_size;
+            for x in 0..size {
+                let mx = x / module_size;
+                let dark = (QUIET_ZONE..QUIET_ZONE + code.width()).contains(&mx)
+                    && (QUIET_ZONE..QUIET_ZONE + code.width()).contains(&my)
+                    && colors[(my - QUIET_ZONE) * code.width() + (mx - QUIET_ZONE)] == Color::Dark;
+                pixels.push(if dark { 0x00 } else { 0xFF });
+            }
+        }
+
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(&mut png, size as u32, size as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&pixels))
+            .map_err(error::qr_code::render)?;
+
+        Ok(png)
+    }
+
+    fn verification_qr(&self) -> Result<QrCode, Error> {
+        QrCode::new(self.verification_uri.as_str()).map_err(error::qr_code::encode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::DeviceAuthResponse;
+
+    fn response() -> DeviceAuthResponse {
+        serde_json::from_value(json!({
+            "device_code": "ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91",
+            "expires_in": 1800,
+            "interval": 5,
+            "user_code": "ABCDEFGH",
+            "verification_uri": "https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn render_terminal() {
+        let qr = response().verification_qr_terminal().unwrap();
+        let lines: Vec<&str> = qr.lines().collect();
+
+        assert!(!lines.is_empty());
+        assert!(lines
+            .iter()
+            .all(|l| l.chars().count() == lines[0].chars().count()));
+        assert!(qr.contains('█') || qr.contains('▀') || qr.contains('▄'));
+    }
+
+    #[test]
+    fn render_svg() {
+        let svg = response().verification_qr_svg(200).unwrap();
+        assert!(svg.contains("<svg"));
+    }
+
+    #[test]
+    fn render_png() {
+        let png = response().verification_qr_png(4).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let err = response().verification_qr_png(u32::MAX).unwrap_err();
+        assert!(err.is_qr_code_error());
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
+use crate::redact::redact_json;
+
 type BoxError = Box<dyn std::error::Error + Send + Sync>;
 
 pub struct Error {
@@ -28,6 +30,17 @@ pub(crate) enum Kind {
     Device,
 
     ClientSetup,
+
+    Scope,
+
+    #[cfg(feature = "qrcode")]
+    QrCode,
+
+    #[cfg(feature = "login")]
+    Login,
+
+    #[cfg(feature = "reload")]
+    Reload,
 }
 
 impl Error {
@@ -66,7 +79,7 @@ impl Error {
                 message: None,
                 source: Some(source.into()),
                 status_code: None,
-                raw: Some(raw.into()),
+                raw: Some(redact_json(raw.into().as_bytes())),
             }),
         }
     }
@@ -78,15 +91,20 @@ impl Error {
                 message: Some(format!("HTTP {status} error")),
                 source: None,
                 status_code: Some(status),
-                raw: Some(body.into()),
+                raw: Some(redact_json(body.into().as_bytes())),
             }),
         }
     }
 
+    pub(crate) fn kind(&self) -> &Kind {
+        &self.inner.kind
+    }
+
     pub fn message(&self) -> Option<&str> {
         self.inner.message.as_deref()
     }
 
+    /// Response body, with token and secret fields redacted
     pub fn raw(&self) -> Option<&str> {
         self.inner.raw.as_deref()
     }
@@ -114,6 +132,25 @@ impl Error {
     pub fn is_device_code_error(&self) -> bool {
         matches!(self.inner.kind, Kind::Device)
     }
+
+    pub fn is_scope_error(&self) -> bool {
+        matches!(self.inner.kind, Kind::Scope)
+    }
+
+    #[cfg(feature = "qrcode")]
+    pub fn is_qr_code_error(&self) -> bool {
+        matches!(self.inner.kind, Kind::QrCode)
+    }
+
+    #[cfg(feature = "login")]
+    pub fn is_login_error(&self) -> bool {
+        matches!(self.inner.kind, Kind::Login)
+    }
+
+    #[cfg(feature = "reload")]
+    pub fn is_reload_error(&self) -> bool {
+        matches!(self.inner.kind, Kind::Reload)
+    }
 }
 
 impl Debug for Error {
@@ -175,6 +212,33 @@ impl Kind {
             Kind::Device => "device code flow error response",
             Kind::ClientSetup => "HTTP client setup failed",
             Kind::Decode => "failed to deserialize response",
+            Kind::Scope => "insufficient token scope",
+            #[cfg(feature = "qrcode")]
+            Kind::QrCode => "failed to render QR code",
+            #[cfg(feature = "login")]
+            Kind::Login => "interactive login failed",
+            #[cfg(feature = "reload")]
+            Kind::Reload => "failed to reload configuration",
+        }
+    }
+
+    /// Metric and tracing label
+    pub(crate) fn name(&self) -> &'static str {
+        match self {
+            Kind::Request => "request",
+            Kind::CsrfTokenMismatch => "csrf_token_mismatch",
+            Kind::FormData => "form_data",
+            Kind::OAuthError => "oauth_error",
+            Kind::Device => "device",
+            Kind::ClientSetup => "client_setup",
+            Kind::Decode => "decode",
+            Kind::Scope => "scope",
+            #[cfg(feature = "qrcode")]
+            Kind::QrCode => "qr_code",
+            #[cfg(feature = "login")]
+            Kind::Login => "login",
+            #[cfg(feature = "reload")]
+            Kind::Reload => "reload",
         }
     }
 }
@@ -245,4 +309,112 @@ pub mod device_code {
     pub fn timeout() -> Error {
         Error::with_message(Kind::Device, "device code expired")
     }
+
+    pub fn session_exists(id: &str) -> Error {
+        Error::with_message(
+            Kind::Device,
+            format!("device session '{id}' is already running"),
+        )
+    }
+
+    pub fn session_limit(max: usize) -> Error {
+        Error::with_message(
+            Kind::Device,
+            format!("device session limit reached ({max} concurrent sessions)"),
+        )
+    }
+
+    pub fn session_cancelled(id: &str) -> Error {
+        Error::with_message(Kind::Device, format!("device session '{id}' was cancelled"))
+    }
+}
+
+pub mod scope {
+    use crate::scope::{EventSubTransport, Requirement, ScopeSet, TokenType};
+
+    use super::{Error, Kind};
+
+    pub fn token_type(endpoint: &str, token_type: TokenType) -> Error {
+        Error::with_message(
+            Kind::Scope,
+            format!("{endpoint} does not accept {token_type} access tokens"),
+        )
+    }
+
+    pub fn transport(subscription: &str, transport: EventSubTransport) -> Error {
+        Error::with_message(
+            Kind::Scope,
+            format!("{subscription} is not available over {transport}"),
+        )
+    }
+
+    pub fn missing(endpoint: &str, requirement: &Requirement) -> Error {
+        Error::with_message(Kind::Scope, format!("{endpoint} requires {requirement}"))
+    }
+
+    pub fn insufficient(missing: ScopeSet) -> Error {
+        Error::with_message(Kind::Scope, format!("token is missing scopes: {missing}"))
+    }
+}
+
+#[cfg(feature = "qrcode")]
+pub mod qr_code {
+    use super::{BoxError, Error, Kind};
+
+    pub fn encode(e: qrcode::types::QrError) -> Error {
+        Error::with_source(Kind::QrCode, e)
+    }
+
+    pub fn render<E: Into<BoxError>>(e: E) -> Error {
+        Error::with_source(Kind::QrCode, e)
+    }
+
+    pub fn too_large(max: usize) -> Error {
+        Error::with_message(
+            Kind::QrCode,
+            format!("QR code image would exceed {max}x{max} pixels"),
+        )
+    }
+}
+
+#[cfg(feature = "login")]
+pub mod login {
+    use super::{BoxError, Error, Kind};
+
+    pub fn bind(e: std::io::Error) -> Error {
+        Error::with_source(Kind::Login, e)
+    }
+
+    pub fn io<E: Into<BoxError>>(e: E) -> Error {
+        Error::with_source(Kind::Login, e)
+    }
+
+    pub fn invalid_redirect(message: impl Into<String>) -> Error {
+        Error::with_message(Kind::Login, message)
+    }
+
+    pub fn invalid_callback(message: impl Into<String>) -> Error {
+        Error::with_message(Kind::Login, message)
+    }
+
+    pub fn timeout() -> Error {
+        Error::with_message(Kind::Login, "timed out waiting for the OAuth callback")
+    }
+
+    pub fn denied(error: String, description: String) -> Error {
+        Error::with_message(Kind::OAuthError, format!("{error}: {description}"))
+    }
+}
+
+#[cfg(feature = "reload")]
+pub mod reload {
+    use super::{BoxError, Error, Kind};
+
+    pub fn read<E: Into<BoxError>>(e: E) -> Error {
+        Error::with_source(Kind::Reload, e)
+    }
+
+    pub fn parse<E: Into<BoxError>>(e: E) -> Error {
+        Error::with_source(Kind::Reload, e)
+    }
 }
@@ -0,0 +1,362 @@
+//! Hooks around every OAuth request
+//!
+//! An [`Interceptor`] runs before each request is handed to the
+//! [`HttpTransport`](crate::transport::HttpTransport) and after its response (or
+//! transport error) comes back. Both hooks receive the [`Operation`] and only see
+//! redacted bodies and headers, so they can log or audit without leaking client
+//! secrets, codes or tokens.
+//!
+//! ```
+//! use http::HeaderValue;
+//! use twitch_oauth_token::{interceptor, TwitchOauth};
+//!
+//! let oauth = TwitchOauth::new("client_id", "client_secret")
+//!     .with_interceptor(interceptor::before_request(|request| {
+//!         request.insert_header("x-correlation-id", HeaderValue::from_static("42"));
+//!     }))
+//!     .with_interceptor(interceptor::after_response(|response| {
+//!         println!(
+//!             "{} took {:?}: {:?}",
+//!             response.operation(),
+//!             response.elapsed(),
+//!             response.status()
+//!         );
+//!     }));
+//! ```
+
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    time::Duration,
+};
+
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
+
+use crate::{
+    redact::{redact_headers, redact_json, redact_request_body},
+    transport::{BoxError, HttpRequest, HttpResponse},
+};
+
+/// The OAuth call a request belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Operation {
+    ExchangeCode,
+    RefreshToken,
+    Validate,
+    Revoke,
+    ClientCredentials,
+    DeviceCode,
+    DeviceToken,
+    /// Requests sent with [`TwitchOauth::send`](crate::TwitchOauth::send) that
+    /// aren't one of the above
+    Other,
+}
+
+impl Operation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::ExchangeCode => "exchange_code",
+            Self::RefreshToken => "refresh_token",
+            Self::Validate => "validate",
+            Self::Revoke => "revoke",
+            Self::ClientCredentials => "client_credentials",
+            Self::DeviceCode => "device_code",
+            Self::DeviceToken => "device_token",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Before-request and after-response hooks
+///
+/// Both methods do nothing by default. Hooks run in the order they were added.
+pub trait Interceptor: Send + Sync {
+    fn before_request(&self, _request: &mut InterceptedRequest<'_>) {}
+
+    fn after_response(&self, _response: &InterceptedResponse<'_>) {}
+}
+
+/// Request about to be sent, see [`Interceptor::before_request`]
+pub struct InterceptedRequest<'a> {
+    operation: Operation,
+    request: &'a mut HttpRequest,
+}
+
+impl<'a> InterceptedRequest<'a> {
+    pub(crate) fn new(operation: Operation, request: &'a mut HttpRequest) -> Self {
+        Self { operation, request }
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn method(&self) -> &Method {
+        self.request.method()
+    }
+
+    pub fn uri(&self) -> &Uri {
+        self.request.uri()
+    }
+
+    /// Headers with `Authorization` redacted
+    pub fn headers(&self) -> HeaderMap {
+        redact_headers(self.request.headers())
+    }
+
+    /// Add a header, e.g. a correlation id
+    pub fn insert_header<K>(&mut self, name: K, value: HeaderValue)
+    where
+        K: TryInto<HeaderName>,
+    {
+        if let Ok(name) = name.try_into() {
+            self.request.headers_mut().insert(name, value);
+        }
+    }
+
+    /// Body with secret fields redacted
+    pub fn body(&self) -> String {
+        redact_request_body(self.request)
+    }
+}
+
+impl Debug for InterceptedRequest<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InterceptedRequest")
+            .field("operation", &self.operation)
+            .field("method", self.method())
+            .field("uri", self.uri())
+            .field("headers", &self.headers())
+            .field("body", &self.body())
+            .finish()
+    }
+}
+
+/// Outcome of a request, see [`Interceptor::after_response`]
+pub struct InterceptedResponse<'a> {
+    operation: Operation,
+    method: &'a Method,
+    uri: &'a Uri,
+    elapsed: Duration,
+    result: Result<&'a HttpResponse, &'a BoxError>,
+}
+
+impl<'a> InterceptedResponse<'a> {
+    pub(crate) fn new(
+        operation: Operation,
+        method: &'a Method,
+        uri: &'a Uri,
+        elapsed: Duration,
+        result: Result<&'a HttpResponse, &'a BoxError>,
+    ) -> Self {
+        Self {
+            operation,
+            method,
+            uri,
+            elapsed,
+            result,
+        }
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+
+    pub fn method(&self) -> &Method {
+        self.method
+    }
+
+    pub fn uri(&self) -> &Uri {
+        self.uri
+    }
+
+    /// Time spent in the transport
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// `None` if the transport failed
+    pub fn status(&self) -> Option<StatusCode> {
+        self.result.ok().map(|resp| resp.status())
+    }
+
+    /// `None` if the transport failed
+    pub fn headers(&self) -> Option<&HeaderMap> {
+        self.result.ok().map(|resp| resp.headers())
+    }
+
+    /// Body with token fields redacted, `None` if the transport failed
+    pub fn body(&self) -> Option<String> {
+        self.result.ok().map(|resp| redact_json(resp.body()))
+    }
+
+    /// The transport error, if no response was received
+    pub fn error(&self) -> Option<&BoxError> {
+        self.result.err()
+    }
+}
+
+impl Debug for InterceptedResponse<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InterceptedResponse")
+            .field("operation", &self.operation)
+            .field("method", self.method)
+            .field("uri", self.uri)
+            .field("elapsed", &self.elapsed)
+            .field("status", &self.status())
+            .field("error", &self.error())
+            .finish()
+    }
+}
+
+/// Interceptor running `f` before each request, see [`BeforeRequest`]
+pub fn before_request<F>(f: F) -> BeforeRequest<F>
+where
+    F: Fn(&mut InterceptedRequest<'_>) + Send + Sync,
+{
+    BeforeRequest(f)
+}
+
+/// Interceptor calling a function before each request
+#[derive(Clone)]
+pub struct BeforeRequest<F>(F);
+
+impl<F> Interceptor for BeforeRequest<F>
+where
+    F: Fn(&mut InterceptedRequest<'_>) + Send + Sync,
+{
+    fn before_request(&self, request: &mut InterceptedRequest<'_>) {
+        (self.0)(request)
+    }
+}
+
+impl<F> Debug for BeforeRequest<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("BeforeRequest").finish_non_exhaustive()
+    }
+}
+
+/// Interceptor running `f` after each response, see [`AfterResponse`]
+pub fn after_response<F>(f: F) -> AfterResponse<F>
+where
+    F: Fn(&InterceptedResponse<'_>) + Send + Sync,
+{
+    AfterResponse(f)
+}
+
+/// Interceptor calling a function after each response
+#[derive(Clone)]
+pub struct AfterResponse<F>(F);
+
+impl<F> Interceptor for AfterResponse<F>
+where
+    F: Fn(&InterceptedResponse<'_>) + Send + Sync,
+{
+    fn after_response(&self, response: &InterceptedResponse<'_>) {
+        (self.0)(response)
+    }
+}
+
+impl<F> Debug for AfterResponse<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("AfterResponse").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use http::{HeaderValue, StatusCode};
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpRequest, HttpResponse},
+        ClientId, DeviceAuth, RefreshToken, TwitchOauth,
+    };
+
+    use super::{after_response, before_request, Operation};
+
+    #[tokio::test]
+    async fn hooks_see_redacted_requests() {
+        let sent = Arc::new(Mutex::new(Vec::<HttpRequest>::new()));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let transport = {
+            let sent = sent.clone();
+            from_fn(move |request| {
+                sent.lock().unwrap().push(request);
+                async {
+                    Ok::<_, BoxError>(HttpResponse::new(
+                        br#"{"access_token":"rfx2uswqe8l4g1mkagrvg5tv0ks3","expires_in":14124,"refresh_token":"5b93chm6hdve3mycz05zfzatkfdenfspp1h1ar2xxdalen01","scope":[],"token_type":"bearer"}"#
+                            .to_vec(),
+                    ))
+                }
+            })
+        };
+
+        let oauth = TwitchOauth::new("client_id", "client_secret")
+            .with_transport(transport)
+            .with_interceptor(before_request({
+                let seen = seen.clone();
+                move |request| {
+                    request.insert_header("x-correlation-id", HeaderValue::from_static("42"));
+                    seen.lock()
+                        .unwrap()
+                        .push((request.operation(), request.body()));
+                }
+            }))
+            .with_interceptor(after_response({
+                let seen = seen.clone();
+                move |response| {
+                    assert_eq!(response.status(), Some(StatusCode::OK));
+                    seen.lock()
+                        .unwrap()
+                        .push((response.operation(), response.body().unwrap()));
+                }
+            }));
+
+        oauth
+            .refresh_access_token(RefreshToken::from("refresh"))
+            .await
+            .unwrap();
+
+        assert_eq!(sent.lock().unwrap()[0].headers()["x-correlation-id"], "42");
+
+        let seen = seen.lock().unwrap();
+        assert_eq!(seen[0].0, Operation::RefreshToken);
+        assert_eq!(seen[1].0, Operation::RefreshToken);
+        for (_, body) in seen.iter() {
+            assert!(!body.contains("client_secret=client_secret"));
+            assert!(!body.contains("refresh_token=refresh"));
+            assert!(!body.contains("rfx2uswqe8l4g1mkagrvg5tv0ks3"));
+            assert!(body.contains("[REDACTED]"));
+        }
+    }
+
+    #[tokio::test]
+    async fn device_and_transport_errors() {
+        let operations = Arc::new(Mutex::new(Vec::new()));
+
+        let device = DeviceAuth::new(ClientId::from("client_id"))
+            .with_transport(from_fn(|_| async {
+                Err::<HttpResponse, BoxError>("connection refused".into())
+            }))
+            .with_interceptor(after_response({
+                let operations = operations.clone();
+                move |response| {
+                    assert!(response.error().is_some());
+                    assert_eq!(response.status(), None);
+                    operations.lock().unwrap().push(response.operation());
+                }
+            }));
+
+        assert!(device.request().await.unwrap_err().is_request_error());
+        assert_eq!(*operations.lock().unwrap(), [Operation::DeviceCode]);
+    }
+}
@@ -23,7 +23,7 @@
 //! **Device Authentication** ([`DeviceAuth`])
 //! - For desktop apps, CLI tools, and devices that cannot store a client secret
 //! - Does not require a client secret or redirect URI
-//! - Use [`TwitchOauth::device_auth()`], [`DeviceAuth::request()`], and [`DeviceAuth::poll()`]
+//! - Use [`TwitchOauth::device_flow()`] (or [`TwitchOauth::device_auth()`]), [`DeviceAuth::request()`], and [`DeviceAuth::poll()`]
 //!
 //! ### Type Safety
 //!
@@ -198,6 +198,10 @@
 //! # }
 //! ```
 //!
+//! Tokens from the device flow belong to a public client and are refreshed
+//! without a client secret through [`DeviceAuth::refresh_access_token()`].
+//! Their refresh tokens are single-use and expire after 30 days.
+//!
 //! ### Validation
 //!
 //! Returns [`TokenInfo`].
@@ -364,10 +368,76 @@
 //! # }
 //! ```
 //!
+//! ## Interactive Login
+//!
+//! CLI and desktop apps can let the library drive the whole flow (requires `login` feature):
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "login")]
+//! # {
+//! use std::str::FromStr;
+//! use twitch_oauth_token::{RedirectUrl, TwitchOauth};
+//!
+//! #[tokio::main]
+//! async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//!     let oauth = TwitchOauth::new("client_id", "client_secret")
+//!         .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000")?);
+//!
+//!     let mut login = oauth.interactive_login();
+//!     login.scopes_mut().chat_api();
+//!
+//!     // Opens the browser and waits for the callback on localhost:3000
+//!     let token = login.run().await?;
+//!     Ok(())
+//! }
+//! # }
+//! ```
+//!
+//! ## Blocking API
+//!
+//! Synchronous applications can use [`blocking::TwitchOauth`] without setting up
+//! a runtime (requires `blocking` feature).
+//!
+//! ## Async Runtimes
+//!
+//! Timers and background tasks go through [`runtime`], so the async API also
+//! works on executors other than tokio.
+//!
+//! ## Logging
+//!
+//! Every operation runs in a `twitch_oauth` [`tracing`](https://docs.rs/tracing)
+//! span with `operation`, `client_id`, the response `status` and `token`, the first
+//! 8 hex digits of the SHA-256 of the token it acts on. Client secrets, codes and
+//! tokens are never logged, and are redacted from [`Error::raw()`] bodies.
+//!
+//! ## Metrics
+//!
+//! With the `metrics` feature, every operation is recorded through the
+//! [`metrics`](https://docs.rs/metrics) facade; install any recorder (e.g.
+//! `metrics-exporter-prometheus`) to collect them:
+//!
+//! - `twitch_oauth_requests_total` (counter): labels `operation` and `outcome`
+//!   (`success` or `error`)
+//! - `twitch_oauth_errors_total` (counter): labels `operation` and `kind`, e.g.
+//!   `oauth_error` or `csrf_token_mismatch`
+//! - `twitch_oauth_request_duration_seconds` (histogram): labels `operation` and
+//!   `outcome`
+//!
+//! `operation` is an [`interceptor::Operation`] name. Device code polling is
+//! recorded once per [`DeviceAuth::poll()`], not for every pending poll.
+//!
+//! ## Hot Reload
+//!
+//! With the `reload` feature, [`TwitchOauth::into_handle()`] shares a client whose
+//! secret, CSRF settings, endpoints and HTTP client can be swapped without
+//! locking the request path, optionally from a watched config file; see
+//! `reload`.
+//!
 //! ## HTTP Client Configuration
 //!
 //! The default HTTP client works for most applications.
-//! For custom configuration, see [`client`].
+//! For custom configuration, see [`client`]. To replace reqwest, e.g. with an
+//! in-memory fake in unit tests, see [`transport`].
 //!
 //! ## Testing
 //!
@@ -376,26 +446,48 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod client;
+pub mod clock;
+pub mod interceptor;
+pub mod runtime;
 pub mod scope;
+pub mod transport;
 
 mod device;
 mod error;
+mod metadata;
 mod oauth;
+mod redact;
 mod request;
+mod telemetry;
 mod tokens;
 mod types;
 
-pub use device::{DeviceAuth, DeviceAuthResponse};
+pub use device::{DeviceAuth, DeviceAuthResponse, DeviceSessionEvent, DeviceSessionManager};
 pub use error::Error;
+pub use metadata::{RateLimit, ResponseMetadata, WithMetadata};
 pub use oauth::{AppAuth, TwitchOauth, UserAuth};
-pub use request::{validate_access_token, AuthrozationRequest};
-pub use scope::Scope;
-pub use tokens::{AppToken, TokenInfo, UserToken};
-pub use types::AuthCallback;
+pub use request::{
+    validate_access_token, AuthrozationRequest, ClientCredentialsRequest, DeviceCodeRequest,
+    DeviceTokenRequest, ExchangeCodeRequest, RefreshRequest, RevokeRequest, ValidateRequest,
+};
+pub use scope::{Scope, ScopeSet, Scopes};
+pub use tokens::{AppToken, ClientType, TokenInfo, UserToken, PUBLIC_REFRESH_TOKEN_LIFETIME};
+pub use types::{AuthCallback, GrantType};
+
+#[cfg(feature = "blocking")]
+pub mod blocking;
 
 #[cfg(feature = "oneshot")]
 pub use asknothingx2_util::oauth::oneshot;
 
+#[cfg(feature = "login")]
+mod login;
+#[cfg(feature = "login")]
+pub use login::InteractiveLogin;
+
+#[cfg(feature = "reload")]
+pub mod reload;
+
 #[cfg(feature = "test")]
 pub mod test_oauth;
 
@@ -0,0 +1,389 @@
+//! Interactive desktop login
+//!
+//! Runs the whole authorization code grant flow for CLI and desktop apps:
+//! binds a loopback listener matching the redirect URI, opens the system browser,
+//! waits for the callback, validates `state`, exchanges the code and answers the
+//! browser with an HTML page.
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    process::{Command, Stdio},
+    thread,
+    time::Duration,
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    time::timeout,
+};
+use tracing::{debug, warn};
+use url::{Host, Url};
+
+use crate::{
+    error,
+    scope::{scopes_mut, Scopes, ScopesMut},
+    AuthorizationCode, Error, TwitchOauth, UserAuth, UserToken,
+};
+
+const DEFAULT_SUCCESS_PAGE: &str = "<!DOCTYPE html>\
+<html><head><meta charset=\"utf-8\"><title>Authorization successful</title></head>\
+<body><h1>Authorization successful</h1><p>You can close this window.</p></body></html>";
+
+const DEFAULT_FAILURE_PAGE: &str = "<!DOCTYPE html>\
+<html><head><meta charset=\"utf-8\"><title>Authorization failed</title></head>\
+<body><h1>Authorization failed</h1><p>{error}</p></body></html>";
+
+/// Largest request head accepted from the browser
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+
+/// How long a connection may take to send its request head
+///
+/// Browsers open speculative connections that never send a request; they must
+/// not hold up the callback.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(3);
+
+type UrlHandler = Box<dyn FnOnce(&Url) + Send>;
+
+/// Builder for [`TwitchOauth::interactive_login`]
+///
+/// Defaults:
+/// - timeout: 300s
+/// - opens the system browser, logging the URL at warn level if that fails
+/// - plain success and failure pages
+pub struct InteractiveLogin<'a> {
+    oauth: &'a TwitchOauth<UserAuth>,
+    scopes: Scopes,
+    force_verify: Option<bool>,
+    timeout: Duration,
+    success_page: String,
+    failure_page: String,
+    url_handler: Option<UrlHandler>,
+}
+
+impl<'a> InteractiveLogin<'a> {
+    pub(crate) fn new(oauth: &'a TwitchOauth<UserAuth>) -> Self {
+        Self {
+            oauth,
+            scopes: Scopes::new(),
+            force_verify: None,
+            timeout: Duration::from_secs(300),
+            success_page: DEFAULT_SUCCESS_PAGE.to_string(),
+            failure_page: DEFAULT_FAILURE_PAGE.to_string(),
+            url_handler: None,
+        }
+    }
+
+    pub fn scopes_mut(&mut self) -> ScopesMut<'_> {
+        scopes_mut(&mut self.scopes)
+    }
+
+    pub fn with_force_verify(mut self, force_verify: bool) -> Self {
+        self.force_verify = Some(force_verify);
+        self
+    }
+
+    /// How long to wait for the user to complete the login
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// HTML served to the browser after the token was obtained
+    pub fn with_success_page(mut self, html: impl Into<String>) -> Self {
+        self.success_page = html.into();
+        self
+    }
+
+    /// HTML served to the browser when the login fails
+    ///
+    /// `{error}` is replaced with an HTML-escaped description of the failure.
+    pub fn with_failure_page(mut self, html: impl Into<String>) -> Self {
+        self.failure_page = html.into();
+        self
+    }
+
+    /// Present the authorization URL yourself instead of opening the browser
+    ///
+    /// Called once the listener is ready to accept the callback. Use this to show
+    /// the URL in your UI, e.g. when no browser is available.
+    pub fn with_url_handler<F>(mut self, handler: F) -> Self
+    where
+        F: FnOnce(&Url) + Send + 'static,
+    {
+        self.url_handler = Some(Box::new(handler));
+        self
+    }
+
+    /// Run the login and return the user token
+    pub async fn run(self) -> Result<UserToken, Error> {
+        let redirect_url = self.oauth.get_redirect_uri().to_url();
+        let addr = loopback_addr(&redirect_url)?;
+
+        let listener = TcpListener::bind(addr).await.map_err(error::login::bind)?;
+        debug!(%addr, "listening for OAuth callback");
+
+        let mut auth_request = self.oauth.authorization_url();
+        auth_request.scopes_mut().append(&self.scopes);
+        if let Some(force_verify) = self.force_verify {
+            auth_request.set_force_verify(force_verify);
+        }
+        let (auth_url, state) = auth_request.url_with_state();
+
+        match self.url_handler {
+            Some(handler) => handler(&auth_url),
+            None => {
+                if let Err(e) = open_browser(&auth_url) {
+                    warn!(error = %e, %auth_url, "failed to open browser, open the URL to log in");
+                }
+            }
+        }
+
+        let pages = Pages {
+            success: &self.success_page,
+            failure: &self.failure_page,
+        };
+
+        timeout(
+            self.timeout,
+            accept_callback(self.oauth, &listener, redirect_url.path(), &state, pages),
+        )
+        .await
+        .map_err(|_| error::login::timeout())?
+    }
+}
+
+impl Debug for InteractiveLogin<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("InteractiveLogin")
+            .field("oauth", &self.oauth)
+            .field("scopes", &self.scopes)
+            .field("force_verify", &self.force_verify)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Pages<'a> {
+    success: &'a str,
+    failure: &'a str,
+}
+
+impl Pages<'_> {
+    fn failure(&self, message: &str) -> String {
+        self.failure.replace("{error}", &escape_html(message))
+    }
+}
+
+async fn accept_callback(
+    oauth: &TwitchOauth<UserAuth>,
+    listener: &TcpListener,
+    path: &str,
+    state: &str,
+    pages: Pages<'_>,
+) -> Result<UserToken, Error> {
+    loop {
+        let (mut stream, remote_addr) = listener.accept().await.map_err(error::login::io)?;
+        debug!(%remote_addr, "accepted callback connection");
+
+        let target = match timeout(REQUEST_TIMEOUT, read_request_target(&mut stream)).await {
+            Ok(Some(target)) => target,
+            Ok(None) => {
+                respond(&mut stream, "400 Bad Request", "").await;
+                continue;
+            }
+            Err(_) => {
+                debug!(%remote_addr, "closing idle callback connection");
+                continue;
+            }
+        };
+
+        // Browsers also ask for things like `/favicon.ico`.
+        let Ok(url) = Url::parse("http://localhost").and_then(|base| base.join(&target)) else {
+            respond(&mut stream, "400 Bad Request", "").await;
+            continue;
+        };
+        if url.path() != path {
+            respond(&mut stream, "404 Not Found", "").await;
+            continue;
+        }
+
+        let query = url.query_pairs().collect::<Vec<_>>();
+        let param = |name: &str| {
+            query
+                .iter()
+                .find(|(k, _)| k == name)
+                .map(|(_, v)| v.to_string())
+        };
+
+        if let Some(err) = param("error") {
+            let description = param("error_description").unwrap_or_else(|| err.clone());
+            respond(&mut stream, "200 OK", &pages.failure(&description)).await;
+            return Err(error::login::denied(err, description));
+        }
+
+        if param("state").as_deref() != Some(state) {
+            respond(
+                &mut stream,
+                "400 Bad Request",
+                &pages.failure("Invalid state"),
+            )
+            .await;
+            return Err(error::oauth::csrf_token_mismatch());
+        }
+
+        let Some(code) = param("code") else {
+            let message = "Missing authorization code";
+            respond(&mut stream, "400 Bad Request", &pages.failure(message)).await;
+            return Err(error::login::invalid_callback(message));
+        };
+
+        return match oauth
+            .exchange_code(AuthorizationCode::from(code), state.to_string())
+            .await
+        {
+            Ok(token) => {
+                respond(&mut stream, "200 OK", pages.success).await;
+                Ok(token)
+            }
+            Err(e) => {
+                respond(&mut stream, "200 OK", &pages.failure(&e.to_string())).await;
+                Err(e)
+            }
+        };
+    }
+}
+
+/// Read the request head and return the request target of a `GET` request
+async fn read_request_target(stream: &mut TcpStream) -> Option<String> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 1024];
+
+    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+        if buf.len() > MAX_REQUEST_SIZE {
+            return None;
+        }
+        let n = stream.read(&mut chunk).await.ok()?;
+        if n == 0 {
+            return None;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let head = String::from_utf8_lossy(&buf);
+    let mut parts = head.lines().next()?.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some("GET"), Some(target)) => Some(target.to_string()),
+        _ => None,
+    }
+}
+
+async fn respond(stream: &mut TcpStream, status: &str, body: &str) {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    if let Err(e) = stream.write_all(response.as_bytes()).await {
+        debug!(error = %e, "failed to write callback response");
+    }
+    let _ = stream.shutdown().await;
+}
+
+fn loopback_addr(redirect_url: &Url) -> Result<SocketAddr, Error> {
+    if redirect_url.scheme() != "http" {
+        return Err(error::login::invalid_redirect("redirect URI must use http"));
+    }
+
+    let ip = match redirect_url.host() {
+        Some(Host::Domain("localhost")) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+        Some(Host::Ipv4(ip)) if ip.is_loopback() => IpAddr::V4(ip),
+        Some(Host::Ipv6(ip)) if ip.is_loopback() => IpAddr::V6(ip),
+        _ => {
+            return Err(error::login::invalid_redirect(
+                "redirect URI must point to a loopback address",
+            ))
+        }
+    };
+
+    let port = redirect_url.port_or_known_default().unwrap_or(80);
+    Ok(SocketAddr::new(ip, port))
+}
+
+fn open_browser(url: &Url) -> std::io::Result<()> {
+    let url = url.as_str();
+
+    let mut command = if cfg!(target_os = "windows") {
+        let mut command = Command::new("rundll32");
+        command.args(["url.dll,FileProtocolHandler", url]);
+        command
+    } else if cfg!(target_os = "macos") {
+        let mut command = Command::new("open");
+        command.arg(url);
+        command
+    } else {
+        let mut command = Command::new("xdg-open");
+        command.arg(url);
+        command
+    };
+
+    // `xdg-open` may not return until the browser exits, so don't wait on the
+    // executor; reap the child on its own thread instead.
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    thread::spawn(move || match child.wait() {
+        Ok(status) if !status.success() => debug!(%status, "browser exited"),
+        Err(e) => debug!(error = %e, "failed to wait for browser"),
+        Ok(_) => {}
+    });
+
+    Ok(())
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use url::Url;
+
+    use super::{escape_html, loopback_addr};
+
+    #[test]
+    fn redirect_must_be_loopback() {
+        let addr = loopback_addr(&Url::from_str("http://localhost:3000/callback").unwrap());
+        assert_eq!(addr.unwrap().to_string(), "127.0.0.1:3000");
+
+        let addr = loopback_addr(&Url::from_str("http://[::1]:8080").unwrap());
+        assert_eq!(addr.unwrap().to_string(), "[::1]:8080");
+
+        assert!(loopback_addr(&Url::from_str("https://localhost:3000").unwrap()).is_err());
+        assert!(loopback_addr(&Url::from_str("http://example.com").unwrap()).is_err());
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            escape_html("<script>\"&'"),
+            "&lt;script&gt;&quot;&amp;&#39;"
+        );
+    }
+}
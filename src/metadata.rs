@@ -0,0 +1,191 @@
+use std::ops::Deref;
+
+use chrono::{DateTime, TimeZone, Utc};
+use http::{header::DATE, HeaderMap};
+
+use serde::de::DeserializeOwned;
+
+use crate::{oauth::decode_response, transport::HttpResponse, Error};
+
+const RATELIMIT_LIMIT: &str = "ratelimit-limit";
+const RATELIMIT_REMAINING: &str = "ratelimit-remaining";
+const RATELIMIT_RESET: &str = "ratelimit-reset";
+
+/// Rate limit bucket reported by Twitch in `Ratelimit-*` headers
+///
+/// <https://dev.twitch.tv/docs/api/guide/#twitch-rate-limits>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RateLimit {
+    /// Points added to the bucket per minute
+    pub limit: u32,
+    /// Points left in the bucket
+    pub remaining: u32,
+    /// When the bucket is full again, as a unix timestamp
+    pub reset: i64,
+}
+
+impl RateLimit {
+    /// Returns `None` unless all three headers are present and valid
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        fn parse<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+            headers.get(name)?.to_str().ok()?.trim().parse().ok()
+        }
+
+        Some(Self {
+            limit: parse(headers, RATELIMIT_LIMIT)?,
+            remaining: parse(headers, RATELIMIT_REMAINING)?,
+            reset: parse(headers, RATELIMIT_RESET)?,
+        })
+    }
+
+    pub fn reset_at(&self) -> Option<DateTime<Utc>> {
+        Utc.timestamp_opt(self.reset, 0).single()
+    }
+
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
+
+/// Status and headers of a successful response
+#[derive(Debug, Clone)]
+pub struct ResponseMetadata {
+    pub status: u16,
+    /// Server time from the `Date` header
+    pub date: Option<DateTime<Utc>>,
+    pub rate_limit: Option<RateLimit>,
+    pub headers: HeaderMap,
+}
+
+impl ResponseMetadata {
+    pub fn from_response(resp: &HttpResponse) -> Self {
+        let headers = resp.headers();
+        Self {
+            status: resp.status().as_u16(),
+            date: headers
+                .get(DATE)
+                .and_then(|date| date.to_str().ok())
+                .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+                .map(|date| date.with_timezone(&Utc)),
+            rate_limit: RateLimit::from_headers(headers),
+            headers: headers.clone(),
+        }
+    }
+}
+
+/// A decoded response body with its [`ResponseMetadata`]
+///
+/// Returned by the `*_with_metadata` methods of [`TwitchOauth`](crate::TwitchOauth).
+#[derive(Debug, Clone)]
+pub struct WithMetadata<T> {
+    pub body: T,
+    pub metadata: ResponseMetadata,
+}
+
+impl<T> WithMetadata<T> {
+    /// Decode a successful response, e.g. from [`TwitchOauth::send`](crate::TwitchOauth::send)
+    pub fn from_response(resp: HttpResponse) -> Result<Self, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let metadata = ResponseMetadata::from_response(&resp);
+        Ok(Self {
+            body: decode_response(resp)?,
+            metadata,
+        })
+    }
+
+    pub fn into_body(self) -> T {
+        self.body
+    }
+
+    pub fn into_parts(self) -> (T, ResponseMetadata) {
+        (self.body, self.metadata)
+    }
+}
+
+impl<T> Deref for WithMetadata<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use http::StatusCode;
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpResponse},
+        TwitchOauth,
+    };
+
+    use super::{RateLimit, ResponseMetadata};
+
+    #[test]
+    fn parses_headers() {
+        let mut resp = HttpResponse::new(Vec::new());
+        let headers = resp.headers_mut();
+        headers.insert("date", "Tue, 15 Nov 1994 08:12:31 GMT".parse().unwrap());
+        headers.insert("Ratelimit-Limit", "800".parse().unwrap());
+        headers.insert("Ratelimit-Remaining", "799".parse().unwrap());
+        headers.insert("Ratelimit-Reset", "1700000000".parse().unwrap());
+
+        let metadata = ResponseMetadata::from_response(&resp);
+        assert_eq!(metadata.status, 200);
+        assert_eq!(
+            metadata.date,
+            Some(Utc.with_ymd_and_hms(1994, 11, 15, 8, 12, 31).unwrap())
+        );
+        assert_eq!(
+            metadata.rate_limit,
+            Some(RateLimit {
+                limit: 800,
+                remaining: 799,
+                reset: 1_700_000_000,
+            })
+        );
+
+        resp.headers_mut()
+            .insert("Ratelimit-Reset", "soon".parse().unwrap());
+        assert_eq!(ResponseMetadata::from_response(&resp).rate_limit, None);
+    }
+
+    #[tokio::test]
+    async fn rate_limit_snapshot() {
+        let oauth = TwitchOauth::new("client_id", "client_secret").with_transport(from_fn(
+            |request| async move {
+                let remaining = if request.method() == "GET" { "0" } else { "41" };
+                let mut resp = HttpResponse::new(
+                    br#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#
+                        .to_vec(),
+                );
+                let headers = resp.headers_mut();
+                headers.insert("Ratelimit-Limit", "800".parse().unwrap());
+                headers.insert("Ratelimit-Remaining", remaining.parse().unwrap());
+                headers.insert("Ratelimit-Reset", "1700000000".parse().unwrap());
+                if remaining == "0" {
+                    *resp.status_mut() = StatusCode::TOO_MANY_REQUESTS;
+                }
+                Ok::<_, BoxError>(resp)
+            },
+        ));
+        assert_eq!(oauth.rate_limit(), None);
+
+        let resp = oauth.app_access_token_with_metadata().await.unwrap();
+        assert_eq!(resp.access_token.secret(), "jostpf5q0puzmxmkba9iyug38kjtg");
+        assert_eq!(resp.metadata.rate_limit.unwrap().remaining, 41);
+
+        let clone = oauth.clone();
+        assert_eq!(clone.rate_limit(), resp.metadata.rate_limit);
+
+        let err = clone
+            .validate_access_token(&"token".into())
+            .await
+            .unwrap_err();
+        assert_eq!(err.status_code(), Some(429));
+        assert!(oauth.rate_limit().unwrap().is_exhausted());
+    }
+}
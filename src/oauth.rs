@@ -2,28 +2,37 @@ use std::{
     fmt::{Debug, Display, Formatter, Result as FmtResult},
     marker::PhantomData,
     str::FromStr,
+    sync::Arc,
+    time::Instant,
 };
 
-use asknothingx2_util::api::IntoRequestBuilder;
+use arc_swap::ArcSwapOption;
 use reqwest::Client;
 
 use crate::{
+    clock::{self, ClockSkew},
     csrf::{self, CsrfConfig},
     device::DeviceAuth,
     error,
+    interceptor::{InterceptedRequest, InterceptedResponse, Interceptor, Operation},
+    metadata::{RateLimit, ResponseMetadata, WithMetadata},
+    redact::fingerprint,
     request::{
         ClientCredentialsRequest, ExchangeCodeRequest, RefreshRequest, RevokeRequest,
         ValidateRequest,
     },
+    telemetry,
+    transport::{HttpResponse, HttpTransport, IntoHttpRequest},
     types::GrantType,
-    AccessToken, AuthUrl, AuthorizationCode, AuthrozationRequest, ClientId, ClientSecret, Error,
-    RedirectUrl, RefreshToken, RevocationUrl, TokenUrl, ValidateUrl,
+    AccessToken, AuthUrl, AuthorizationCode, AuthrozationRequest, ClientId, ClientSecret,
+    DeviceUrl, Error, RedirectUrl, RefreshToken, RevocationUrl, TokenUrl, ValidateUrl,
 };
 
 pub const AUTH_URL: &str = "https://id.twitch.tv/oauth2/authorize";
 pub const TOKEN_URL: &str = "https://id.twitch.tv/oauth2/token";
 pub const REVOKE_URL: &str = "https://id.twitch.tv/oauth2/revoke";
 pub const VALIDATE_URL: &str = "https://id.twitch.tv/oauth2/validate";
+pub const DEVICE_URL: &str = "https://id.twitch.tv/oauth2/device";
 
 mod private {
     pub trait Sealed {}
@@ -78,6 +87,9 @@ impl OauthFlow for UserAuth {
 /// - **AppAuth**: For server-to-server communication (no user interaction)
 /// - **UserAuth**: For user authentication flows (requires redirect URI)
 ///
+/// Cloning is cheap: clones share the configuration, HTTP transport and rate
+/// limit snapshot, so a clone can be moved into each spawned task.
+///
 /// **App authentication** (most common for backend services):
 /// ```no_run
 /// use twitch_oauth_token::TwitchOauth;
@@ -118,17 +130,137 @@ pub struct TwitchOauth<Flow = AppAuth>
 where
     Flow: OauthFlow,
 {
-    client_id: ClientId,
-    client_secret: ClientSecret,
+    core: Core,
+    client_secret: Arc<ClientSecret>,
     redirect_uri: Flow::RedirectUrl,
+    phanthom: PhantomData<Flow>,
+}
+
+/// Everything but the client secret, shared with the [`DeviceAuth`]s created
+/// from a client
+#[derive(Clone)]
+pub(crate) struct Core {
+    config: Arc<Config>,
+    rate_limit: Arc<ArcSwapOption<RateLimit>>,
+}
+
+/// Configuration shared by clones, copied on the first `with_*`/`set_*` call
+/// after cloning
+#[derive(Clone)]
+struct Config {
+    client_id: ClientId,
     secret_key: [u8; 32],
-    client: Client,
+    transport: Arc<dyn HttpTransport>,
+    clock_skew: bool,
+    interceptors: Vec<Arc<dyn Interceptor>>,
     token_url: TokenUrl,
     auth_url: AuthUrl,
     revoke_url: RevocationUrl,
     validate_url: ValidateUrl,
+    device_url: DeviceUrl,
     csrf_config: CsrfConfig,
-    phanthom: PhantomData<Flow>,
+}
+
+impl Core {
+    pub(crate) fn new(client_id: ClientId) -> Self {
+        Self {
+            config: Arc::new(Config {
+                client_id,
+                secret_key: csrf::generate_secret_key(),
+                transport: crate::client::transport(),
+                clock_skew: false,
+                interceptors: Vec::new(),
+                token_url: TokenUrl::from_str(TOKEN_URL).unwrap(),
+                auth_url: AuthUrl::from_str(AUTH_URL).unwrap(),
+                revoke_url: RevocationUrl::from_str(REVOKE_URL).unwrap(),
+                validate_url: ValidateUrl::from_str(VALIDATE_URL).unwrap(),
+                device_url: DeviceUrl::from_str(DEVICE_URL).unwrap(),
+                csrf_config: CsrfConfig::default(),
+            }),
+            rate_limit: Arc::default(),
+        }
+    }
+
+    pub(crate) fn client_id(&self) -> &ClientId {
+        &self.config.client_id
+    }
+
+    pub(crate) fn token_url(&self) -> &TokenUrl {
+        &self.config.token_url
+    }
+
+    pub(crate) fn device_url(&self) -> &DeviceUrl {
+        &self.config.device_url
+    }
+
+    pub(crate) fn rate_limit(&self) -> Option<RateLimit> {
+        self.rate_limit.load().as_deref().copied()
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        Arc::make_mut(&mut self.config)
+    }
+
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn HttpTransport>) {
+        self.config_mut().transport = transport;
+    }
+
+    pub(crate) fn add_interceptor(&mut self, interceptor: Arc<dyn Interceptor>) {
+        self.config_mut().interceptors.push(interceptor);
+    }
+
+    pub(crate) fn set_token_url(&mut self, token_url: TokenUrl) {
+        self.config_mut().token_url = token_url;
+    }
+
+    pub(crate) fn set_device_url(&mut self, device_url: DeviceUrl) {
+        self.config_mut().device_url = device_url;
+    }
+
+    /// See [`TwitchOauth::send`]
+    pub(crate) async fn send<T>(&self, request: T) -> Result<HttpResponse, Error>
+    where
+        T: IntoHttpRequest,
+    {
+        let operation = request.operation();
+        let mut request = request.into_http_request()?;
+        for interceptor in &self.config.interceptors {
+            interceptor.before_request(&mut InterceptedRequest::new(operation, &mut request));
+        }
+
+        let method = request.method().clone();
+        let uri = request.uri().clone();
+        let start = Instant::now();
+        let result = self.config.transport.send(request).await;
+
+        if !self.config.interceptors.is_empty() {
+            let response = InterceptedResponse::new(
+                operation,
+                &method,
+                &uri,
+                start.elapsed(),
+                result.as_ref(),
+            );
+            for interceptor in &self.config.interceptors {
+                interceptor.after_response(&response);
+            }
+        }
+
+        let resp = result.map_err(error::network::request)?;
+        tracing::Span::current().record("status", resp.status().as_u16());
+
+        if let Some(rate_limit) = RateLimit::from_headers(resp.headers()) {
+            self.rate_limit.store(Some(Arc::new(rate_limit)));
+        }
+
+        if self.config.clock_skew {
+            if let Some(date) = ResponseMetadata::from_response(&resp).date {
+                ClockSkew::global().observe(date);
+            }
+        }
+
+        check_status(resp)
+    }
 }
 
 impl<Flow> TwitchOauth<Flow>
@@ -137,7 +269,15 @@ where
 {
     /// Get the client ID
     pub fn client_id(&self) -> &ClientId {
-        &self.client_id
+        self.core.client_id()
+    }
+
+    /// Latest rate limit reported by Twitch
+    ///
+    /// Updated without locking by every request, and shared with clones of this
+    /// client and the [`DeviceAuth`]s created from it.
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        self.core.rate_limit()
     }
 
     /// Get the client secret
@@ -151,7 +291,40 @@ where
     /// Note: This only affects this OAuth instance, not the global client.
     /// For global configuration, use [client::setup()](crate::client::setup()) instead.
     pub fn with_client(mut self, client: Client) -> Self {
-        self.client = client;
+        self.core.set_transport(Arc::new(client));
+        self
+    }
+
+    /// Send requests through a custom [`HttpTransport`] instead of a [`Client`]
+    ///
+    /// See [`crate::transport`].
+    pub fn with_transport<T>(mut self, transport: T) -> Self
+    where
+        T: HttpTransport + 'static,
+    {
+        self.core.set_transport(Arc::new(transport));
+        self
+    }
+
+    /// Feed the `Date` header of every response into [`ClockSkew::global()`]
+    ///
+    /// Token expiry, CSRF state and device code deadlines are then corrected for
+    /// the difference between the server and local clock; see [`crate::clock`].
+    pub fn with_clock_skew(mut self, enabled: bool) -> Self {
+        self.config_mut().clock_skew = enabled;
+        self
+    }
+
+    /// Run an [`Interceptor`] around every request
+    ///
+    /// Interceptors are kept by [`device_flow`](Self::device_flow) and
+    /// [`with_redirect_uri`](TwitchOauth::with_redirect_uri); see
+    /// [`crate::interceptor`].
+    pub fn with_interceptor<T>(mut self, interceptor: T) -> Self
+    where
+        T: Interceptor + 'static,
+    {
+        self.core.add_interceptor(Arc::new(interceptor));
         self
     }
 
@@ -167,7 +340,7 @@ where
     /// Note: For multi-server deployments, also use [`TwitchOauth<UserAuth>::with_secret_key`] to share
     /// the same secret across all instances.
     pub fn with_csrf_config(mut self, config: CsrfConfig) -> Self {
-        self.csrf_config = config;
+        self.config_mut().csrf_config = config;
         self
     }
 
@@ -175,7 +348,7 @@ where
     ///
     /// Default: `https://id.twitch.tv/oauth2/authorize`
     pub fn with_auth_url(mut self, auth_url: AuthUrl) -> Self {
-        self.auth_url = auth_url;
+        self.config_mut().auth_url = auth_url;
         self
     }
 
@@ -183,7 +356,7 @@ where
     ///
     /// Default: `https://id.twitch.tv/oauth2/token`
     pub fn with_token_url(mut self, token_url: TokenUrl) -> Self {
-        self.token_url = token_url;
+        self.core.set_token_url(token_url);
         self
     }
 
@@ -191,7 +364,7 @@ where
     ///
     /// Default: `https://id.twitch.tv/oauth2/revoke`
     pub fn with_revoke_url(mut self, revoke_url: RevocationUrl) -> Self {
-        self.revoke_url = revoke_url;
+        self.config_mut().revoke_url = revoke_url;
         self
     }
 
@@ -199,48 +372,99 @@ where
     ///
     /// Default: `https://id.twitch.tv/oauth2/validate`
     pub fn with_validate_url(mut self, validate_url: ValidateUrl) -> Self {
-        self.validate_url = validate_url;
+        self.config_mut().validate_url = validate_url;
+        self
+    }
+
+    /// Override the device authorization URL
+    ///
+    /// Default: `https://id.twitch.tv/oauth2/device`
+    pub fn with_device_url(mut self, device_url: DeviceUrl) -> Self {
+        self.core.set_device_url(device_url);
         self
     }
 
     /// Update the client secret at runtime
     ///
-    /// Use this when you need to rotate credentials in a running application,
-    /// especially in `Arc<RwLock<TwitchOauth>>` patterns.
+    /// Use this when you need to rotate credentials in a running application.
+    /// To rotate them without putting a lock on every request, use
+    /// `reload::OauthHandle` (requires `reload` feature).
     ///
     /// For initial configuration during construction, use [`TwitchOauth::new`] instead.
     pub fn set_client_secret(&mut self, client_secret: ClientSecret) {
-        self.client_secret = client_secret;
+        self.client_secret = Arc::new(client_secret);
     }
 
     /// Update CSRF token validation settings at runtime
     ///
-    /// Use this to adjust CSRF validation behavior in a running application,
-    /// especially in `Arc<RwLock<TwitchOauth>>` patterns.
+    /// Use this to adjust CSRF validation behavior in a running application.
+    /// To change it without putting a lock on every request, use
+    /// `reload::OauthHandle` (requires `reload` feature).
     ///
     /// For initial configuration during construction, use [`TwitchOauth::with_csrf_config`] instead.
     pub fn set_csrf_config(&mut self, config: CsrfConfig) {
-        self.csrf_config = config;
+        self.config_mut().csrf_config = config;
     }
 
-    pub async fn send<T>(&self, request: T) -> Result<reqwest::Response, T::Error>
+    /// Send a request through this client's transport
+    ///
+    /// Non-success responses are returned as OAuth errors carrying the status
+    /// code and body.
+    pub async fn send<T>(&self, request: T) -> Result<HttpResponse, Error>
     where
-        T: IntoRequestBuilder<Error = Error>,
+        T: IntoHttpRequest,
     {
-        let resp = request
-            .into_request_builder(&self.client)?
-            .send()
-            .await
-            .map_err(error::network::request)?;
+        self.core.send(request).await
+    }
 
-        if !resp.status().is_success() {
-            let status = resp.status().as_u16();
-            let v = resp.bytes().await?;
-            let body = String::from_utf8_lossy(&v).to_string();
-            return Err(error::oauth::http_error(status, body));
-        }
+    /// Request for [`TwitchOauth::refresh_access_token`]
+    ///
+    /// Requests borrow this client's configuration; call `into_owned()` to move
+    /// one into a spawned task or a queue, then pass it to [`TwitchOauth::send`]
+    /// and decode with [`WithMetadata::from_response`].
+    ///
+    /// ```no_run
+    /// # use twitch_oauth_token::{TwitchOauth, RefreshToken, UserToken, WithMetadata};
+    /// # async fn run(oauth: TwitchOauth, refresh_token: RefreshToken) -> Result<(), twitch_oauth_token::Error> {
+    /// let request = oauth.refresh_request(refresh_token).into_owned();
+    /// let handle = tokio::spawn(async move {
+    ///     let resp = oauth.send(request).await?;
+    ///     WithMetadata::<UserToken>::from_response(resp)
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn refresh_request(&self, refresh_token: RefreshToken) -> RefreshRequest<'_> {
+        RefreshRequest::new(
+            &self.core.config.client_id,
+            &self.client_secret,
+            refresh_token,
+            &self.core.config.token_url,
+        )
+    }
+
+    /// Request for [`TwitchOauth::revoke_access_token`], see [`TwitchOauth::refresh_request`]
+    pub fn revoke_request<'a>(&'a self, access_token: &'a AccessToken) -> RevokeRequest<'a> {
+        RevokeRequest::new(
+            access_token,
+            &self.core.config.client_id,
+            &self.core.config.revoke_url,
+        )
+    }
 
-        Ok(resp)
+    /// Request for [`TwitchOauth::app_access_token`], see [`TwitchOauth::refresh_request`]
+    pub fn app_access_token_request(&self) -> ClientCredentialsRequest<'_> {
+        ClientCredentialsRequest::new(
+            &self.core.config.client_id,
+            &self.client_secret,
+            GrantType::ClientCredentials,
+            &self.core.config.token_url,
+        )
+    }
+
+    /// Request for [`TwitchOauth::validate_access_token`], see [`TwitchOauth::refresh_request`]
+    pub fn validate_request<'a>(&'a self, access_token: &'a AccessToken) -> ValidateRequest<'a> {
+        ValidateRequest::new(access_token, &self.core.config.validate_url)
     }
 
     /// **Refresh an access token** using a refresh token
@@ -259,16 +483,27 @@ where
         &self,
         refresh_token: RefreshToken,
     ) -> Result<crate::UserToken, Error> {
-        let resp = self
-            .send(RefreshRequest::new(
-                &self.client_id,
-                &self.client_secret,
-                refresh_token,
-                &self.token_url,
-            ))
-            .await?;
+        self.refresh_access_token_with_metadata(refresh_token)
+            .await
+            .map(WithMetadata::into_body)
+    }
+
+    /// [`TwitchOauth::refresh_access_token`] with the response metadata
+    pub async fn refresh_access_token_with_metadata(
+        &self,
+        refresh_token: RefreshToken,
+    ) -> Result<WithMetadata<crate::UserToken>, Error> {
+        telemetry::observe(
+            Operation::RefreshToken,
+            &self.core.config.client_id,
+            Some(fingerprint(refresh_token.secret())),
+            async {
+                let resp = self.send(self.refresh_request(refresh_token)).await?;
 
-        decode_response(resp).await
+                WithMetadata::from_response(resp)
+            },
+        )
+        .await
     }
 
     /// **Revoke/invalidate an access token**
@@ -290,15 +525,30 @@ where
     ///
     /// <https://dev.twitch.tv/docs/authentication/revoke-tokens/>
     pub async fn revoke_access_token(&self, access_token: &AccessToken) -> Result<(), Error> {
-        let _resp = self
-            .send(RevokeRequest::new(
-                access_token,
-                &self.client_id,
-                &self.revoke_url,
-            ))
-            .await?;
+        self.revoke_access_token_with_metadata(access_token)
+            .await
+            .map(WithMetadata::into_body)
+    }
+
+    /// [`TwitchOauth::revoke_access_token`] with the response metadata
+    pub async fn revoke_access_token_with_metadata(
+        &self,
+        access_token: &AccessToken,
+    ) -> Result<WithMetadata<()>, Error> {
+        telemetry::observe(
+            Operation::Revoke,
+            &self.core.config.client_id,
+            Some(fingerprint(access_token.secret())),
+            async {
+                let resp = self.send(self.revoke_request(access_token)).await?;
 
-        Ok(())
+                Ok(WithMetadata {
+                    body: (),
+                    metadata: ResponseMetadata::from_response(&resp),
+                })
+            },
+        )
+        .await
     }
 
     /// **Get an app access token** (Client Credentials Flow)
@@ -320,16 +570,68 @@ where
     ///
     /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#client-credentials-grant-flow>
     pub async fn app_access_token(&self) -> Result<crate::AppToken, Error> {
-        let resp = self
-            .send(ClientCredentialsRequest::new(
-                &self.client_id,
-                &self.client_secret,
-                GrantType::ClientCredentials,
-                &self.token_url,
-            ))
-            .await?;
+        self.app_access_token_with_metadata()
+            .await
+            .map(WithMetadata::into_body)
+    }
+
+    /// [`TwitchOauth::app_access_token`] with the response metadata
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use twitch_oauth_token::TwitchOauth;
+    /// # async fn run(oauth: TwitchOauth) -> Result<(), twitch_oauth_token::Error> {
+    /// let resp = oauth.app_access_token_with_metadata().await?;
+    /// if let Some(rate_limit) = resp.metadata.rate_limit {
+    ///     println!("{} requests left", rate_limit.remaining);
+    /// }
+    /// let token = resp.into_body();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn app_access_token_with_metadata(
+        &self,
+    ) -> Result<WithMetadata<crate::AppToken>, Error> {
+        telemetry::observe(
+            Operation::ClientCredentials,
+            &self.core.config.client_id,
+            None,
+            async {
+                let resp = self.send(self.app_access_token_request()).await?;
 
-        decode_response(resp).await
+                WithMetadata::from_response(resp)
+            },
+        )
+        .await
+    }
+
+    /// **Start a device code grant flow** sharing this client's configuration
+    ///
+    /// The returned [`DeviceAuth`] uses the same HTTP client, endpoints and error
+    /// handling as the other grants. The client secret is never sent.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use twitch_oauth_token::TwitchOauth;
+    /// # async fn run(oauth: TwitchOauth) -> Result<(), twitch_oauth_token::Error> {
+    /// let mut device_flow = oauth.device_flow();
+    /// device_flow.scopes_mut().chat_api();
+    ///
+    /// let resp = device_flow.request().await?;
+    /// println!("Visit: {}", resp.verification_uri);
+    ///
+    /// let token = device_flow.poll(resp).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#device-code-grant-flow>
+    pub fn device_flow(&self) -> DeviceAuth {
+        DeviceAuth::from_core(self.core.clone())
+    }
+
+    fn config_mut(&mut self) -> &mut Config {
+        self.core.config_mut()
     }
 
     /// **Validate access token**
@@ -348,45 +650,45 @@ where
         &self,
         access_token: &AccessToken,
     ) -> Result<crate::TokenInfo, Error> {
-        let resp = self
-            .send(ValidateRequest::new(access_token, &self.validate_url))
-            .await?;
+        self.validate_access_token_with_metadata(access_token)
+            .await
+            .map(WithMetadata::into_body)
+    }
 
-        decode_response(resp).await
+    /// [`TwitchOauth::validate_access_token`] with the response metadata
+    pub async fn validate_access_token_with_metadata(
+        &self,
+        access_token: &AccessToken,
+    ) -> Result<WithMetadata<crate::TokenInfo>, Error> {
+        telemetry::observe(
+            Operation::Validate,
+            &self.core.config.client_id,
+            Some(fingerprint(access_token.secret())),
+            async {
+                let resp = self.send(self.validate_request(access_token)).await?;
+
+                WithMetadata::from_response(resp)
+            },
+        )
+        .await
     }
 }
 
 impl TwitchOauth<AppAuth> {
     /// Create OAuth client for app authentication
     pub fn new(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
-        Self {
-            client_id: ClientId::from(client_id.into()),
-            client_secret: ClientSecret::from(client_secret.into()),
-            redirect_uri: (),
-            secret_key: csrf::generate_secret_key(),
-            token_url: TokenUrl::from_str(TOKEN_URL).unwrap(),
-            auth_url: AuthUrl::from_str(AUTH_URL).unwrap(),
-            revoke_url: RevocationUrl::from_str(REVOKE_URL).unwrap(),
-            validate_url: ValidateUrl::from_str(VALIDATE_URL).unwrap(),
-            client: crate::client::get().clone(),
-            csrf_config: CsrfConfig::default(),
-            phanthom: PhantomData,
-        }
+        Self::from_credentials(
+            ClientId::from(client_id.into()),
+            ClientSecret::from(client_secret.into()),
+        )
     }
 
     /// Upgrade to user authentication by adding redirect URI
     pub fn with_redirect_uri(self, redirect_uri: RedirectUrl) -> TwitchOauth<UserAuth> {
         TwitchOauth {
-            client_id: self.client_id,
+            core: self.core,
             client_secret: self.client_secret,
             redirect_uri,
-            secret_key: self.secret_key,
-            token_url: self.token_url,
-            auth_url: self.auth_url,
-            revoke_url: self.revoke_url,
-            validate_url: self.validate_url,
-            client: self.client,
-            csrf_config: self.csrf_config,
             phanthom: PhantomData,
         }
     }
@@ -396,21 +698,17 @@ impl TwitchOauth<AppAuth> {
     /// Most users should use [`TwitchOauth::new()`] instead.
     pub fn from_credentials(client_id: ClientId, client_secret: ClientSecret) -> Self {
         Self {
-            client_id,
-            client_secret,
+            core: Core::new(client_id),
+            client_secret: Arc::new(client_secret),
             redirect_uri: (),
-            secret_key: csrf::generate_secret_key(),
-            client: crate::client::get().clone(),
-            token_url: TokenUrl::from_str(TOKEN_URL).unwrap(),
-            auth_url: AuthUrl::from_str(AUTH_URL).unwrap(),
-            revoke_url: RevocationUrl::from_str(REVOKE_URL).unwrap(),
-            validate_url: ValidateUrl::from_str(VALIDATE_URL).unwrap(),
-            csrf_config: CsrfConfig::default(),
             phanthom: PhantomData,
         }
     }
 
     /// Create Device code grant flow
+    ///
+    /// Uses the default configuration. To share an existing client's HTTP client
+    /// and endpoints, use [`TwitchOauth::device_flow`] instead.
     pub fn device_auth(client_id: ClientId) -> DeviceAuth {
         DeviceAuth::new(client_id)
     }
@@ -450,10 +748,14 @@ impl TwitchOauth<UserAuth> {
     /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#authorization-code-grant-flow>
     pub fn authorization_url<'a>(&'a self) -> AuthrozationRequest<'a> {
         AuthrozationRequest::new(
-            &self.auth_url,
-            &self.client_id,
+            &self.core.config.auth_url,
+            &self.core.config.client_id,
             &self.redirect_uri,
-            csrf::generate(&self.secret_key, Some(&self.client_id)),
+            csrf::generate_at_time(
+                &self.core.config.secret_key,
+                Some(&self.core.config.client_id),
+                clock::now(),
+            ),
         )
     }
 
@@ -490,28 +792,58 @@ impl TwitchOauth<UserAuth> {
         code: AuthorizationCode,
         state: String,
     ) -> Result<crate::UserToken, Error> {
-        if csrf::verify_with_config(
-            &self.secret_key,
-            &state,
-            Some(&self.client_id),
-            &self.csrf_config,
+        self.exchange_code_with_metadata(code, state)
+            .await
+            .map(WithMetadata::into_body)
+    }
+
+    /// Request for [`TwitchOauth::exchange_code`], see [`TwitchOauth::refresh_request`]
+    ///
+    /// The CSRF `state` is verified here, so a request that was built can be sent
+    /// later without checking it again.
+    pub fn exchange_code_request(
+        &self,
+        code: AuthorizationCode,
+        state: &str,
+    ) -> Result<ExchangeCodeRequest<'_>, Error> {
+        if csrf::verify_at_time(
+            &self.core.config.secret_key,
+            state,
+            Some(&self.core.config.client_id),
+            clock::now(),
+            &self.core.config.csrf_config,
         )
         .is_err()
         {
             return Err(error::oauth::csrf_token_mismatch());
         }
 
-        let resp = self
-            .send(ExchangeCodeRequest::new(
-                &self.client_id,
-                &self.client_secret,
-                code,
-                &self.redirect_uri,
-                &self.token_url,
-            ))
-            .await?;
+        Ok(ExchangeCodeRequest::new(
+            &self.core.config.client_id,
+            &self.client_secret,
+            code,
+            &self.redirect_uri,
+            &self.core.config.token_url,
+        ))
+    }
 
-        decode_response(resp).await
+    /// [`TwitchOauth::exchange_code`] with the response metadata
+    pub async fn exchange_code_with_metadata(
+        &self,
+        code: AuthorizationCode,
+        state: String,
+    ) -> Result<WithMetadata<crate::UserToken>, Error> {
+        telemetry::observe(
+            Operation::ExchangeCode,
+            &self.core.config.client_id,
+            None,
+            async {
+                let resp = self.send(self.exchange_code_request(code, &state)?).await?;
+
+                WithMetadata::from_response(resp)
+            },
+        )
+        .await
     }
 
     /// Set custom secret key for CSRF token generation
@@ -536,18 +868,81 @@ impl TwitchOauth<UserAuth> {
     /// # }
     /// ```
     pub fn with_secret_key(mut self, secret_key: [u8; 32]) -> Self {
-        self.secret_key = secret_key;
+        self.config_mut().secret_key = secret_key;
         self
     }
 
     /// Update the CSRF secret key at runtime
     ///
-    /// Use this to rotate CSRF secret keys in a running application,
-    /// especially in `Arc<RwLock<TwitchOauth>>` patterns or multi-server deployments.
+    /// Use this to rotate CSRF secret keys in a running application or across
+    /// multi-server deployments. To rotate them without putting a lock on every
+    /// request, use `reload::OauthHandle` (requires `reload` feature).
     ///
     /// For initial configuration during construction, use [`TwitchOauth::with_secret_key`] instead.
     pub fn set_secret_key(&mut self, secret_key: [u8; 32]) {
-        self.secret_key = secret_key;
+        self.config_mut().secret_key = secret_key;
+    }
+
+    /// **Log in from a CLI or desktop app**
+    ///
+    /// Binds a loopback listener on the redirect URI's host and port, opens the
+    /// browser, waits for the callback, validates `state` and exchanges the code.
+    /// The redirect URI must be `http://localhost`, `http://127.0.0.1` or `http://[::1]`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use std::{str::FromStr, time::Duration};
+    /// use twitch_oauth_token::{RedirectUrl, TwitchOauth};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let oauth = TwitchOauth::new("client_id", "client_secret")
+    ///     .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000/auth/callback")?);
+    ///
+    /// let mut login = oauth.interactive_login();
+    /// login.scopes_mut().chat_api();
+    ///
+    /// let token = login.with_timeout(Duration::from_secs(120)).run().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "login")]
+    pub fn interactive_login(&self) -> crate::InteractiveLogin<'_> {
+        crate::InteractiveLogin::new(self)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<Flow> TwitchOauth<Flow>
+where
+    Flow: OauthFlow,
+{
+    /// Synchronous version of this client, see [`crate::blocking`]
+    pub fn into_blocking(self) -> crate::blocking::TwitchOauth<Flow> {
+        crate::blocking::TwitchOauth::from(self)
+    }
+}
+
+#[cfg(feature = "reload")]
+impl<Flow> TwitchOauth<Flow>
+where
+    Flow: OauthFlow,
+{
+    /// Share this client through a hot-reloadable handle, see [`crate::reload`]
+    pub fn into_handle(self) -> crate::reload::OauthHandle<Flow>
+    where
+        Self: Clone,
+    {
+        crate::reload::OauthHandle::new(self)
+    }
+
+    pub(crate) fn csrf_config(&self) -> CsrfConfig {
+        self.core.config.csrf_config
+    }
+
+    /// [`TwitchOauth::with_secret_key`] for any flow, used by [`crate::reload`]
+    pub(crate) fn replace_secret_key(mut self, secret_key: [u8; 32]) -> Self {
+        self.config_mut().secret_key = secret_key;
+        self
     }
 }
 
@@ -563,7 +958,7 @@ where
 
 impl Display for TwitchOauth<AppAuth> {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
-        write!(f, "TwitchOauth(client_id: {})", self.client_id)
+        write!(f, "TwitchOauth(client_id: {})", self.core.config.client_id)
     }
 }
 
@@ -572,7 +967,7 @@ impl Display for TwitchOauth<UserAuth> {
         write!(
             f,
             "TwitchOauth(client_id: {}, redirect_uri: {})",
-            self.client_id, self.redirect_uri
+            self.core.config.client_id, self.redirect_uri
         )
     }
 }
@@ -583,20 +978,53 @@ where
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("TwitchOauth")
-            .field("client_id", &self.client_id)
-            .field("client_secret", &self.client_secret)
+            .field("client_id", &self.core.config.client_id)
             .field("redirect_uri", &self.redirect_uri)
-            .field("token_url", &self.token_url)
-            .field("auth_url", &self.auth_url)
-            .field("revoke_url", &self.revoke_url)
-            .finish()
+            .field("token_url", &self.core.config.token_url)
+            .field("auth_url", &self.core.config.auth_url)
+            .field("revoke_url", &self.core.config.revoke_url)
+            .field("device_url", &self.core.config.device_url)
+            .finish_non_exhaustive()
     }
 }
 
-pub(crate) async fn decode_response<T>(resp: reqwest::Response) -> Result<T, Error>
+impl Debug for Core {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Core")
+            .field("client_id", &self.config.client_id)
+            .field("token_url", &self.config.token_url)
+            .field("device_url", &self.config.device_url)
+            .finish_non_exhaustive()
+    }
+}
+
+pub(crate) async fn send<T, R>(transport: &T, request: R) -> Result<HttpResponse, Error>
+where
+    T: HttpTransport + ?Sized,
+    R: IntoHttpRequest,
+{
+    let resp = transport
+        .send(request.into_http_request()?)
+        .await
+        .map_err(error::network::request)?;
+
+    check_status(resp)
+}
+
+fn check_status(resp: HttpResponse) -> Result<HttpResponse, Error> {
+    if !resp.status().is_success() {
+        let status = resp.status().as_u16();
+        let body = String::from_utf8_lossy(resp.body()).to_string();
+        return Err(error::oauth::http_error(status, body));
+    }
+
+    Ok(resp)
+}
+
+pub(crate) fn decode_response<T>(resp: HttpResponse) -> Result<T, Error>
 where
     T: serde::de::DeserializeOwned,
 {
-    let v = resp.bytes().await?;
-    serde_json::from_slice(&v).map_err(|e| error::response::decode(e, String::from_utf8_lossy(&v)))
+    let v = resp.body();
+    serde_json::from_slice(v).map_err(|e| error::response::decode(e, String::from_utf8_lossy(v)))
 }
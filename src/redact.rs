@@ -0,0 +1,201 @@
+//! Keeping secrets out of logs, interceptors and errors
+
+use http::{
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    HeaderMap, HeaderValue,
+};
+use sha2::{Digest, Sha256};
+
+use crate::transport::HttpRequest;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Form and JSON fields holding secrets
+const SECRET_FIELDS: &[&str] = &[
+    "client_secret",
+    "code",
+    "refresh_token",
+    "access_token",
+    "token",
+    "device_code",
+];
+
+/// First 8 hex digits of the SHA-256 of `secret`
+///
+/// Identifies a token across log lines without revealing it.
+pub(crate) fn fingerprint(secret: &str) -> String {
+    hex::encode(&Sha256::digest(secret.as_bytes())[..4])
+}
+
+pub(crate) fn redact_headers(headers: &HeaderMap) -> HeaderMap {
+    let mut headers = headers.clone();
+    if headers.contains_key(AUTHORIZATION) {
+        headers.insert(AUTHORIZATION, HeaderValue::from_static(REDACTED));
+    }
+    headers
+}
+
+pub(crate) fn redact_request_body(request: &HttpRequest) -> String {
+    let is_form = request.headers().get(CONTENT_TYPE).is_some_and(|v| {
+        v.as_bytes()
+            .starts_with(b"application/x-www-form-urlencoded")
+    });
+
+    if is_form {
+        redact_form(request.body())
+    } else {
+        redact_json(request.body())
+    }
+}
+
+pub(crate) fn redact_form(body: &[u8]) -> String {
+    let Ok(fields) = serde_urlencoded::from_bytes::<Vec<(String, String)>>(body) else {
+        return REDACTED.to_string();
+    };
+
+    // Joined by hand so the placeholder isn't percent-encoded
+    fields
+        .iter()
+        .map(|(key, value)| {
+            if SECRET_FIELDS.contains(&key.as_str()) {
+                format!("{key}={REDACTED}")
+            } else {
+                serde_urlencoded::to_string([(key, value)]).unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Redacts secret fields of a JSON body; other bodies are returned as text
+///
+/// Bodies without secret fields are returned unchanged. Bodies that don't parse,
+/// e.g. truncated ones, still have the string values of secret fields redacted.
+pub(crate) fn redact_json(body: &[u8]) -> String {
+    fn redact(value: &mut serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::Object(map) => {
+                let mut redacted = false;
+                for (key, value) in map.iter_mut() {
+                    if SECRET_FIELDS.contains(&key.as_str()) {
+                        *value = serde_json::Value::from(REDACTED);
+                        redacted = true;
+                    } else {
+                        redacted |= redact(value);
+                    }
+                }
+                redacted
+            }
+            serde_json::Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |redacted, value| redact(value) | redacted),
+            _ => false,
+        }
+    }
+
+    if let Ok(mut value) = serde_json::from_slice::<serde_json::Value>(body) {
+        if redact(&mut value) {
+            return value.to_string();
+        }
+    }
+    redact_text(&String::from_utf8_lossy(body))
+}
+
+/// Replaces the value of every `"<secret field>": "..."` in `text`
+///
+/// Unterminated values are redacted up to the end of `text`.
+fn redact_text(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('"') {
+        let after_quote = &rest[start + 1..];
+        let Some(key) = SECRET_FIELDS.iter().find(|field| {
+            after_quote
+                .strip_prefix(**field)
+                .is_some_and(|tail| tail.starts_with('"'))
+        }) else {
+            out.push_str(&rest[..=start]);
+            rest = after_quote;
+            continue;
+        };
+
+        let after_key = &after_quote[key.len() + 1..];
+        let Some(value) = after_key
+            .trim_start()
+            .strip_prefix(':')
+            .map(str::trim_start)
+            .and_then(|tail| tail.strip_prefix('"'))
+        else {
+            let end = start + 1 + key.len() + 1;
+            out.push_str(&rest[..end]);
+            rest = &rest[end..];
+            continue;
+        };
+
+        // Everything up to and including the value's opening quote
+        let prefix_len = rest.len() - value.len();
+        out.push_str(&rest[..prefix_len]);
+        out.push_str(REDACTED);
+
+        let mut escaped = false;
+        let end = value.char_indices().find_map(|(i, c)| match c {
+            _ if escaped => {
+                escaped = false;
+                None
+            }
+            '\\' => {
+                escaped = true;
+                None
+            }
+            '"' => Some(i),
+            _ => None,
+        });
+        rest = match end {
+            Some(end) => &value[end..],
+            None => "",
+        };
+    }
+
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fingerprint, redact_form, redact_json};
+
+    #[test]
+    fn redacts_secret_fields() {
+        assert_eq!(
+            redact_form(b"client_id=abc&client_secret=s3cr3t&grant_type=refresh_token&refresh_token=r%26t"),
+            "client_id=abc&client_secret=[REDACTED]&grant_type=refresh_token&refresh_token=[REDACTED]"
+        );
+        assert_eq!(
+            redact_json(br#"{"access_token":"abc","scope":["chat:read"],"expires_in":1}"#),
+            r#"{"access_token":"[REDACTED]","expires_in":1,"scope":["chat:read"]}"#
+        );
+        assert_eq!(
+            redact_json(br#"{"status":400, "message":"authorization_pending"}"#),
+            r#"{"status":400, "message":"authorization_pending"}"#
+        );
+        assert_eq!(redact_json(b"<html>"), "<html>");
+        assert_eq!(
+            redact_json(br#"{"access_token":"abc"#),
+            r#"{"access_token":"[REDACTED]"#
+        );
+        assert_eq!(
+            redact_json(br#"{"code": "a\"b", "token" :"t", "message":"code", "refresh_token":1"#),
+            r#"{"code": "[REDACTED]", "token" :"[REDACTED]", "message":"code", "refresh_token":1"#
+        );
+
+        let err = crate::error::response::decode("EOF while parsing", r#"{"access_token":"abc"#);
+        assert_eq!(err.raw(), Some(r#"{"access_token":"[REDACTED]"#));
+        let err = crate::error::oauth::http_error(500, r#"{"refresh_token":"abc"#);
+        assert_eq!(err.raw(), Some(r#"{"refresh_token":"[REDACTED]"#));
+
+        assert_eq!(fingerprint("token").len(), 8);
+        assert_eq!(fingerprint("token"), fingerprint("token"));
+        assert_ne!(fingerprint("token"), fingerprint("other"));
+    }
+}
@@ -0,0 +1,435 @@
+//! Hot-reloadable configuration (requires `reload` feature)
+//!
+//! [`OauthHandle`] holds the current [`TwitchOauth`] in an atomically swappable
+//! slot. Requests read it without locking; the client secret, CSRF key and
+//! config, endpoints and HTTP client are changed by swapping in a reconfigured
+//! copy, so in-flight requests finish with the configuration they started with.
+//!
+//! ```no_run
+//! use twitch_oauth_token::{ClientSecret, TwitchOauth};
+//!
+//! # async fn run() -> Result<(), twitch_oauth_token::Error> {
+//! let handle = TwitchOauth::new("client_id", "client_secret").into_handle();
+//!
+//! // Request path: lock-free
+//! let token = handle.load().app_access_token().await?;
+//!
+//! // Rotation, e.g. from an admin endpoint
+//! handle.update(|oauth| {
+//!     let mut oauth = oauth.clone();
+//!     oauth.set_client_secret(ClientSecret::from("rotated".to_string()));
+//!     oauth
+//! });
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Config File
+//!
+//! [`OauthHandle::watch`] reloads a JSON file whenever its contents change. Every field is
+//! optional; missing fields keep their current value:
+//!
+//! ```json
+//! {
+//!   "client_secret": "...",
+//!   "csrf_secret_key": "<64 hex digits>",
+//!   "csrf_max_age": 1800,
+//!   "csrf_clock_skew": 30,
+//!   "auth_url": "https://id.twitch.tv/oauth2/authorize",
+//!   "token_url": "https://id.twitch.tv/oauth2/token",
+//!   "revoke_url": "https://id.twitch.tv/oauth2/revoke",
+//!   "validate_url": "https://id.twitch.tv/oauth2/validate",
+//!   "device_url": "https://id.twitch.tv/oauth2/device"
+//! }
+//! ```
+//!
+//! Rotating `csrf_secret_key` invalidates the `state` of authorization URLs
+//! issued before the rotation.
+
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+use arc_swap::ArcSwap;
+use serde::{Deserialize, Deserializer};
+use sha2::{Digest, Sha256};
+use tracing::{debug, info, warn};
+
+use crate::{
+    csrf::CsrfConfig, error, oauth::OauthFlow, AppAuth, AuthUrl, ClientSecret, DeviceUrl, Error,
+    RevocationUrl, TokenUrl, TwitchOauth, ValidateUrl,
+};
+
+/// Shared [`TwitchOauth`] whose configuration can be swapped at runtime
+///
+/// Clones refer to the same slot.
+pub struct OauthHandle<Flow = AppAuth>
+where
+    Flow: OauthFlow,
+{
+    current: Arc<ArcSwap<TwitchOauth<Flow>>>,
+}
+
+impl<Flow> OauthHandle<Flow>
+where
+    Flow: OauthFlow,
+    TwitchOauth<Flow>: Clone,
+{
+    pub fn new(oauth: TwitchOauth<Flow>) -> Self {
+        Self {
+            current: Arc::new(ArcSwap::from_pointee(oauth)),
+        }
+    }
+
+    /// The current client, without locking
+    pub fn load(&self) -> Arc<TwitchOauth<Flow>> {
+        self.current.load_full()
+    }
+
+    /// Replace the current client
+    pub fn store(&self, oauth: TwitchOauth<Flow>) {
+        self.current.store(Arc::new(oauth));
+    }
+
+    /// Swap in a client derived from the current one
+    ///
+    /// `f` may run more than once if another update races with this one.
+    pub fn update<F>(&self, f: F)
+    where
+        F: Fn(&TwitchOauth<Flow>) -> TwitchOauth<Flow>,
+    {
+        self.current.rcu(|current| f(current));
+    }
+
+    /// Apply the fields set in `config`
+    pub fn apply(&self, config: &ReloadConfig) {
+        self.update(|oauth| config.apply(oauth.clone()));
+    }
+
+    /// Reload `path` now and whenever its contents change
+    ///
+    /// The file is read every `interval` on a background thread, so file I/O never
+    /// blocks the async runtime, and compared by SHA-256 digest. Files that can't
+    /// be read or parsed are logged and skipped, keeping the previous
+    /// configuration; an unavailable file is logged once, not on every poll.
+    /// Watching stops when the returned [`ConfigWatcher`] is dropped.
+    pub fn watch(&self, path: impl Into<PathBuf>, interval: Duration) -> ConfigWatcher
+    where
+        TwitchOauth<Flow>: Send + Sync + 'static,
+    {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let handle = self.clone();
+        let path = path.into();
+        let stop = stopped.clone();
+
+        thread::spawn(move || {
+            let mut last: Option<[u8; 32]> = None;
+            // Only log changes in availability, not every failed poll
+            let mut unavailable: Option<io::ErrorKind> = None;
+            while !stop.load(Ordering::Relaxed) {
+                match fs::read(&path) {
+                    Ok(json) => {
+                        if unavailable.take().is_some() {
+                            info!(path = %path.display(), "configuration file available again");
+                        }
+                        let digest = Sha256::digest(&json).into();
+                        if last != Some(digest) {
+                            last = Some(digest);
+                            match ReloadConfig::from_slice(&json) {
+                                Ok(config) => {
+                                    handle.apply(&config);
+                                    debug!(path = %path.display(), "configuration reloaded");
+                                }
+                                Err(e) => {
+                                    warn!(path = %path.display(), error = %e, "configuration reload failed")
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        if unavailable != Some(e.kind()) {
+                            unavailable = Some(e.kind());
+                            warn!(path = %path.display(), error = %e, "configuration file unavailable");
+                        }
+                    }
+                }
+
+                thread::sleep(interval);
+            }
+        });
+
+        ConfigWatcher { stopped }
+    }
+}
+
+impl<Flow> Clone for OauthHandle<Flow>
+where
+    Flow: OauthFlow,
+{
+    fn clone(&self) -> Self {
+        Self {
+            current: self.current.clone(),
+        }
+    }
+}
+
+impl<Flow> Debug for OauthHandle<Flow>
+where
+    Flow: OauthFlow,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("OauthHandle")
+            .field(&**self.current.load())
+            .finish()
+    }
+}
+
+impl<Flow> From<TwitchOauth<Flow>> for OauthHandle<Flow>
+where
+    Flow: OauthFlow,
+    TwitchOauth<Flow>: Clone,
+{
+    fn from(oauth: TwitchOauth<Flow>) -> Self {
+        Self::new(oauth)
+    }
+}
+
+/// Stops [`OauthHandle::watch`] when dropped
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    stopped: Arc<AtomicBool>,
+}
+
+impl ConfigWatcher {
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Reloadable settings, see the [module docs](self) for the file format
+#[derive(Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReloadConfig {
+    pub client_secret: Option<ClientSecret>,
+    #[serde(deserialize_with = "secret_key")]
+    pub csrf_secret_key: Option<[u8; 32]>,
+    pub csrf_max_age: Option<u64>,
+    /// `0` disables clock skew tolerance
+    pub csrf_clock_skew: Option<u64>,
+    #[serde(deserialize_with = "url")]
+    pub auth_url: Option<AuthUrl>,
+    #[serde(deserialize_with = "url")]
+    pub token_url: Option<TokenUrl>,
+    #[serde(deserialize_with = "url")]
+    pub revoke_url: Option<RevocationUrl>,
+    #[serde(deserialize_with = "url")]
+    pub validate_url: Option<ValidateUrl>,
+    #[serde(deserialize_with = "url")]
+    pub device_url: Option<DeviceUrl>,
+}
+
+impl ReloadConfig {
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let json = fs::read(path).map_err(error::reload::read)?;
+        Self::from_slice(&json)
+    }
+
+    pub fn from_slice(json: &[u8]) -> Result<Self, Error> {
+        serde_json::from_slice(json).map_err(error::reload::parse)
+    }
+
+    /// Apply the fields that are set
+    pub fn apply<Flow>(&self, mut oauth: TwitchOauth<Flow>) -> TwitchOauth<Flow>
+    where
+        Flow: OauthFlow,
+    {
+        if let Some(client_secret) = &self.client_secret {
+            oauth.set_client_secret(client_secret.clone());
+        }
+        if let Some(secret_key) = self.csrf_secret_key {
+            oauth = oauth.replace_secret_key(secret_key);
+        }
+        if self.csrf_max_age.is_some() || self.csrf_clock_skew.is_some() {
+            let current = oauth.csrf_config();
+            oauth.set_csrf_config(CsrfConfig {
+                max_age: self.csrf_max_age.unwrap_or(current.max_age),
+                clock_skew: match self.csrf_clock_skew {
+                    Some(clock_skew) => (clock_skew > 0).then_some(clock_skew),
+                    None => current.clock_skew,
+                },
+            });
+        }
+        if let Some(auth_url) = &self.auth_url {
+            oauth = oauth.with_auth_url(auth_url.clone());
+        }
+        if let Some(token_url) = &self.token_url {
+            oauth = oauth.with_token_url(token_url.clone());
+        }
+        if let Some(revoke_url) = &self.revoke_url {
+            oauth = oauth.with_revoke_url(revoke_url.clone());
+        }
+        if let Some(validate_url) = &self.validate_url {
+            oauth = oauth.with_validate_url(validate_url.clone());
+        }
+        if let Some(device_url) = &self.device_url {
+            oauth = oauth.with_device_url(device_url.clone());
+        }
+        oauth
+    }
+}
+
+impl Debug for ReloadConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ReloadConfig")
+            .field("client_secret", &self.client_secret)
+            .field(
+                "csrf_secret_key",
+                &self.csrf_secret_key.map(|_| crate::redact::REDACTED),
+            )
+            .field("csrf_max_age", &self.csrf_max_age)
+            .field("csrf_clock_skew", &self.csrf_clock_skew)
+            .field("auth_url", &self.auth_url)
+            .field("token_url", &self.token_url)
+            .field("revoke_url", &self.revoke_url)
+            .field("validate_url", &self.validate_url)
+            .field("device_url", &self.device_url)
+            .finish()
+    }
+}
+
+fn url<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = url::ParseError>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|url| T::from_str(&url).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+fn secret_key<'de, D>(deserializer: D) -> Result<Option<[u8; 32]>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|key| {
+            hex::decode(key)
+                .ok()
+                .and_then(|key| <[u8; 32]>::try_from(key).ok())
+                .ok_or_else(|| serde::de::Error::custom("expected 32 bytes as 64 hex digits"))
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpRequest, HttpResponse, HttpTransport},
+        TwitchOauth,
+    };
+
+    use super::ReloadConfig;
+
+    fn transport() -> (Arc<Mutex<Vec<HttpRequest>>>, impl HttpTransport + 'static) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let transport = from_fn(move |request| {
+            seen.lock().unwrap().push(request);
+            async {
+                Ok::<_, BoxError>(HttpResponse::new(
+                    br#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#
+                        .to_vec(),
+                ))
+            }
+        });
+        (requests, transport)
+    }
+
+    #[tokio::test]
+    async fn swaps_configuration() {
+        let (requests, transport) = transport();
+        let handle = TwitchOauth::new("client_id", "client_secret")
+            .with_transport(transport)
+            .into_handle();
+
+        let before = handle.load();
+        let config: ReloadConfig = serde_json::from_str(
+            r#"{"client_secret":"rotated","token_url":"http://localhost/token","csrf_max_age":60}"#,
+        )
+        .unwrap();
+        handle.apply(&config);
+
+        before.app_access_token().await.unwrap();
+        handle.load().app_access_token().await.unwrap();
+        assert_eq!(handle.load().csrf_config().max_age, 60);
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].uri(), "https://id.twitch.tv/oauth2/token");
+        assert!(String::from_utf8_lossy(requests[0].body()).contains("client_secret=client_secret"));
+        assert_eq!(requests[1].uri(), "http://localhost/token");
+        assert!(String::from_utf8_lossy(requests[1].body()).contains("client_secret=rotated"));
+
+        assert!(serde_json::from_str::<ReloadConfig>(r#"{"token_url":"not a url"}"#).is_err());
+        assert!(serde_json::from_str::<ReloadConfig>(r#"{"csrf_secret_key":"abcd"}"#).is_err());
+        assert!(!format!("{config:?}").contains("rotated"));
+    }
+
+    #[tokio::test]
+    async fn watches_file() {
+        let path =
+            std::env::temp_dir().join(format!("twitch-oauth-reload-{}.json", std::process::id()));
+        fs::write(&path, r#"{"token_url":"http://localhost/a"}"#).unwrap();
+
+        let (requests, transport) = transport();
+        let handle = TwitchOauth::new("client_id", "client_secret")
+            .with_transport(transport)
+            .into_handle();
+        let watcher = handle.watch(&path, Duration::from_millis(10));
+
+        let wait_for = |url: &'static str| {
+            let handle = handle.clone();
+            let requests = requests.clone();
+            async move {
+                let start = Instant::now();
+                loop {
+                    handle.load().app_access_token().await.unwrap();
+                    let last = requests.lock().unwrap().last().unwrap().uri().to_string();
+                    if last == url || start.elapsed() > Duration::from_secs(5) {
+                        return last;
+                    }
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+            }
+        };
+
+        assert_eq!(wait_for("http://localhost/a").await, "http://localhost/a");
+
+        // Same length, likely within the same mtime tick
+        fs::write(&path, r#"{"token_url":"http://localhost/b"}"#).unwrap();
+        assert_eq!(wait_for("http://localhost/b").await, "http://localhost/b");
+
+        drop(watcher);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,9 +1,9 @@
-use std::{collections::HashSet, ops::Deref};
+use std::ops::Deref;
 
 use url::Url;
 
 use crate::{
-    scope::{scopes_mut, Scope, ScopesMut},
+    scope::{scopes_mut, Scopes, ScopesMut},
     types::ResponseType,
     AuthUrl, ClientId, RedirectUrl,
 };
@@ -17,7 +17,7 @@ pub struct AuthrozationRequest<'a> {
     client_id: &'a ClientId,
     force_verify: Option<bool>,
     redirect_url: &'a RedirectUrl,
-    scopes: HashSet<Scope>,
+    scopes: Scopes,
     state: String,
 }
 
@@ -33,7 +33,7 @@ impl<'a> AuthrozationRequest<'a> {
             client_id,
             force_verify: None,
             redirect_url,
-            scopes: HashSet::new(),
+            scopes: Scopes::new(),
             state,
         }
     }
@@ -61,14 +61,7 @@ impl<'a> AuthrozationRequest<'a> {
                 ("state", &self.state),
             ]);
 
-            let scopes = self
-                .scopes
-                .into_iter()
-                .map(|x| x.as_str())
-                .collect::<Vec<_>>()
-                .join(" ");
-
-            query_pairs.append_pair("scope", &scopes);
+            query_pairs.append_pair("scope", &self.scopes.to_string());
 
             if let Some(force_verify) = self.force_verify {
                 query_pairs.append_pair("force_verify", &force_verify.to_string());
@@ -1,22 +1,21 @@
-use std::ops::Deref;
+use std::borrow::Cow;
 
-use asknothingx2_util::api::{mime_type::Application, IntoRequestBuilder, Method};
-use reqwest::{
-    header::{ACCEPT, CONTENT_TYPE},
-    Client, RequestBuilder,
+use crate::{
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
+    types::GrantType,
+    ClientId, ClientSecret, Error, TokenUrl,
 };
 
-use crate::{error, types::GrantType, ClientId, ClientSecret, Error, TokenUrl};
-
-use super::{CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
+use super::{form_post, CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
 
 /// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#client-credentials-grant-flow>
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ClientCredentialsRequest<'a> {
-    client_id: &'a ClientId,
-    client_secret: &'a ClientSecret,
+    client_id: Cow<'a, ClientId>,
+    client_secret: Cow<'a, ClientSecret>,
     grant_type: GrantType,
-    token_url: &'a TokenUrl,
+    token_url: Cow<'a, TokenUrl>,
 }
 
 impl<'a> ClientCredentialsRequest<'a> {
@@ -27,29 +26,37 @@ impl<'a> ClientCredentialsRequest<'a> {
         token_url: &'a TokenUrl,
     ) -> Self {
         Self {
-            client_id,
-            client_secret,
+            client_id: Cow::Borrowed(client_id),
+            client_secret: Cow::Borrowed(client_secret),
             grant_type,
-            token_url,
+            token_url: Cow::Borrowed(token_url),
         }
     }
-}
 
-impl IntoRequestBuilder for ClientCredentialsRequest<'_> {
-    type Error = Error;
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> ClientCredentialsRequest<'static> {
+        ClientCredentialsRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            client_secret: Cow::Owned(self.client_secret.into_owned()),
+            grant_type: self.grant_type,
+            token_url: Cow::Owned(self.token_url.into_owned()),
+        }
+    }
+}
 
-    fn into_request_builder(self, client: &Client) -> Result<RequestBuilder, Error> {
-        let form_string = serde_urlencoded::to_string([
-            (CLIENT_ID, self.client_id.deref()),
-            (CLIENT_SECRET, self.client_secret.secret()),
-            (GRANT_TYPE, self.grant_type.as_str()),
-        ])
-        .map_err(error::validation::form_data)?;
+impl IntoHttpRequest for ClientCredentialsRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::ClientCredentials
+    }
 
-        Ok(client
-            .request(Method::POST, self.token_url.as_str())
-            .header(ACCEPT, Application::Json)
-            .header(CONTENT_TYPE, Application::FormUrlEncoded)
-            .body(form_string))
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        form_post(
+            self.token_url.as_str(),
+            [
+                (CLIENT_ID, self.client_id.as_str()),
+                (CLIENT_SECRET, self.client_secret.secret()),
+                (GRANT_TYPE, self.grant_type.as_str()),
+            ],
+        )
     }
 }
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use crate::{
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
+    ClientId, DeviceUrl, Error, Scopes,
+};
+
+use super::{form_post, CLIENT_ID};
+
+/// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#device-code-grant-flow>
+#[derive(Debug, Clone)]
+pub struct DeviceCodeRequest<'a> {
+    client_id: Cow<'a, ClientId>,
+    scopes: Cow<'a, Scopes>,
+    device_url: Cow<'a, DeviceUrl>,
+}
+
+impl<'a> DeviceCodeRequest<'a> {
+    pub fn new(client_id: &'a ClientId, scopes: &'a Scopes, device_url: &'a DeviceUrl) -> Self {
+        Self {
+            client_id: Cow::Borrowed(client_id),
+            scopes: Cow::Borrowed(scopes),
+            device_url: Cow::Borrowed(device_url),
+        }
+    }
+
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> DeviceCodeRequest<'static> {
+        DeviceCodeRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            scopes: Cow::Owned(self.scopes.into_owned()),
+            device_url: Cow::Owned(self.device_url.into_owned()),
+        }
+    }
+}
+
+impl IntoHttpRequest for DeviceCodeRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::DeviceCode
+    }
+
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        let scopes = self.scopes.to_string();
+        form_post(
+            self.device_url.as_str(),
+            [
+                (CLIENT_ID, self.client_id.as_str()),
+                ("scopes", scopes.as_str()),
+            ],
+        )
+    }
+}
@@ -0,0 +1,64 @@
+use std::borrow::Cow;
+
+use crate::{
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
+    types::GrantType,
+    ClientId, DeviceCode, Error, Scopes, TokenUrl,
+};
+
+use super::{form_post, CLIENT_ID, GRANT_TYPE};
+
+/// <https://dev.twitch.tv/docs/authentication/getting-tokens-oauth/#device-code-grant-flow>
+#[derive(Debug, Clone)]
+pub struct DeviceTokenRequest<'a> {
+    client_id: Cow<'a, ClientId>,
+    device_code: Cow<'a, DeviceCode>,
+    scopes: Cow<'a, Scopes>,
+    token_url: Cow<'a, TokenUrl>,
+}
+
+impl<'a> DeviceTokenRequest<'a> {
+    pub fn new(
+        client_id: &'a ClientId,
+        device_code: &'a DeviceCode,
+        scopes: &'a Scopes,
+        token_url: &'a TokenUrl,
+    ) -> Self {
+        Self {
+            client_id: Cow::Borrowed(client_id),
+            device_code: Cow::Borrowed(device_code),
+            scopes: Cow::Borrowed(scopes),
+            token_url: Cow::Borrowed(token_url),
+        }
+    }
+
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> DeviceTokenRequest<'static> {
+        DeviceTokenRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            device_code: Cow::Owned(self.device_code.into_owned()),
+            scopes: Cow::Owned(self.scopes.into_owned()),
+            token_url: Cow::Owned(self.token_url.into_owned()),
+        }
+    }
+}
+
+impl IntoHttpRequest for DeviceTokenRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::DeviceToken
+    }
+
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        let scopes = self.scopes.to_string();
+        form_post(
+            self.token_url.as_str(),
+            [
+                (CLIENT_ID, self.client_id.as_str()),
+                ("scopes", scopes.as_str()),
+                ("device_code", self.device_code.secret()),
+                (GRANT_TYPE, GrantType::DeviceCode.as_str()),
+            ],
+        )
+    }
+}
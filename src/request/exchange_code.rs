@@ -1,26 +1,21 @@
-use std::ops::Deref;
-
-use asknothingx2_util::api::{mime_type::Application, IntoRequestBuilder, Method};
-use reqwest::{
-    header::{ACCEPT, CONTENT_TYPE},
-    Client,
-};
+use std::borrow::Cow;
 
 use crate::{
-    error::{self},
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
     types::GrantType,
     AuthorizationCode, ClientId, ClientSecret, Error, RedirectUrl, TokenUrl,
 };
 
-use super::{CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
+use super::{form_post, CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ExchangeCodeRequest<'a> {
-    client_id: &'a ClientId,
-    client_secret: &'a ClientSecret,
+    client_id: Cow<'a, ClientId>,
+    client_secret: Cow<'a, ClientSecret>,
     code: AuthorizationCode,
-    redirect_url: &'a RedirectUrl,
-    token_url: &'a TokenUrl,
+    redirect_url: Cow<'a, RedirectUrl>,
+    token_url: Cow<'a, TokenUrl>,
 }
 
 impl<'a> ExchangeCodeRequest<'a> {
@@ -32,34 +27,41 @@ impl<'a> ExchangeCodeRequest<'a> {
         token_url: &'a TokenUrl,
     ) -> Self {
         Self {
-            client_id,
-            client_secret,
+            client_id: Cow::Borrowed(client_id),
+            client_secret: Cow::Borrowed(client_secret),
             code,
-            redirect_url,
-            token_url,
+            redirect_url: Cow::Borrowed(redirect_url),
+            token_url: Cow::Borrowed(token_url),
         }
     }
-}
-
-impl IntoRequestBuilder for ExchangeCodeRequest<'_> {
-    type Error = Error;
 
-    fn into_request_builder(self, client: &Client) -> Result<reqwest::RequestBuilder, Self::Error> {
-        let form_string = serde_urlencoded::to_string([
-            (CLIENT_ID, self.client_id.deref()),
-            (CLIENT_SECRET, self.client_secret.secret()),
-            ("code", self.code.secret()),
-            (GRANT_TYPE, GrantType::AuthorizationCode.as_str()),
-            ("redirect_uri", self.redirect_url.as_str()),
-        ])
-        .map_err(error::validation::form_data)?;
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> ExchangeCodeRequest<'static> {
+        ExchangeCodeRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            client_secret: Cow::Owned(self.client_secret.into_owned()),
+            code: self.code,
+            redirect_url: Cow::Owned(self.redirect_url.into_owned()),
+            token_url: Cow::Owned(self.token_url.into_owned()),
+        }
+    }
+}
 
-        let client = client
-            .request(Method::POST, self.token_url.to_string())
-            .header(ACCEPT, Application::Json)
-            .header(CONTENT_TYPE, Application::FormUrlEncoded)
-            .body(form_string);
+impl IntoHttpRequest for ExchangeCodeRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::ExchangeCode
+    }
 
-        Ok(client)
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        form_post(
+            self.token_url.as_str(),
+            [
+                (CLIENT_ID, self.client_id.as_str()),
+                (CLIENT_SECRET, self.client_secret.secret()),
+                ("code", self.code.secret()),
+                (GRANT_TYPE, GrantType::AuthorizationCode.as_str()),
+                ("redirect_uri", self.redirect_url.as_str()),
+            ],
+        )
     }
 }
@@ -1,5 +1,7 @@
 mod authorize_request;
 mod client_credentials;
+mod device_code;
+mod device_token;
 mod exchange_code;
 mod refresh_request;
 mod revoke_request;
@@ -7,11 +9,30 @@ mod validate_request;
 
 pub use authorize_request::AuthrozationRequest;
 pub use client_credentials::ClientCredentialsRequest;
+pub use device_code::DeviceCodeRequest;
+pub use device_token::DeviceTokenRequest;
 pub use exchange_code::ExchangeCodeRequest;
 pub use refresh_request::RefreshRequest;
 pub use revoke_request::RevokeRequest;
 pub use validate_request::{validate_access_token, ValidateRequest};
 
+use asknothingx2_util::api::mime_type::Application;
+use http::header::{ACCEPT, CONTENT_TYPE};
+use serde::Serialize;
+
+use crate::{error, transport::HttpRequest, Error};
+
 pub const CLIENT_ID: &str = "client_id";
 const CLIENT_SECRET: &str = "client_secret";
 pub const GRANT_TYPE: &str = "grant_type";
+
+/// `POST` of a urlencoded form, accepting JSON
+fn form_post<T: Serialize>(url: &str, form: T) -> Result<HttpRequest, Error> {
+    let form_string = serde_urlencoded::to_string(form).map_err(error::validation::form_data)?;
+
+    http::Request::post(url)
+        .header(ACCEPT, Application::Json)
+        .header(CONTENT_TYPE, Application::FormUrlEncoded)
+        .body(form_string.into_bytes())
+        .map_err(error::validation::form_data)
+}
@@ -1,22 +1,21 @@
-use std::ops::Deref;
+use std::borrow::Cow;
 
-use asknothingx2_util::api::{mime_type::Application, IntoRequestBuilder, Method};
-use reqwest::{
-    header::{ACCEPT, CONTENT_TYPE},
-    Client, RequestBuilder,
+use crate::{
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
+    types::GrantType,
+    ClientId, ClientSecret, Error, RefreshToken, TokenUrl,
 };
 
-use crate::{error, types::GrantType, ClientId, ClientSecret, Error, RefreshToken, TokenUrl};
-
-use super::{CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
+use super::{form_post, CLIENT_ID, CLIENT_SECRET, GRANT_TYPE};
 
 /// <https://dev.twitch.tv/docs/authentication/refresh-tokens/>
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RefreshRequest<'a> {
-    client_id: &'a ClientId,
-    client_secret: &'a ClientSecret,
+    client_id: Cow<'a, ClientId>,
+    client_secret: Option<Cow<'a, ClientSecret>>,
     refresh_token: RefreshToken,
-    token_url: &'a TokenUrl,
+    token_url: Cow<'a, TokenUrl>,
 }
 
 impl<'a> RefreshRequest<'a> {
@@ -27,30 +26,55 @@ impl<'a> RefreshRequest<'a> {
         token_url: &'a TokenUrl,
     ) -> Self {
         Self {
-            client_id,
-            client_secret,
+            client_id: Cow::Borrowed(client_id),
+            client_secret: Some(Cow::Borrowed(client_secret)),
+            refresh_token,
+            token_url: Cow::Borrowed(token_url),
+        }
+    }
+
+    /// Refresh request for a public client, sent without `client_secret`
+    pub fn public(
+        client_id: &'a ClientId,
+        refresh_token: RefreshToken,
+        token_url: &'a TokenUrl,
+    ) -> Self {
+        Self {
+            client_id: Cow::Borrowed(client_id),
+            client_secret: None,
             refresh_token,
-            token_url,
+            token_url: Cow::Borrowed(token_url),
+        }
+    }
+
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> RefreshRequest<'static> {
+        RefreshRequest {
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            client_secret: self.client_secret.map(|v| Cow::Owned(v.into_owned())),
+            refresh_token: self.refresh_token,
+            token_url: Cow::Owned(self.token_url.into_owned()),
         }
     }
 }
 
-impl IntoRequestBuilder for RefreshRequest<'_> {
-    type Error = Error;
+impl IntoHttpRequest for RefreshRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::RefreshToken
+    }
 
-    fn into_request_builder(self, client: &Client) -> Result<RequestBuilder, Error> {
-        let form_string = serde_urlencoded::to_string([
-            (CLIENT_ID, self.client_id.deref()),
-            (CLIENT_SECRET, self.client_secret.secret()),
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        let mut params = vec![(CLIENT_ID, self.client_id.as_str())];
+
+        if let Some(client_secret) = &self.client_secret {
+            params.push((CLIENT_SECRET, client_secret.secret()));
+        }
+
+        params.extend([
             (GRANT_TYPE, GrantType::RefreshToken.as_str()),
             ("refresh_token", self.refresh_token.secret()),
-        ])
-        .map_err(error::validation::form_data)?;
-
-        Ok(client
-            .request(Method::POST, self.token_url.to_string())
-            .header(ACCEPT, Application::Json)
-            .header(CONTENT_TYPE, Application::FormUrlEncoded)
-            .body(form_string))
+        ]);
+
+        form_post(self.token_url.as_str(), params)
     }
 }
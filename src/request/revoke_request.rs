@@ -1,21 +1,19 @@
-use std::ops::Deref;
+use std::borrow::Cow;
 
-use asknothingx2_util::api::{mime_type::Application, IntoRequestBuilder, Method};
-use reqwest::{
-    header::{ACCEPT, CONTENT_TYPE},
-    Client, RequestBuilder,
+use crate::{
+    interceptor::Operation,
+    transport::{HttpRequest, IntoHttpRequest},
+    AccessToken, ClientId, Error, RevocationUrl,
 };
 
-use crate::{error, AccessToken, ClientId, Error, RevocationUrl};
-
-use super::CLIENT_ID;
+use super::{form_post, CLIENT_ID};
 
 /// <https://dev.twitch.tv/docs/authentication/revoke-tokens/>
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct RevokeRequest<'a> {
-    access_token: &'a AccessToken,
-    client_id: &'a ClientId,
-    revoke_url: &'a RevocationUrl,
+    access_token: Cow<'a, AccessToken>,
+    client_id: Cow<'a, ClientId>,
+    revoke_url: Cow<'a, RevocationUrl>,
 }
 
 impl<'a> RevokeRequest<'a> {
@@ -25,27 +23,34 @@ impl<'a> RevokeRequest<'a> {
         revoke_url: &'a RevocationUrl,
     ) -> Self {
         Self {
-            access_token,
-            client_id,
-            revoke_url,
+            access_token: Cow::Borrowed(access_token),
+            client_id: Cow::Borrowed(client_id),
+            revoke_url: Cow::Borrowed(revoke_url),
         }
     }
-}
 
-impl IntoRequestBuilder for RevokeRequest<'_> {
-    type Error = Error;
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> RevokeRequest<'static> {
+        RevokeRequest {
+            access_token: Cow::Owned(self.access_token.into_owned()),
+            client_id: Cow::Owned(self.client_id.into_owned()),
+            revoke_url: Cow::Owned(self.revoke_url.into_owned()),
+        }
+    }
+}
 
-    fn into_request_builder(self, client: &Client) -> Result<RequestBuilder, Self::Error> {
-        let form_string = serde_urlencoded::to_string([
-            (CLIENT_ID, self.client_id.deref()),
-            ("token", self.access_token.secret()),
-        ])
-        .map_err(error::validation::form_data)?;
+impl IntoHttpRequest for RevokeRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::Revoke
+    }
 
-        Ok(client
-            .request(Method::POST, self.revoke_url.as_str())
-            .header(ACCEPT, Application::Json)
-            .header(CONTENT_TYPE, Application::FormUrlEncoded)
-            .body(form_string))
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        form_post(
+            self.revoke_url.as_str(),
+            [
+                (CLIENT_ID, self.client_id.as_str()),
+                ("token", self.access_token.secret()),
+            ],
+        )
     }
 }
@@ -1,55 +1,69 @@
-use asknothingx2_util::api::{AuthScheme, IntoRequestBuilder, Method};
-use reqwest::{header::AUTHORIZATION, Client, RequestBuilder};
+use std::borrow::Cow;
 
-use crate::{error, tokens::TokenInfo, AccessToken, Error, ValidateUrl};
+use asknothingx2_util::api::AuthScheme;
+use http::header::AUTHORIZATION;
+
+use crate::{
+    error,
+    interceptor::Operation,
+    tokens::TokenInfo,
+    transport::{HttpRequest, HttpTransport, IntoHttpRequest},
+    AccessToken, Error, ValidateUrl,
+};
 
 /// <https://dev.twitch.tv/docs/authentication/validate-tokens/>
-pub async fn validate_access_token(
+///
+/// `transport` is usually a [`reqwest::Client`], see [`crate::transport`].
+pub async fn validate_access_token<T>(
     access_token: &AccessToken,
-    client: &Client,
+    transport: &T,
     validate_url: &ValidateUrl,
-) -> Result<TokenInfo, Error> {
-    let resp = ValidateRequest::new(access_token, validate_url)
-        .into_request_builder(client)?
-        .send()
-        .await
-        .map_err(error::network::request)?;
-
-    if !resp.status().is_success() {
-        let status = resp.status().as_u16();
-        let v = resp.bytes().await?;
-        let body = String::from_utf8_lossy(&v).to_string();
-        return Err(error::oauth::http_error(status, body));
-    }
+) -> Result<TokenInfo, Error>
+where
+    T: HttpTransport + ?Sized,
+{
+    let resp =
+        crate::oauth::send(transport, ValidateRequest::new(access_token, validate_url)).await?;
 
-    crate::oauth::decode_response(resp).await
+    crate::oauth::decode_response(resp)
 }
 
 /// <https://dev.twitch.tv/docs/authentication/validate-tokens/>
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ValidateRequest<'a> {
-    access_token: &'a AccessToken,
-    validate_url: &'a ValidateUrl,
+    access_token: Cow<'a, AccessToken>,
+    validate_url: Cow<'a, ValidateUrl>,
 }
 
 impl<'a> ValidateRequest<'a> {
     pub fn new(access_token: &'a AccessToken, validate_url: &'a ValidateUrl) -> Self {
         Self {
-            access_token,
-            validate_url,
+            access_token: Cow::Borrowed(access_token),
+            validate_url: Cow::Borrowed(validate_url),
+        }
+    }
+
+    /// Detach from the borrowed configuration, e.g. to spawn or queue the request
+    pub fn into_owned(self) -> ValidateRequest<'static> {
+        ValidateRequest {
+            access_token: Cow::Owned(self.access_token.into_owned()),
+            validate_url: Cow::Owned(self.validate_url.into_owned()),
         }
     }
 }
 
-impl IntoRequestBuilder for ValidateRequest<'_> {
-    type Error = Error;
+impl IntoHttpRequest for ValidateRequest<'_> {
+    fn operation(&self) -> Operation {
+        Operation::Validate
+    }
+
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        let authorization =
+            AuthScheme::custom("OAuth", self.access_token.secret()).to_header_value()?;
 
-    fn into_request_builder(self, client: &Client) -> Result<RequestBuilder, Error> {
-        Ok(client
-            .request(Method::GET, self.validate_url.as_str())
-            .header(
-                AUTHORIZATION,
-                AuthScheme::custom("OAuth", self.access_token.secret()).to_header_value()?,
-            ))
+        http::Request::get(self.validate_url.as_str())
+            .header(AUTHORIZATION, authorization)
+            .body(Vec::new())
+            .map_err(error::validation::form_data)
     }
 }
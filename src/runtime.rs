@@ -0,0 +1,277 @@
+//! Async runtime used for timers and background tasks
+//!
+//! Device code polling sleeps between requests and
+//! [`DeviceSessionManager`](crate::DeviceSessionManager) runs its poll loops in the
+//! background. Both go through the global [`Runtime`], so the crate isn't tied to
+//! tokio.
+//!
+//! # Default Behavior (No Setup Required)
+//!
+//! - Inside a tokio runtime (requires `tokio` feature, enabled by default):
+//!   [`TokioRuntime`]
+//! - Anywhere else, e.g. smol or async-std: [`ThreadRuntime`]
+//!
+//! Note that the default [`reqwest::Client`] transport still needs tokio; on other
+//! executors, also configure an [`HttpTransport`](crate::transport::HttpTransport).
+//!
+//! # Custom Runtime
+//! ```
+//! use std::time::Duration;
+//!
+//! use twitch_oauth_token::{runtime, transport::BoxFuture};
+//!
+//! struct Smol;
+//!
+//! impl runtime::Runtime for Smol {
+//!     fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+//!         Box::pin(async move {
+//!             // smol::Timer::after(duration).await;
+//!         })
+//!     }
+//!
+//!     fn spawn(&self, task: BoxFuture<'static, ()>) {
+//!         // smol::spawn(task).detach();
+//!     }
+//! }
+//!
+//! # fn run() -> Result<(), twitch_oauth_token::Error> {
+//! // Configure once at startup
+//! runtime::setup(Smol)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+    time::Duration,
+};
+
+use crate::{error, transport::BoxFuture, Error};
+
+/// Timers and task spawning
+pub trait Runtime: Send + Sync {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()>;
+
+    /// Run `task` in the background
+    fn spawn(&self, task: BoxFuture<'static, ()>);
+}
+
+/// The tokio runtime of the caller (requires `tokio` feature)
+///
+/// Panics when used outside a tokio runtime.
+#[cfg(feature = "tokio")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioRuntime;
+
+#[cfg(feature = "tokio")]
+impl Runtime for TokioRuntime {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        tokio::spawn(task);
+    }
+}
+
+/// Executor-independent runtime built on OS threads
+///
+/// Each sleep and each spawned task gets its own thread, which is fine for
+/// polling intervals measured in seconds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThreadRuntime;
+
+impl Runtime for ThreadRuntime {
+    fn sleep(&self, duration: Duration) -> BoxFuture<'static, ()> {
+        Box::pin(ThreadSleep {
+            duration,
+            state: None,
+        })
+    }
+
+    fn spawn(&self, task: BoxFuture<'static, ()>) {
+        thread::spawn(move || block_on(task));
+    }
+}
+
+static RUNTIME: OnceLock<Box<dyn Runtime>> = OnceLock::new();
+
+/// Configure the global runtime
+///
+/// This should be called once at application startup.
+pub fn setup<T>(runtime: T) -> Result<(), Error>
+where
+    T: Runtime + 'static,
+{
+    RUNTIME
+        .set(Box::new(runtime))
+        .map_err(|_| error::client_setup::already_initialized())
+}
+
+/// Get the global runtime, see the [module docs](self) for the default
+pub fn get() -> &'static dyn Runtime {
+    if let Some(runtime) = RUNTIME.get() {
+        return runtime.as_ref();
+    }
+
+    #[cfg(feature = "tokio")]
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return &TokioRuntime;
+    }
+
+    &ThreadRuntime
+}
+
+#[derive(Default)]
+struct SleepState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+struct ThreadSleep {
+    duration: Duration,
+    state: Option<Arc<Mutex<SleepState>>>,
+}
+
+impl Future for ThreadSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let duration = self.duration;
+        let state = self.state.get_or_insert_with(|| {
+            let state = Arc::new(Mutex::new(SleepState::default()));
+            let timer = state.clone();
+            thread::spawn(move || {
+                thread::sleep(duration);
+                let mut timer = timer.lock().unwrap();
+                timer.done = true;
+                if let Some(waker) = timer.waker.take() {
+                    waker.wake();
+                }
+            });
+            state
+        });
+
+        let mut state = state.lock().unwrap();
+        if state.done {
+            Poll::Ready(())
+        } else {
+            state.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc, Arc,
+        },
+        time::{Duration, Instant},
+    };
+
+    use futures::executor::block_on;
+    use http::StatusCode;
+
+    use crate::{
+        transport::{from_fn, BoxError, HttpResponse, HttpTransport},
+        ClientId, DeviceAuth, DeviceSessionManager,
+    };
+
+    /// Device flow that stays pending for `pending` polls
+    fn transport(pending: usize) -> impl HttpTransport + 'static {
+        let polls = Arc::new(AtomicUsize::new(0));
+        from_fn(move |request| {
+            let polls = polls.clone();
+            async move {
+                let (status, json) = if request.uri().path() == "/oauth2/device" {
+                    (
+                        StatusCode::OK,
+                        r#"{"device_code":"ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91","expires_in":1800,"interval":0,"user_code":"ABCDEFGH","verification_uri":"https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH"}"#,
+                    )
+                } else if polls.fetch_add(1, Ordering::SeqCst) < pending {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        r#"{"status":400,"message":"authorization_pending"}"#,
+                    )
+                } else {
+                    (
+                        StatusCode::OK,
+                        r#"{"access_token":"rfx2uswqe8l4g1mkagrvg5tv0ks3","expires_in":14124,"refresh_token":"5b93chm6hdve3mycz05zfzatkfdenfspp1h1ar2xxdalen01","scope":["user:read:email"],"token_type":"bearer"}"#,
+                    )
+                };
+
+                let mut response = HttpResponse::new(json.as_bytes().to_vec());
+                *response.status_mut() = status;
+                Ok::<_, BoxError>(response)
+            }
+        })
+    }
+
+    fn device(pending: usize) -> DeviceAuth {
+        DeviceAuth::new(ClientId::from("client_id")).with_transport(transport(pending))
+    }
+
+    #[test]
+    fn sleeps_without_tokio() {
+        let start = Instant::now();
+        block_on(super::get().sleep(Duration::from_millis(50)));
+        assert!(start.elapsed() >= Duration::from_millis(50));
+    }
+
+    #[test]
+    fn device_flow_without_tokio() {
+        let device = device(2);
+        let token = block_on(async {
+            let response = device.request().await?;
+            device.poll(response).await
+        })
+        .unwrap();
+        assert_eq!(token.access_token.secret(), "rfx2uswqe8l4g1mkagrvg5tv0ks3");
+    }
+
+    #[test]
+    fn session_manager_without_tokio() {
+        let (tx, rx) = mpsc::channel();
+        let manager = DeviceSessionManager::with_callback(device(1), 2, move |event| {
+            tx.send(event).unwrap();
+        });
+
+        block_on(manager.start("streamer-1", device(1))).unwrap();
+        let event = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(event.id, "streamer-1");
+        assert!(event.result.is_ok());
+
+        block_on(manager.start("streamer-2", device(usize::MAX))).unwrap();
+        assert!(manager.cancel("streamer-2"));
+        assert!(rx.recv_timeout(Duration::from_millis(200)).is_err());
+        assert!(manager.is_empty());
+    }
+}
@@ -0,0 +1,414 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use super::{EventSubType, GrantedScopes, HelixEndpoint, Requirement, Scope, ScopeSet};
+
+/// Works out which scopes an application actually needs
+///
+/// Record the endpoints and EventSub subscriptions in use, then ask for the
+/// smallest scope set covering them or lint the scopes currently requested.
+///
+/// ```
+/// use twitch_oauth_token::{
+///     scope::{EventSubType, HelixEndpoint, ScopeAnalyzer, ScopeLint, ScopeSet},
+///     Scope,
+/// };
+///
+/// let mut analyzer = ScopeAnalyzer::new();
+/// analyzer
+///     .endpoint(HelixEndpoint::GetPolls)
+///     .endpoint(HelixEndpoint::CreatePoll)
+///     .eventsub(EventSubType::ChannelPollBegin);
+///
+/// assert_eq!(analyzer.minimal(), ScopeSet::from(Scope::ChannelManagePolls));
+///
+/// let requested = ScopeSet::from([
+///     Scope::ChannelReadPolls,
+///     Scope::ChannelManagePolls,
+///     Scope::ChatRead,
+/// ]);
+/// let report = analyzer.lint(&requested);
+/// assert!(report.lints().contains(&ScopeLint::Redundant {
+///     scope: Scope::ChannelReadPolls,
+///     covered_by: Scope::ChannelManagePolls,
+/// }));
+/// assert!(report.lints().contains(&ScopeLint::Unused {
+///     scope: Scope::ChatRead
+/// }));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ScopeAnalyzer {
+    requirements: Vec<Requirement>,
+}
+
+impl ScopeAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn require(&mut self, requirement: Requirement) -> &mut Self {
+        if !self.requirements.contains(&requirement) {
+            self.requirements.push(requirement);
+        }
+        self
+    }
+
+    pub fn endpoint(&mut self, endpoint: HelixEndpoint) -> &mut Self {
+        self.require(endpoint.requirement())
+    }
+
+    pub fn eventsub(&mut self, subscription: EventSubType) -> &mut Self {
+        self.require(subscription.requirement())
+    }
+
+    /// Smallest set of scopes satisfying every recorded requirement
+    ///
+    /// When several sets are equally small, earlier alternatives win, which
+    /// favours read scopes over manage scopes.
+    pub fn minimal(&self) -> ScopeSet {
+        let mut pending = self
+            .requirements
+            .iter()
+            .map(|requirement| alternatives(*requirement))
+            .filter(|alternatives| !alternatives.iter().any(|a| a.is_empty()))
+            .collect::<Vec<_>>();
+
+        // Requirements that share no scopes are solved independently, which keeps
+        // the search small.
+        let mut minimal = ScopeSet::new();
+        while !pending.is_empty() {
+            let mut component = vec![pending.remove(0)];
+            let mut universe = union(&component[0]);
+            loop {
+                let (connected, rest): (Vec<_>, Vec<_>) = pending
+                    .into_iter()
+                    .partition(|alternatives| !union(alternatives).is_disjoint(universe));
+                pending = rest;
+                if connected.is_empty() {
+                    break;
+                }
+                for alternatives in connected {
+                    universe |= union(&alternatives);
+                    component.push(alternatives);
+                }
+            }
+
+            let mut best = None;
+            search(&component, ScopeSet::new(), &mut best);
+            minimal |= best.unwrap_or_default();
+        }
+        minimal
+    }
+
+    /// Report deprecated, redundant and unused scopes in `scopes`
+    pub fn lint<T>(&self, scopes: &T) -> ScopeReport
+    where
+        T: GrantedScopes + ?Sized,
+    {
+        let scopes = scopes.granted_scopes();
+        let used = self
+            .requirements
+            .iter()
+            .flat_map(|requirement| alternatives(*requirement))
+            .fold(ScopeSet::new(), |used, a| used | a);
+        let registry = registry();
+
+        let mut lints = Vec::new();
+        for scope in scopes {
+            if let Some(reason) = scope.deprecation() {
+                lints.push(ScopeLint::Deprecated { scope, reason });
+            }
+            if !used.contains(scope) {
+                lints.push(ScopeLint::Unused { scope });
+                continue;
+            }
+            let covering = scopes.without(scope).iter().find(|other| {
+                covers(&registry, *other, scope) && !covers(&registry, scope, *other)
+            });
+            if let Some(covered_by) = covering {
+                lints.push(ScopeLint::Redundant { scope, covered_by });
+            }
+        }
+
+        ScopeReport { lints }
+    }
+}
+
+/// A problem found by [`ScopeAnalyzer::lint`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeLint {
+    /// See [`Scope::deprecation`]
+    Deprecated { scope: Scope, reason: &'static str },
+    /// Everything `scope` grants in the Helix and EventSub registries is also
+    /// granted by `covered_by`
+    Redundant { scope: Scope, covered_by: Scope },
+    /// No recorded endpoint or subscription accepts `scope`
+    Unused { scope: Scope },
+}
+
+impl ScopeLint {
+    pub fn scope(&self) -> Scope {
+        match self {
+            Self::Deprecated { scope, .. }
+            | Self::Redundant { scope, .. }
+            | Self::Unused { scope } => *scope,
+        }
+    }
+}
+
+impl Display for ScopeLint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Deprecated { scope, reason } => write!(f, "{scope} is deprecated: {reason}"),
+            Self::Redundant { scope, covered_by } => {
+                write!(f, "{scope} is redundant with {covered_by}")
+            }
+            Self::Unused { scope } => write!(f, "{scope} is not used"),
+        }
+    }
+}
+
+/// Returned by [`ScopeAnalyzer::lint`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScopeReport {
+    lints: Vec<ScopeLint>,
+}
+
+impl ScopeReport {
+    /// Lints in [`Scope`] declaration order
+    pub fn lints(&self) -> &[ScopeLint] {
+        &self.lints
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lints.is_empty()
+    }
+
+    /// Scopes that can be dropped without losing access to anything recorded
+    pub fn removable(&self) -> ScopeSet {
+        self.lints
+            .iter()
+            .filter(|lint| !matches!(lint, ScopeLint::Deprecated { .. }))
+            .map(ScopeLint::scope)
+            .collect()
+    }
+}
+
+impl<'a> IntoIterator for &'a ScopeReport {
+    type Item = &'a ScopeLint;
+    type IntoIter = std::slice::Iter<'a, ScopeLint>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.lints.iter()
+    }
+}
+
+/// One lint per line
+impl Display for ScopeReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (i, lint) in self.lints.iter().enumerate() {
+            if i > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{lint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Sets of scopes any one of which satisfies `requirement`, in preference order
+fn alternatives(requirement: Requirement) -> Vec<ScopeSet> {
+    match requirement {
+        Requirement::None => vec![ScopeSet::new()],
+        Requirement::Scope(scope) => vec![ScopeSet::from(scope)],
+        Requirement::AnyOf(requirements) => requirements
+            .iter()
+            .flat_map(|requirement| alternatives(*requirement))
+            .collect(),
+        Requirement::AllOf(requirements) => {
+            requirements
+                .iter()
+                .fold(vec![ScopeSet::new()], |acc, requirement| {
+                    let next = alternatives(*requirement);
+                    acc.iter()
+                        .flat_map(|a| next.iter().map(move |b| *a | *b))
+                        .collect()
+                })
+        }
+    }
+}
+
+fn union(alternatives: &[ScopeSet]) -> ScopeSet {
+    alternatives.iter().fold(ScopeSet::new(), |acc, a| acc | *a)
+}
+
+fn search(requirements: &[Vec<ScopeSet>], chosen: ScopeSet, best: &mut Option<ScopeSet>) {
+    if best.is_some_and(|best| chosen.len() >= best.len()) {
+        return;
+    }
+    let unsatisfied = requirements
+        .iter()
+        .position(|alternatives| !alternatives.iter().any(|a| a.is_subset(chosen)));
+    match unsatisfied {
+        None => *best = Some(chosen),
+        Some(i) => {
+            for alternative in &requirements[i] {
+                search(&requirements[i + 1..], chosen | *alternative, best);
+            }
+        }
+    }
+}
+
+/// Alternatives of every Helix endpoint and EventSub subscription
+fn registry() -> Vec<Vec<ScopeSet>> {
+    HelixEndpoint::ALL
+        .iter()
+        .map(HelixEndpoint::requirement)
+        .chain(EventSubType::ALL.iter().map(EventSubType::requirement))
+        .map(alternatives)
+        .collect()
+}
+
+/// Whether `by` can stand in for `scope` everywhere `scope` is accepted
+fn covers(registry: &[Vec<ScopeSet>], by: Scope, scope: Scope) -> bool {
+    let mut accepted = false;
+    for alternatives in registry {
+        for alternative in alternatives.iter().filter(|a| a.contains(scope)) {
+            accepted = true;
+            let substituted = alternative.without(scope).with(by);
+            if !alternatives.iter().any(|a| a.is_subset(substituted)) {
+                return false;
+            }
+        }
+    }
+    accepted
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scope::{EventSubType, HelixEndpoint, Requirement, ScopeSet},
+        Scope,
+    };
+
+    use super::{ScopeAnalyzer, ScopeLint};
+
+    #[test]
+    fn minimal() {
+        let mut analyzer = ScopeAnalyzer::new();
+        assert!(analyzer.minimal().is_empty());
+
+        analyzer
+            .endpoint(HelixEndpoint::GetUsers)
+            .endpoint(HelixEndpoint::GetCustomReward)
+            .eventsub(EventSubType::ChannelModerateV2)
+            .endpoint(HelixEndpoint::BanUser);
+        assert_eq!(
+            analyzer.minimal(),
+            ScopeSet::from([
+                Scope::ChannelReadRedemptions,
+                Scope::ModeratorReadBlockedTerms,
+                Scope::ModeratorReadChatSettings,
+                Scope::ModeratorReadUnbanRequests,
+                Scope::ModeratorManageBannedUsers,
+                Scope::ModeratorReadChatMessages,
+                Scope::ModeratorReadModerators,
+                Scope::ModeratorReadVips,
+                Scope::ModeratorReadWarnings,
+            ])
+        );
+
+        analyzer.endpoint(HelixEndpoint::UpdateRedemptionStatus);
+        let minimal = analyzer.minimal();
+        assert!(minimal.contains(Scope::ChannelManageRedemptions));
+        assert!(!minimal.contains(Scope::ChannelReadRedemptions));
+    }
+
+    #[test]
+    fn prefers_smaller_alternatives() {
+        const EITHER: Requirement = Requirement::AnyOf(&[
+            Requirement::AllOf(&[
+                Requirement::Scope(Scope::UserBot),
+                Requirement::Scope(Scope::ChannelBot),
+                Requirement::Scope(Scope::ChannelModerate),
+            ]),
+            Requirement::Scope(Scope::UserReadChat),
+        ]);
+
+        let mut analyzer = ScopeAnalyzer::new();
+        analyzer.require(EITHER);
+        assert_eq!(analyzer.minimal(), ScopeSet::from(Scope::UserReadChat));
+
+        analyzer
+            .require(Requirement::Scope(Scope::UserBot))
+            .require(Requirement::Scope(Scope::ChannelBot))
+            .require(Requirement::Scope(Scope::ChannelModerate));
+        assert_eq!(
+            analyzer.minimal(),
+            ScopeSet::from([Scope::UserBot, Scope::ChannelBot, Scope::ChannelModerate])
+        );
+    }
+
+    #[test]
+    fn lint() {
+        let mut analyzer = ScopeAnalyzer::new();
+        analyzer
+            .endpoint(HelixEndpoint::GetChatters)
+            .eventsub(EventSubType::UserWhisperMessage);
+
+        let report = analyzer.lint(&ScopeSet::from([
+            Scope::ChatRead,
+            Scope::ModeratorReadChatters,
+            Scope::UserReadWhispers,
+            Scope::UserManageWhispers,
+            Scope::ChannelReadAds,
+            Scope::ChannelManageAds,
+        ]));
+
+        assert_eq!(
+            report.lints(),
+            [
+                ScopeLint::Unused {
+                    scope: Scope::ChannelManageAds
+                },
+                ScopeLint::Unused {
+                    scope: Scope::ChannelReadAds
+                },
+                ScopeLint::Redundant {
+                    scope: Scope::UserReadWhispers,
+                    covered_by: Scope::UserManageWhispers,
+                },
+                ScopeLint::Deprecated {
+                    scope: Scope::ChatRead,
+                    reason: Scope::ChatRead.deprecation().unwrap(),
+                },
+                ScopeLint::Unused {
+                    scope: Scope::ChatRead
+                },
+            ]
+        );
+        assert_eq!(
+            report.removable(),
+            ScopeSet::from([
+                Scope::ChannelReadAds,
+                Scope::ChannelManageAds,
+                Scope::ChatRead,
+                Scope::UserReadWhispers,
+            ])
+        );
+        assert!(report.to_string().ends_with("chat:read is not used"));
+    }
+
+    #[test]
+    fn manage_does_not_always_cover_read() {
+        let mut analyzer = ScopeAnalyzer::new();
+        analyzer
+            .endpoint(HelixEndpoint::GetAdSchedule)
+            .endpoint(HelixEndpoint::SnoozeNextAd);
+
+        let report = analyzer.lint(&ScopeSet::from([
+            Scope::ChannelReadAds,
+            Scope::ChannelManageAds,
+        ]));
+        assert!(report.is_empty());
+    }
+}
@@ -0,0 +1,294 @@
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Scope, ScopeName, ScopeSet};
+
+/// Groups scopes for consent pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopeCategory {
+    Chat,
+    Moderation,
+    Channel,
+    User,
+    /// Scopes this crate doesn't know, see [`Scopes::consent_summary`](super::Scopes::consent_summary)
+    Other,
+}
+
+impl ScopeCategory {
+    /// In display order
+    pub const ALL: &'static [ScopeCategory] = &[
+        Self::Chat,
+        Self::Moderation,
+        Self::Channel,
+        Self::User,
+        Self::Other,
+    ];
+
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Chat => "chat",
+            Self::Moderation => "moderation",
+            Self::Channel => "channel",
+            Self::User => "user",
+            Self::Other => "other",
+        }
+    }
+
+    /// English heading, e.g. `Chat`
+    pub const fn title(&self) -> &'static str {
+        match self {
+            Self::Chat => "Chat",
+            Self::Moderation => "Moderation",
+            Self::Channel => "Your channel",
+            Self::User => "Your account",
+            Self::Other => "Other",
+        }
+    }
+}
+
+impl Display for ScopeCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Text shown on consent pages
+///
+/// Every method defaults to English, so an implementation only overrides what it
+/// translates.
+pub trait Translations {
+    fn summary(&self, scope: Scope) -> &str {
+        scope.summary()
+    }
+
+    /// Summary of a scope this crate doesn't know, the scope string by default
+    fn unknown_summary<'a>(&'a self, scope: &'a str) -> &'a str {
+        scope
+    }
+
+    fn category_title(&self, category: ScopeCategory) -> &str {
+        category.title()
+    }
+}
+
+/// The built-in English text
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl Translations for English {}
+
+/// Translations loaded at runtime, falling back to English
+///
+/// Scope keys are kept as strings, so a table can translate scopes this crate
+/// doesn't know yet. Deserializes from e.g.
+///
+/// ```json
+/// {
+///     "categories": { "chat": "Chat" },
+///     "scopes": { "user:write:chat": "Envoyer des messages dans le chat" }
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslationTable {
+    #[serde(default)]
+    categories: HashMap<ScopeCategory, String>,
+    #[serde(default)]
+    scopes: HashMap<String, String>,
+}
+
+impl TranslationTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_summary<'a>(
+        mut self,
+        scope: impl Into<ScopeName<'a>>,
+        summary: impl Into<String>,
+    ) -> Self {
+        self.scopes
+            .insert(scope.into().as_str().to_string(), summary.into());
+        self
+    }
+
+    pub fn with_category_title(
+        mut self,
+        category: ScopeCategory,
+        title: impl Into<String>,
+    ) -> Self {
+        self.categories.insert(category, title.into());
+        self
+    }
+}
+
+impl Translations for TranslationTable {
+    fn summary(&self, scope: Scope) -> &str {
+        self.scopes
+            .get(scope.as_str())
+            .map_or_else(|| scope.summary(), String::as_str)
+    }
+
+    fn unknown_summary<'a>(&'a self, scope: &'a str) -> &'a str {
+        self.scopes.get(scope).map_or(scope, String::as_str)
+    }
+
+    fn category_title(&self, category: ScopeCategory) -> &str {
+        self.categories
+            .get(&category)
+            .map_or_else(|| category.title(), String::as_str)
+    }
+}
+
+/// One category of [`ScopeSet::consent_summary`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsentSection<'a> {
+    pub category: ScopeCategory,
+    pub title: &'a str,
+    /// Summaries in [`Scope`] declaration order, without duplicates
+    pub items: Vec<&'a str>,
+}
+
+impl ScopeSet {
+    /// Group the scopes by category for a "this app will be able to..." page
+    ///
+    /// Empty categories are left out.
+    ///
+    /// ```
+    /// use twitch_oauth_token::{
+    ///     scope::{English, ScopeCategory, TranslationTable},
+    ///     Scope, ScopeSet,
+    /// };
+    ///
+    /// let scopes = ScopeSet::from([
+    ///     Scope::UserWriteChat,
+    ///     Scope::UserReadChat,
+    ///     Scope::ModeratorManageBannedUsers,
+    /// ]);
+    ///
+    /// let sections = scopes.consent_summary(&English);
+    /// assert_eq!(sections[0].title, "Chat");
+    /// assert_eq!(sections[0].items, ["Read chat messages", "Send chat messages"]);
+    /// assert_eq!(sections[1].category, ScopeCategory::Moderation);
+    ///
+    /// let french = TranslationTable::new()
+    ///     .with_category_title(ScopeCategory::Chat, "Chat")
+    ///     .with_summary(Scope::UserWriteChat, "Envoyer des messages dans le chat");
+    /// let sections = scopes.consent_summary(&french);
+    /// assert_eq!(
+    ///     sections[0].items,
+    ///     ["Read chat messages", "Envoyer des messages dans le chat"]
+    /// );
+    /// ```
+    pub fn consent_summary<T>(self, translations: &T) -> Vec<ConsentSection<'_>>
+    where
+        T: Translations + ?Sized,
+    {
+        ScopeCategory::ALL
+            .iter()
+            .filter_map(|category| {
+                let mut items = Vec::new();
+                for scope in self.iter().filter(|scope| scope.category() == *category) {
+                    let summary = translations.summary(scope);
+                    if !items.contains(&summary) {
+                        items.push(summary);
+                    }
+                }
+                (!items.is_empty()).then(|| ConsentSection {
+                    category: *category,
+                    title: translations.category_title(*category),
+                    items,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Scope, ScopeSet, Scopes};
+
+    use super::{English, ScopeCategory, TranslationTable};
+
+    #[test]
+    fn every_scope_has_a_summary() {
+        for scope in Scope::ALL {
+            let summary = scope.summary();
+            assert!(!summary.is_empty() && !summary.ends_with('.'), "{scope}");
+        }
+        for category in ScopeCategory::ALL
+            .iter()
+            .filter(|category| **category != ScopeCategory::Other)
+        {
+            assert!(Scope::ALL.iter().any(|scope| scope.category() == *category));
+        }
+    }
+
+    #[test]
+    fn deduplicates_and_orders_sections() {
+        let scopes = ScopeSet::from([
+            Scope::UserReadEmail,
+            Scope::ChatRead,
+            Scope::UserReadChat,
+            Scope::ChannelReadPolls,
+        ]);
+        let sections = scopes.consent_summary(&English);
+
+        let categories = sections.iter().map(|s| s.category).collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            [
+                ScopeCategory::Chat,
+                ScopeCategory::Channel,
+                ScopeCategory::User
+            ]
+        );
+        assert_eq!(sections[0].items, ["Read chat messages"]);
+    }
+
+    #[test]
+    fn translation_table_from_json() {
+        let table: TranslationTable = serde_json::from_value(serde_json::json!({
+            "categories": { "user": "Votre compte" },
+            "scopes": { "user:read:email": "Voir votre adresse e-mail" }
+        }))
+        .unwrap();
+
+        let sections =
+            ScopeSet::from([Scope::UserReadEmail, Scope::UserReadFollows]).consent_summary(&table);
+        assert_eq!(sections[0].title, "Votre compte");
+        assert_eq!(
+            sections[0].items,
+            ["Voir votre adresse e-mail", "View the channels you follow"]
+        );
+    }
+
+    #[test]
+    fn translation_table_with_unknown_scopes() {
+        let table: TranslationTable = serde_json::from_value(serde_json::json!({
+            "scopes": {
+                "user:read:email": "Voir votre adresse e-mail",
+                "channel:manage:future_feature": "Gérer une nouvelle fonction"
+            }
+        }))
+        .unwrap();
+
+        let scopes: Scopes = serde_json::from_value(serde_json::json!([
+            "user:read:email",
+            "channel:manage:future_feature",
+            "user:read:other_feature"
+        ]))
+        .unwrap();
+        let sections = scopes.consent_summary(&table);
+        assert_eq!(sections[0].items, ["Voir votre adresse e-mail"]);
+        assert_eq!(sections[1].category, ScopeCategory::Other);
+        assert_eq!(
+            sections[1].items,
+            ["Gérer une nouvelle fonction", "user:read:other_feature"]
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error, Error};
+
+use super::{Requirement, Scope, ScopeSet, TokenType};
+
+define_eventsub! {
+    AutomodMessageHold => "automod.message.hold" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorManageAutomod,
+    }
+    AutomodMessageHoldV2 => "automod.message.hold" ("2") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorManageAutomod,
+    }
+    AutomodMessageUpdate => "automod.message.update" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorManageAutomod,
+    }
+    AutomodMessageUpdateV2 => "automod.message.update" ("2") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorManageAutomod,
+    }
+    AutomodSettingsUpdate => "automod.settings.update" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorReadAutomodSettings,
+    }
+    AutomodTermsUpdate => "automod.terms.update" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorManageAutomod,
+    }
+    ChannelBitsUse => "channel.bits.use" ("1") {
+        condition: [broadcaster_user_id],
+        requires: BitsRead,
+    }
+    ChannelUpdate => "channel.update" ("2") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    ChannelFollow => "channel.follow" ("2") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorReadFollowers,
+    }
+    ChannelAdBreakBegin => "channel.ad_break.begin" ("1") {
+        condition: [broadcaster_id],
+        requires: ChannelReadAds,
+    }
+    ChannelChatClear => "channel.chat.clear" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatClearUserMessages => "channel.chat.clear_user_messages" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatMessage => "channel.chat.message" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatMessageDelete => "channel.chat.message_delete" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatNotification => "channel.chat.notification" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatSettingsUpdate => "channel.chat_settings.update" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatUserMessageHold => "channel.chat.user_message_hold" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelChatUserMessageUpdate => "channel.chat.user_message_update" ("1") {
+        condition: [broadcaster_user_id, user_id],
+        requires: UserReadChat,
+    }
+    ChannelSharedChatBegin => "channel.shared_chat.begin" ("1") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    ChannelSharedChatUpdate => "channel.shared_chat.update" ("1") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    ChannelSharedChatEnd => "channel.shared_chat.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    ChannelSubscribe => "channel.subscribe" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadSubscriptions,
+    }
+    ChannelSubscriptionEnd => "channel.subscription.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadSubscriptions,
+    }
+    ChannelSubscriptionGift => "channel.subscription.gift" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadSubscriptions,
+    }
+    ChannelSubscriptionMessage => "channel.subscription.message" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadSubscriptions,
+    }
+    ChannelCheer => "channel.cheer" ("1") {
+        condition: [broadcaster_user_id],
+        requires: BitsRead,
+    }
+    ChannelRaid => "channel.raid" ("1") {
+        condition: [from_broadcaster_user_id, to_broadcaster_user_id],
+        requires: none,
+    }
+    ChannelBan => "channel.ban" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelModerate,
+    }
+    ChannelUnban => "channel.unban" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelModerate,
+    }
+    ChannelUnbanRequestCreate => "channel.unban_request.create" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadUnbanRequests, ModeratorManageUnbanRequests],
+    }
+    ChannelUnbanRequestResolve => "channel.unban_request.resolve" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadUnbanRequests, ModeratorManageUnbanRequests],
+    }
+    ChannelModerate => "channel.moderate" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: all[
+            any[ModeratorReadBlockedTerms, ModeratorManageBlockedTerms],
+            any[ModeratorReadChatSettings, ModeratorManageChatSettings],
+            any[ModeratorReadUnbanRequests, ModeratorManageUnbanRequests],
+            any[ModeratorReadBannedUsers, ModeratorManageBannedUsers],
+            any[ModeratorReadChatMessages, ModeratorManageChatMessages],
+            ModeratorReadModerators,
+            ModeratorReadVips,
+        ],
+    }
+    ChannelModerateV2 => "channel.moderate" ("2") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: all[
+            any[ModeratorReadBlockedTerms, ModeratorManageBlockedTerms],
+            any[ModeratorReadChatSettings, ModeratorManageChatSettings],
+            any[ModeratorReadUnbanRequests, ModeratorManageUnbanRequests],
+            any[ModeratorReadBannedUsers, ModeratorManageBannedUsers],
+            any[ModeratorReadChatMessages, ModeratorManageChatMessages],
+            ModeratorReadModerators,
+            ModeratorReadVips,
+            any[ModeratorReadWarnings, ModeratorManageWarnings],
+        ],
+    }
+    ChannelModeratorAdd => "channel.moderator.add" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ModerationRead,
+    }
+    ChannelModeratorRemove => "channel.moderator.remove" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ModerationRead,
+    }
+    ChannelGuestStarSessionBegin => "channel.guest_star_session.begin" ("beta") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[
+            ChannelReadGuestStar,
+            ChannelManageGuestStar,
+            ModeratorReadGuestStar,
+            ModeratorManageGuestStar,
+        ],
+    }
+    ChannelGuestStarSessionEnd => "channel.guest_star_session.end" ("beta") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[
+            ChannelReadGuestStar,
+            ChannelManageGuestStar,
+            ModeratorReadGuestStar,
+            ModeratorManageGuestStar,
+        ],
+    }
+    ChannelGuestStarGuestUpdate => "channel.guest_star_guest.update" ("beta") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[
+            ChannelReadGuestStar,
+            ChannelManageGuestStar,
+            ModeratorReadGuestStar,
+            ModeratorManageGuestStar,
+        ],
+    }
+    ChannelGuestStarSettingsUpdate => "channel.guest_star_settings.update" ("beta") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[
+            ChannelReadGuestStar,
+            ChannelManageGuestStar,
+            ModeratorReadGuestStar,
+            ModeratorManageGuestStar,
+        ],
+    }
+    ChannelPointsAutomaticRewardRedemptionAdd => "channel.channel_points_automatic_reward_redemption.add" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsAutomaticRewardRedemptionAddV2 => "channel.channel_points_automatic_reward_redemption.add" ("2") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsCustomRewardAdd => "channel.channel_points_custom_reward.add" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsCustomRewardUpdate => "channel.channel_points_custom_reward.update" ("1") {
+        condition: [broadcaster_user_id, reward_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsCustomRewardRemove => "channel.channel_points_custom_reward.remove" ("1") {
+        condition: [broadcaster_user_id, reward_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsCustomRewardRedemptionAdd => "channel.channel_points_custom_reward_redemption.add" ("1") {
+        condition: [broadcaster_user_id, reward_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPointsCustomRewardRedemptionUpdate => "channel.channel_points_custom_reward_redemption.update" ("1") {
+        condition: [broadcaster_user_id, reward_id],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    ChannelPollBegin => "channel.poll.begin" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPolls, ChannelManagePolls],
+    }
+    ChannelPollProgress => "channel.poll.progress" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPolls, ChannelManagePolls],
+    }
+    ChannelPollEnd => "channel.poll.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPolls, ChannelManagePolls],
+    }
+    ChannelPredictionBegin => "channel.prediction.begin" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPredictions, ChannelManagePredictions],
+    }
+    ChannelPredictionProgress => "channel.prediction.progress" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPredictions, ChannelManagePredictions],
+    }
+    ChannelPredictionLock => "channel.prediction.lock" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPredictions, ChannelManagePredictions],
+    }
+    ChannelPredictionEnd => "channel.prediction.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadPredictions, ChannelManagePredictions],
+    }
+    ChannelSuspiciousUserMessage => "channel.suspicious_user.message" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorReadSuspiciousUsers,
+    }
+    ChannelSuspiciousUserUpdate => "channel.suspicious_user.update" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: ModeratorReadSuspiciousUsers,
+    }
+    ChannelVipAdd => "channel.vip.add" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadVips, ChannelManageVips],
+    }
+    ChannelVipRemove => "channel.vip.remove" ("1") {
+        condition: [broadcaster_user_id],
+        requires: any[ChannelReadVips, ChannelManageVips],
+    }
+    ChannelWarningAcknowledge => "channel.warning.acknowledge" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadWarnings, ModeratorManageWarnings],
+    }
+    ChannelWarningSend => "channel.warning.send" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadWarnings, ModeratorManageWarnings],
+    }
+    ChannelHypeTrainBegin => "channel.hype_train.begin" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelHypeTrainBeginV2 => "channel.hype_train.begin" ("2") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelHypeTrainProgress => "channel.hype_train.progress" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelHypeTrainProgressV2 => "channel.hype_train.progress" ("2") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelHypeTrainEnd => "channel.hype_train.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelHypeTrainEndV2 => "channel.hype_train.end" ("2") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadHypeTrain,
+    }
+    ChannelCharityCampaignDonate => "channel.charity_campaign.donate" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadCharity,
+    }
+    ChannelCharityCampaignStart => "channel.charity_campaign.start" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadCharity,
+    }
+    ChannelCharityCampaignProgress => "channel.charity_campaign.progress" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadCharity,
+    }
+    ChannelCharityCampaignStop => "channel.charity_campaign.stop" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadCharity,
+    }
+    ChannelShieldModeBegin => "channel.shield_mode.begin" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadShieldMode, ModeratorManageShieldMode],
+    }
+    ChannelShieldModeEnd => "channel.shield_mode.end" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadShieldMode, ModeratorManageShieldMode],
+    }
+    ChannelShoutoutCreate => "channel.shoutout.create" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadShoutouts, ModeratorManageShoutouts],
+    }
+    ChannelShoutoutReceive => "channel.shoutout.receive" ("1") {
+        condition: [broadcaster_user_id, moderator_user_id],
+        requires: any[ModeratorReadShoutouts, ModeratorManageShoutouts],
+    }
+    ConduitShardDisabled => "conduit.shard.disabled" ("1") {
+        condition: [client_id, conduit_id],
+        transports: [Webhook],
+        requires: none,
+    }
+    DropEntitlementGrant => "drop.entitlement.grant" ("1") {
+        condition: [organization_id, category_id, campaign_id],
+        transports: [Webhook],
+        requires: none,
+    }
+    ExtensionBitsTransactionCreate => "extension.bits_transaction.create" ("1") {
+        condition: [extension_client_id],
+        transports: [Webhook],
+        requires: none,
+    }
+    ChannelGoalBegin => "channel.goal.begin" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadGoals,
+    }
+    ChannelGoalProgress => "channel.goal.progress" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadGoals,
+    }
+    ChannelGoalEnd => "channel.goal.end" ("1") {
+        condition: [broadcaster_user_id],
+        requires: ChannelReadGoals,
+    }
+    StreamOnline => "stream.online" ("1") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    StreamOffline => "stream.offline" ("1") {
+        condition: [broadcaster_user_id],
+        requires: none,
+    }
+    UserAuthorizationGrant => "user.authorization.grant" ("1") {
+        condition: [client_id],
+        transports: [Webhook],
+        requires: none,
+    }
+    UserAuthorizationRevoke => "user.authorization.revoke" ("1") {
+        condition: [client_id],
+        transports: [Webhook],
+        requires: none,
+    }
+    UserUpdate => "user.update" ("1") {
+        condition: [user_id],
+        requires: none,
+    }
+    UserWhisperMessage => "user.whisper.message" ("1") {
+        condition: [user_id],
+        requires: any[UserReadWhispers, UserManageWhispers],
+    }
+}
+
+/// How EventSub notifications are delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventSubTransport {
+    Webhook,
+    WebSocket,
+    Conduit,
+}
+
+impl EventSubTransport {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Webhook => "webhook",
+            Self::WebSocket => "websocket",
+            Self::Conduit => "conduit",
+        }
+    }
+
+    /// Token used to create subscriptions on this transport
+    ///
+    /// Webhooks and conduits are created with an app access token, WebSocket
+    /// subscriptions with a user access token.
+    pub const fn token_type(&self) -> TokenType {
+        match self {
+            Self::Webhook | Self::Conduit => TokenType::App,
+            Self::WebSocket => TokenType::User,
+        }
+    }
+}
+
+impl Display for EventSubTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+impl EventSubType {
+    /// Look up a subscription by the `type` and `version` sent to Twitch
+    ///
+    /// ```
+    /// use twitch_oauth_token::{scope::EventSubType, Scope};
+    ///
+    /// let follow = EventSubType::lookup("channel.follow", "2").unwrap();
+    /// assert_eq!(follow, EventSubType::ChannelFollow);
+    /// assert_eq!(follow.condition(), ["broadcaster_user_id", "moderator_user_id"]);
+    /// assert!(follow
+    ///     .requirement()
+    ///     .is_satisfied_by(&Scope::ModeratorReadFollowers.into()));
+    ///
+    /// assert!(EventSubType::lookup("channel.follow", "1").is_err());
+    /// ```
+    pub fn lookup(kind: &str, version: &str) -> Result<Self, UnknownEventSubError> {
+        Self::ALL
+            .iter()
+            .find(|t| t.as_str() == kind && t.version() == version)
+            .copied()
+            .ok_or_else(|| UnknownEventSubError {
+                kind: kind.to_string(),
+                version: version.to_string(),
+            })
+    }
+
+    /// Every known version of a subscription type, oldest first
+    pub fn versions(kind: &str) -> impl Iterator<Item = EventSubType> + '_ {
+        Self::ALL
+            .iter()
+            .copied()
+            .filter(move |t| t.as_str() == kind)
+    }
+
+    pub fn supports(&self, transport: EventSubTransport) -> bool {
+        self.transports().contains(&transport)
+    }
+
+    /// Check whether a token can create this subscription
+    ///
+    /// `scopes` are the scopes the user in the condition authorized. With an app
+    /// access token these are the scopes granted to the client, not the app token
+    /// itself.
+    pub fn check(&self, transport: EventSubTransport, scopes: &ScopeSet) -> Result<(), Error> {
+        let name = self.to_string();
+        if !self.supports(transport) {
+            return Err(error::scope::transport(&name, transport));
+        }
+
+        let requirement = self.requirement();
+        if !requirement.is_satisfied_by(scopes) {
+            return Err(error::scope::missing(&name, &requirement));
+        }
+
+        Ok(())
+    }
+}
+
+/// `channel.follow v2`
+impl Display for EventSubType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{} v{}", self.as_str(), self.version())
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownEventSubError {
+    kind: String,
+    version: String,
+}
+
+impl Display for UnknownEventSubError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "Unknown EventSub subscription: '{}' version '{}'",
+            self.kind, self.version
+        )
+    }
+}
+
+impl std::error::Error for UnknownEventSubError {}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        scope::{EventSubTransport, EventSubType, ScopeSet},
+        Scope,
+    };
+
+    #[test]
+    fn registry() {
+        let mut seen = HashSet::new();
+        for kind in EventSubType::ALL {
+            assert!(seen.insert((kind.as_str(), kind.version())), "{kind}");
+            assert!(!kind.condition().is_empty(), "{kind}");
+            assert_eq!(
+                EventSubType::lookup(kind.as_str(), kind.version()).unwrap(),
+                *kind
+            );
+        }
+
+        assert_eq!(
+            EventSubType::versions("channel.moderate").collect::<Vec<_>>(),
+            [
+                EventSubType::ChannelModerate,
+                EventSubType::ChannelModerateV2
+            ]
+        );
+    }
+
+    #[test]
+    fn scope_catalog_types_match() {
+        for scope in Scope::ALL {
+            for kind in scope.eventsub_types() {
+                assert!(
+                    EventSubType::versions(kind).next().is_some(),
+                    "{scope}: {kind}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn check() {
+        let kind = EventSubType::UserAuthorizationRevoke;
+        assert!(kind
+            .check(EventSubTransport::Webhook, &ScopeSet::new())
+            .is_ok());
+
+        let err = kind
+            .check(EventSubTransport::WebSocket, &ScopeSet::new())
+            .unwrap_err();
+        assert!(err.is_scope_error());
+        assert_eq!(
+            err.message(),
+            Some("user.authorization.revoke v1 is not available over websocket")
+        );
+
+        let err = EventSubType::ChannelModerateV2
+            .check(
+                EventSubTransport::WebSocket,
+                &ScopeSet::from(Scope::ModeratorReadWarnings),
+            )
+            .unwrap_err();
+        assert!(err
+            .message()
+            .unwrap()
+            .starts_with("channel.moderate v2 requires (moderator:read:blocked_terms or"));
+    }
+}
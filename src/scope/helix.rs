@@ -0,0 +1,746 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use asknothingx2_util::api::Method;
+
+use crate::{error, Error};
+
+use super::{Requirement, Scope, ScopeSet, TokenType};
+
+define_endpoints! {
+    StartCommercial => "Start Commercial" ("start-commercial") {
+        POST "/channels/commercial",
+        tokens: [User],
+        requires: ChannelEditCommercial,
+    }
+    GetAdSchedule => "Get Ad Schedule" ("get-ad-schedule") {
+        GET "/channels/ads",
+        tokens: [User],
+        requires: ChannelReadAds,
+    }
+    SnoozeNextAd => "Snooze Next Ad" ("snooze-next-ad") {
+        POST "/channels/ads/schedule/snooze",
+        tokens: [User],
+        requires: ChannelManageAds,
+    }
+    GetExtensionAnalytics => "Get Extension Analytics" ("get-extension-analytics") {
+        GET "/analytics/extensions",
+        tokens: [User],
+        requires: AnalyticsReadExtensions,
+    }
+    GetGameAnalytics => "Get Game Analytics" ("get-game-analytics") {
+        GET "/analytics/games",
+        tokens: [User],
+        requires: AnalyticsReadGames,
+    }
+    GetBitsLeaderboard => "Get Bits Leaderboard" ("get-bits-leaderboard") {
+        GET "/bits/leaderboard",
+        tokens: [User],
+        requires: BitsRead,
+    }
+    GetCheermotes => "Get Cheermotes" ("get-cheermotes") {
+        GET "/bits/cheermotes",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetExtensionTransactions => "Get Extension Transactions" ("get-extension-transactions") {
+        GET "/extensions/transactions",
+        tokens: [App],
+        requires: none,
+    }
+    GetChannelInformation => "Get Channel Information" ("get-channel-information") {
+        GET "/channels",
+        tokens: [App, User],
+        requires: none,
+    }
+    ModifyChannelInformation => "Modify Channel Information" ("modify-channel-information") {
+        PATCH "/channels",
+        tokens: [User],
+        requires: ChannelManageBroadcast,
+    }
+    GetChannelEditors => "Get Channel Editors" ("get-channel-editors") {
+        GET "/channels/editors",
+        tokens: [User],
+        requires: ChannelReadEditors,
+    }
+    GetFollowedChannels => "Get Followed Channels" ("get-followed-channels") {
+        GET "/channels/followed",
+        tokens: [User],
+        requires: UserReadFollows,
+    }
+    GetChannelFollowers => "Get Channel Followers" ("get-channel-followers") {
+        GET "/channels/followers",
+        tokens: [User],
+        requires: ModeratorReadFollowers,
+    }
+    CreateCustomRewards => "Create Custom Rewards" ("create-custom-rewards") {
+        POST "/channel_points/custom_rewards",
+        tokens: [User],
+        requires: ChannelManageRedemptions,
+    }
+    DeleteCustomReward => "Delete Custom Reward" ("delete-custom-reward") {
+        DELETE "/channel_points/custom_rewards",
+        tokens: [User],
+        requires: ChannelManageRedemptions,
+    }
+    GetCustomReward => "Get Custom Reward" ("get-custom-reward") {
+        GET "/channel_points/custom_rewards",
+        tokens: [User],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    GetCustomRewardRedemption => "Get Custom Reward Redemption" ("get-custom-reward-redemption") {
+        GET "/channel_points/custom_rewards/redemptions",
+        tokens: [User],
+        requires: any[ChannelReadRedemptions, ChannelManageRedemptions],
+    }
+    UpdateCustomReward => "Update Custom Reward" ("update-custom-reward") {
+        PATCH "/channel_points/custom_rewards",
+        tokens: [User],
+        requires: ChannelManageRedemptions,
+    }
+    UpdateRedemptionStatus => "Update Redemption Status" ("update-redemption-status") {
+        PATCH "/channel_points/custom_rewards/redemptions",
+        tokens: [User],
+        requires: ChannelManageRedemptions,
+    }
+    GetCharityCampaign => "Get Charity Campaign" ("get-charity-campaign") {
+        GET "/charity/campaigns",
+        tokens: [User],
+        requires: ChannelReadCharity,
+    }
+    GetCharityCampaignDonations => "Get Charity Campaign Donations" ("get-charity-campaign-donations") {
+        GET "/charity/donations",
+        tokens: [User],
+        requires: ChannelReadCharity,
+    }
+    GetChatters => "Get Chatters" ("get-chatters") {
+        GET "/chat/chatters",
+        tokens: [User],
+        requires: ModeratorReadChatters,
+    }
+    GetChannelEmotes => "Get Channel Emotes" ("get-channel-emotes") {
+        GET "/chat/emotes",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetGlobalEmotes => "Get Global Emotes" ("get-global-emotes") {
+        GET "/chat/emotes/global",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetEmoteSets => "Get Emote Sets" ("get-emote-sets") {
+        GET "/chat/emotes/set",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetChannelChatBadges => "Get Channel Chat Badges" ("get-channel-chat-badges") {
+        GET "/chat/badges",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetGlobalChatBadges => "Get Global Chat Badges" ("get-global-chat-badges") {
+        GET "/chat/badges/global",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetChatSettings => "Get Chat Settings" ("get-chat-settings") {
+        GET "/chat/settings",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetSharedChatSession => "Get Shared Chat Session" ("get-shared-chat-session") {
+        GET "/shared_chat/session",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetUserEmotes => "Get User Emotes" ("get-user-emotes") {
+        GET "/chat/emotes/user",
+        tokens: [User],
+        requires: UserReadEmotes,
+    }
+    UpdateChatSettings => "Update Chat Settings" ("update-chat-settings") {
+        PATCH "/chat/settings",
+        tokens: [User],
+        requires: ModeratorManageChatSettings,
+    }
+    SendChatAnnouncement => "Send Chat Announcement" ("send-chat-announcement") {
+        POST "/chat/announcements",
+        tokens: [User],
+        requires: ModeratorManageAnnouncements,
+    }
+    SendShoutout => "Send a Shoutout" ("send-a-shoutout") {
+        POST "/chat/shoutouts",
+        tokens: [User],
+        requires: ModeratorManageShoutouts,
+    }
+    SendChatMessage => "Send Chat Message" ("send-chat-message") {
+        POST "/chat/messages",
+        tokens: [App, User],
+        requires: UserWriteChat,
+    }
+    GetUserChatColor => "Get User Chat Color" ("get-user-chat-color") {
+        GET "/chat/color",
+        tokens: [App, User],
+        requires: none,
+    }
+    UpdateUserChatColor => "Update User Chat Color" ("update-user-chat-color") {
+        PUT "/chat/color",
+        tokens: [User],
+        requires: UserManageChatColor,
+    }
+    CreateClip => "Create Clip" ("create-clip") {
+        POST "/clips",
+        tokens: [User],
+        requires: ClipsEdit,
+    }
+    CreateClipFromVod => "Create Clip From VOD" ("create-clip-from-vod") {
+        POST "/videos/clips",
+        tokens: [User],
+        requires: any[EditorManageClips, ChannelManageClips],
+    }
+    GetClips => "Get Clips" ("get-clips") {
+        GET "/clips",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetClipsDownload => "Get Clips Download" ("get-clips-download") {
+        GET "/clips/downloads",
+        tokens: [App, User],
+        requires: any[EditorManageClips, ChannelManageClips],
+    }
+    GetContentClassificationLabels => "Get Content Classification Labels" ("get-content-classification-labels") {
+        GET "/content_classification_labels",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetTopGames => "Get Top Games" ("get-top-games") {
+        GET "/games/top",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetGames => "Get Games" ("get-games") {
+        GET "/games",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetCreatorGoals => "Get Creator Goals" ("get-creator-goals") {
+        GET "/goals",
+        tokens: [User],
+        requires: ChannelReadGoals,
+    }
+    GetChannelGuestStarSettings => "Get Channel Guest Star Settings" ("get-channel-guest-star-settings") {
+        GET "/guest_star/channel_settings",
+        tokens: [User],
+        requires: any[ChannelReadGuestStar, ChannelManageGuestStar, ModeratorReadGuestStar, ModeratorManageGuestStar],
+    }
+    UpdateChannelGuestStarSettings => "Update Channel Guest Star Settings" ("update-channel-guest-star-settings") {
+        PUT "/guest_star/channel_settings",
+        tokens: [User],
+        requires: ChannelManageGuestStar,
+    }
+    GetGuestStarSession => "Get Guest Star Session" ("get-guest-star-session") {
+        GET "/guest_star/session",
+        tokens: [User],
+        requires: any[ChannelReadGuestStar, ChannelManageGuestStar, ModeratorReadGuestStar, ModeratorManageGuestStar],
+    }
+    CreateGuestStarSession => "Create Guest Star Session" ("create-guest-star-session") {
+        POST "/guest_star/session",
+        tokens: [User],
+        requires: ChannelManageGuestStar,
+    }
+    EndGuestStarSession => "End Guest Star Session" ("end-guest-star-session") {
+        DELETE "/guest_star/session",
+        tokens: [User],
+        requires: ChannelManageGuestStar,
+    }
+    GetGuestStarInvites => "Get Guest Star Invites" ("get-guest-star-invites") {
+        GET "/guest_star/invites",
+        tokens: [User],
+        requires: any[ChannelReadGuestStar, ChannelManageGuestStar, ModeratorReadGuestStar, ModeratorManageGuestStar],
+    }
+    SendGuestStarInvite => "Send Guest Star Invite" ("send-guest-star-invite") {
+        POST "/guest_star/invites",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    DeleteGuestStarInvite => "Delete Guest Star Invite" ("delete-guest-star-invite") {
+        DELETE "/guest_star/invites",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    AssignGuestStarSlot => "Assign Guest Star Slot" ("assign-guest-star-slot") {
+        POST "/guest_star/slot",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    UpdateGuestStarSlot => "Update Guest Star Slot" ("update-guest-star-slot") {
+        PATCH "/guest_star/slot",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    DeleteGuestStarSlot => "Delete Guest Star Slot" ("delete-guest-star-slot") {
+        DELETE "/guest_star/slot",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    UpdateGuestStarSlotSettings => "Update Guest Star Slot Settings" ("update-guest-star-slot-settings") {
+        PATCH "/guest_star/slot_settings",
+        tokens: [User],
+        requires: any[ChannelManageGuestStar, ModeratorManageGuestStar],
+    }
+    GetHypeTrainStatus => "Get Hype Train Status" ("get-hype-train-status") {
+        GET "/hypetrain/status",
+        tokens: [User],
+        requires: ChannelReadHypeTrain,
+    }
+    CheckAutomodStatus => "Check AutoMod Status" ("check-automod-status") {
+        POST "/moderation/enforcements/status",
+        tokens: [User],
+        requires: ModerationRead,
+    }
+    ManageHeldAutomodMessages => "Manage Held AutoMod Messages" ("manage-held-automod-messages") {
+        POST "/moderation/automod/message",
+        tokens: [User],
+        requires: ModeratorManageAutomod,
+    }
+    GetAutomodSettings => "Get AutoMod Settings" ("get-automod-settings") {
+        GET "/moderation/automod/settings",
+        tokens: [User],
+        requires: any[ModeratorReadAutomodSettings, ModeratorManageAutomodSettings],
+    }
+    UpdateAutomodSettings => "Update AutoMod Settings" ("update-automod-settings") {
+        PUT "/moderation/automod/settings",
+        tokens: [User],
+        requires: ModeratorManageAutomodSettings,
+    }
+    GetBannedUsers => "Get Banned Users" ("get-banned-users") {
+        GET "/moderation/banned",
+        tokens: [User],
+        requires: any[ModerationRead, ModeratorManageBannedUsers],
+    }
+    BanUser => "Ban User" ("ban-user") {
+        POST "/moderation/bans",
+        tokens: [User],
+        requires: ModeratorManageBannedUsers,
+    }
+    UnbanUser => "Unban User" ("unban-user") {
+        DELETE "/moderation/bans",
+        tokens: [User],
+        requires: ModeratorManageBannedUsers,
+    }
+    GetUnbanRequests => "Get Unban Requests" ("get-unban-requests") {
+        GET "/moderation/unban_requests",
+        tokens: [User],
+        requires: any[ModeratorReadUnbanRequests, ModeratorManageUnbanRequests],
+    }
+    ResolveUnbanRequests => "Resolve Unban Requests" ("resolve-unban-requests") {
+        PATCH "/moderation/unban_requests",
+        tokens: [User],
+        requires: ModeratorManageUnbanRequests,
+    }
+    GetBlockedTerms => "Get Blocked Terms" ("get-blocked-terms") {
+        GET "/moderation/blocked_terms",
+        tokens: [User],
+        requires: any[ModeratorReadBlockedTerms, ModeratorManageBlockedTerms],
+    }
+    AddBlockedTerm => "Add Blocked Term" ("add-blocked-term") {
+        POST "/moderation/blocked_terms",
+        tokens: [User],
+        requires: ModeratorManageBlockedTerms,
+    }
+    RemoveBlockedTerm => "Remove Blocked Term" ("remove-blocked-term") {
+        DELETE "/moderation/blocked_terms",
+        tokens: [User],
+        requires: ModeratorManageBlockedTerms,
+    }
+    DeleteChatMessages => "Delete Chat Messages" ("delete-chat-messages") {
+        DELETE "/moderation/chat",
+        tokens: [User],
+        requires: ModeratorManageChatMessages,
+    }
+    GetModeratedChannels => "Get Moderated Channels" ("get-moderated-channels") {
+        GET "/moderation/channels",
+        tokens: [User],
+        requires: UserReadModeratedChannels,
+    }
+    GetModerators => "Get Moderators" ("get-moderators") {
+        GET "/moderation/moderators",
+        tokens: [User],
+        requires: any[ModerationRead, ChannelManageModerators],
+    }
+    AddChannelModerator => "Add Channel Moderator" ("add-channel-moderator") {
+        POST "/moderation/moderators",
+        tokens: [User],
+        requires: ChannelManageModerators,
+    }
+    RemoveChannelModerator => "Remove Channel Moderator" ("remove-channel-moderator") {
+        DELETE "/moderation/moderators",
+        tokens: [User],
+        requires: ChannelManageModerators,
+    }
+    GetVips => "Get VIPs" ("get-vips") {
+        GET "/channels/vips",
+        tokens: [User],
+        requires: any[ChannelReadVips, ChannelManageVips],
+    }
+    AddChannelVip => "Add Channel VIP" ("add-channel-vip") {
+        POST "/channels/vips",
+        tokens: [User],
+        requires: ChannelManageVips,
+    }
+    RemoveChannelVip => "Remove Channel VIP" ("remove-channel-vip") {
+        DELETE "/channels/vips",
+        tokens: [User],
+        requires: ChannelManageVips,
+    }
+    UpdateShieldModeStatus => "Update Shield Mode Status" ("update-shield-mode-status") {
+        PUT "/moderation/shield_mode",
+        tokens: [User],
+        requires: ModeratorManageShieldMode,
+    }
+    GetShieldModeStatus => "Get Shield Mode Status" ("get-shield-mode-status") {
+        GET "/moderation/shield_mode",
+        tokens: [User],
+        requires: any[ModeratorReadShieldMode, ModeratorManageShieldMode],
+    }
+    WarnChatUser => "Warn Chat User" ("warn-chat-user") {
+        POST "/moderation/warnings",
+        tokens: [User],
+        requires: ModeratorManageWarnings,
+    }
+    AddSuspiciousStatusToChatUser => "Add Suspicious Status to Chat User" ("add-suspicious-status-to-chat-user") {
+        POST "/moderation/suspicious_users",
+        tokens: [User],
+        requires: ModeratorManageSuspiciousUsers,
+    }
+    RemoveSuspiciousStatusFromChatUser => "Remove Suspicious Status From Chat User" ("remove-suspicious-status-from-chat-user") {
+        DELETE "/moderation/suspicious_users",
+        tokens: [User],
+        requires: ModeratorManageSuspiciousUsers,
+    }
+    GetPolls => "Get Polls" ("get-polls") {
+        GET "/polls",
+        tokens: [User],
+        requires: any[ChannelReadPolls, ChannelManagePolls],
+    }
+    CreatePoll => "Create Poll" ("create-poll") {
+        POST "/polls",
+        tokens: [User],
+        requires: ChannelManagePolls,
+    }
+    EndPoll => "End Poll" ("end-poll") {
+        PATCH "/polls",
+        tokens: [User],
+        requires: ChannelManagePolls,
+    }
+    GetPredictions => "Get Predictions" ("get-predictions") {
+        GET "/predictions",
+        tokens: [User],
+        requires: any[ChannelReadPredictions, ChannelManagePredictions],
+    }
+    CreatePrediction => "Create Prediction" ("create-prediction") {
+        POST "/predictions",
+        tokens: [User],
+        requires: ChannelManagePredictions,
+    }
+    EndPrediction => "End Prediction" ("end-prediction") {
+        PATCH "/predictions",
+        tokens: [User],
+        requires: ChannelManagePredictions,
+    }
+    StartRaid => "Start a raid" ("start-a-raid") {
+        POST "/raids",
+        tokens: [User],
+        requires: ChannelManageRaids,
+    }
+    CancelRaid => "Cancel a raid" ("cancel-a-raid") {
+        DELETE "/raids",
+        tokens: [User],
+        requires: ChannelManageRaids,
+    }
+    GetChannelStreamSchedule => "Get Channel Stream Schedule" ("get-channel-stream-schedule") {
+        GET "/schedule",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetChannelICalendar => "Get Channel iCalendar" ("get-channel-icalendar") {
+        GET "/schedule/icalendar",
+        tokens: [App, User],
+        requires: none,
+    }
+    UpdateChannelStreamSchedule => "Update Channel Stream Schedule" ("update-channel-stream-schedule") {
+        PATCH "/schedule/settings",
+        tokens: [User],
+        requires: ChannelManageSchedule,
+    }
+    CreateChannelStreamScheduleSegment => "Create Channel Stream Schedule Segment" ("create-channel-stream-schedule-segment") {
+        POST "/schedule/segment",
+        tokens: [User],
+        requires: ChannelManageSchedule,
+    }
+    UpdateChannelStreamScheduleSegment => "Update Channel Stream Schedule Segment" ("update-channel-stream-schedule-segment") {
+        PATCH "/schedule/segment",
+        tokens: [User],
+        requires: ChannelManageSchedule,
+    }
+    DeleteChannelStreamScheduleSegment => "Delete Channel Stream Schedule Segment" ("delete-channel-stream-schedule-segment") {
+        DELETE "/schedule/segment",
+        tokens: [User],
+        requires: ChannelManageSchedule,
+    }
+    SearchCategories => "Search Categories" ("search-categories") {
+        GET "/search/categories",
+        tokens: [App, User],
+        requires: none,
+    }
+    SearchChannels => "Search Channels" ("search-channels") {
+        GET "/search/channels",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetStreamKey => "Get Stream Key" ("get-stream-key") {
+        GET "/streams/key",
+        tokens: [User],
+        requires: ChannelReadStreamKey,
+    }
+    GetStreams => "Get Streams" ("get-streams") {
+        GET "/streams",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetFollowedStreams => "Get Followed Streams" ("get-followed-streams") {
+        GET "/streams/followed",
+        tokens: [User],
+        requires: UserReadFollows,
+    }
+    CreateStreamMarker => "Create Stream Marker" ("create-stream-marker") {
+        POST "/streams/markers",
+        tokens: [User],
+        requires: ChannelManageBroadcast,
+    }
+    GetStreamMarkers => "Get Stream Markers" ("get-stream-markers") {
+        GET "/streams/markers",
+        tokens: [User],
+        requires: any[UserReadBroadcast, ChannelManageBroadcast],
+    }
+    GetBroadcasterSubscriptions => "Get Broadcaster Subscriptions" ("get-broadcaster-subscriptions") {
+        GET "/subscriptions",
+        tokens: [User],
+        requires: ChannelReadSubscriptions,
+    }
+    CheckUserSubscription => "Check User Subscription" ("check-user-subscription") {
+        GET "/subscriptions/user",
+        tokens: [User],
+        requires: UserReadSubscriptions,
+    }
+    GetChannelTeams => "Get Channel Teams" ("get-channel-teams") {
+        GET "/teams/channel",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetTeams => "Get Teams" ("get-teams") {
+        GET "/teams",
+        tokens: [App, User],
+        requires: none,
+    }
+    GetUsers => "Get Users" ("get-users") {
+        GET "/users",
+        tokens: [App, User],
+        requires: none,
+    }
+    UpdateUser => "Update User" ("update-user") {
+        PUT "/users",
+        tokens: [User],
+        requires: UserEdit,
+    }
+    GetAuthorizationByUser => "Get Authorization By User" ("get-authorization-by-user") {
+        GET "/authorization/users",
+        tokens: [App],
+        requires: none,
+    }
+    GetUserBlockList => "Get User Block List" ("get-user-block-list") {
+        GET "/users/blocks",
+        tokens: [User],
+        requires: UserReadBlockedUsers,
+    }
+    BlockUser => "Block User" ("block-user") {
+        PUT "/users/blocks",
+        tokens: [User],
+        requires: UserManageBlockedUsers,
+    }
+    UnblockUser => "Unblock User" ("unblock-user") {
+        DELETE "/users/blocks",
+        tokens: [User],
+        requires: UserManageBlockedUsers,
+    }
+    GetUserExtensions => "Get User Extensions" ("get-user-extensions") {
+        GET "/users/extensions/list",
+        tokens: [User],
+        requires: any[UserReadBroadcast, UserEditBroadcast],
+    }
+    GetUserActiveExtensions => "Get User Active Extensions" ("get-user-active-extensions") {
+        GET "/users/extensions",
+        tokens: [User],
+        requires: any[UserReadBroadcast, UserEditBroadcast],
+    }
+    UpdateUserExtensions => "Update User Extensions" ("update-user-extensions") {
+        PUT "/users/extensions",
+        tokens: [User],
+        requires: UserEditBroadcast,
+    }
+    GetVideos => "Get Videos" ("get-videos") {
+        GET "/videos",
+        tokens: [App, User],
+        requires: none,
+    }
+    DeleteVideos => "Delete Videos" ("delete-videos") {
+        DELETE "/videos",
+        tokens: [User],
+        requires: ChannelManageVideos,
+    }
+    SendWhisper => "Send Whisper" ("send-whisper") {
+        POST "/whispers",
+        tokens: [User],
+        requires: UserManageWhispers,
+    }
+}
+
+impl HelixEndpoint {
+    pub fn accepts(&self, token_type: TokenType) -> bool {
+        self.token_types().contains(&token_type)
+    }
+
+    /// Check whether a token can call this endpoint
+    ///
+    /// ```
+    /// use twitch_oauth_token::{
+    ///     scope::{HelixEndpoint, ScopeSet, TokenType},
+    ///     Scope,
+    /// };
+    ///
+    /// let scopes = ScopeSet::from(Scope::ModeratorReadChatters);
+    /// assert!(HelixEndpoint::GetChatters.check(TokenType::User, &scopes).is_ok());
+    ///
+    /// let err = HelixEndpoint::GetChatters
+    ///     .check(TokenType::App, &scopes)
+    ///     .unwrap_err();
+    /// assert!(err.is_scope_error());
+    /// ```
+    pub fn check(&self, token_type: TokenType, scopes: &ScopeSet) -> Result<(), Error> {
+        if !self.accepts(token_type) {
+            return Err(error::scope::token_type(self.name(), token_type));
+        }
+
+        let requirement = self.requirement();
+        if !requirement.is_satisfied_by(scopes) {
+            return Err(error::scope::missing(self.name(), &requirement));
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for HelixEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownEndpointError(String);
+
+impl Display for UnknownEndpointError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "Unknown Helix endpoint: '{}'", self.0)
+    }
+}
+
+impl std::error::Error for UnknownEndpointError {}
+
+/// Parses the API reference name, ignoring case
+impl FromStr for HelixEndpoint {
+    type Err = UnknownEndpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|endpoint| endpoint.name().eq_ignore_ascii_case(s))
+            .copied()
+            .ok_or_else(|| UnknownEndpointError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scope::{HelixEndpoint, ScopeSet, TokenType},
+        Scope,
+    };
+
+    #[test]
+    fn registry() {
+        for endpoint in HelixEndpoint::ALL {
+            assert!(endpoint.path().starts_with('/'), "{endpoint}");
+            assert!(!endpoint.token_types().is_empty(), "{endpoint}");
+            assert_eq!(endpoint.name().parse::<HelixEndpoint>().unwrap(), *endpoint);
+        }
+
+        let endpoint = HelixEndpoint::GetChatters;
+        assert_eq!(endpoint.method(), "GET");
+        assert_eq!(endpoint.path(), "/chat/chatters");
+        assert_eq!(
+            endpoint.reference_url(),
+            "https://dev.twitch.tv/docs/api/reference/#get-chatters"
+        );
+    }
+
+    #[test]
+    fn scope_catalog_names_match() {
+        for scope in Scope::ALL {
+            for name in scope.api_endpoints() {
+                assert!(name.parse::<HelixEndpoint>().is_ok(), "{scope}: {name}");
+            }
+        }
+    }
+
+    #[test]
+    fn check() {
+        let endpoint = HelixEndpoint::GetBannedUsers;
+        assert_eq!(
+            endpoint.requirement().to_string(),
+            "moderation:read or moderator:manage:banned_users"
+        );
+
+        let err = endpoint
+            .check(TokenType::User, &ScopeSet::from(Scope::ChatRead))
+            .unwrap_err();
+        assert!(err.is_scope_error());
+        assert_eq!(
+            err.message(),
+            Some("Get Banned Users requires moderation:read or moderator:manage:banned_users")
+        );
+
+        let scopes = ScopeSet::from(Scope::ModeratorManageBannedUsers);
+        assert!(endpoint.check(TokenType::User, &scopes).is_ok());
+
+        let err = endpoint.check(TokenType::App, &scopes).unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some("Get Banned Users does not accept app access tokens")
+        );
+
+        let err = HelixEndpoint::GetExtensionTransactions
+            .check(TokenType::User, &ScopeSet::new())
+            .unwrap_err();
+        assert_eq!(
+            err.message(),
+            Some("Get Extension Transactions does not accept user access tokens")
+        );
+
+        assert!(HelixEndpoint::GetGames
+            .check(TokenType::App, &ScopeSet::new())
+            .is_ok());
+    }
+}
@@ -0,0 +1,352 @@
+/// Defines [`Scope`](super::Scope) and its metadata from a single table.
+///
+/// Each entry generates the enum variant with its docs, the `as_str`/`FromStr`
+/// mapping, the data returned by `description`, `summary`, `category`,
+/// `api_endpoints`, `eventsub_types` and `deprecation`, and a type in the
+/// `marker` module.
+///
+/// ```text
+/// Variant => "scope:string" {
+///     description: "...",
+///     summary: "...",                         // short, user-facing
+///     category: Chat | Moderation | Channel | User,
+///     api: ["Helix Endpoint Name" ("reference-anchor"), ...],     // optional
+///     eventsub: ["subscription.type" ("reference-anchor"), ...],  // optional
+///     deprecated: "reason",                     // optional
+///     docs: "<https://...>",                    // optional
+/// }
+/// ```
+macro_rules! define_scopes {
+    (
+        $(
+            $variant:ident => $scope:literal {
+                description: $description:literal,
+                summary: $summary:literal,
+                category: $category:ident,
+                $(api: [$($api:literal ($api_anchor:literal)),* $(,)?],)?
+                $(eventsub: [$($eventsub:literal ($eventsub_anchor:literal)),* $(,)?],)?
+                $(deprecated: $deprecated:literal,)?
+                $(docs: $docs:literal,)?
+            }
+        )*
+    ) => {
+        /// Twitch OAuth scopes
+        ///
+        /// Descriptions, endpoints and EventSub types are also available at runtime
+        /// through [`Scope::description`], [`Scope::api_endpoints`] and
+        /// [`Scope::eventsub_types`].
+        ///
+        /// <https://dev.twitch.tv/docs/authentication/scopes/>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Scope {
+            $(
+                #[doc = concat!("`", $scope, "`")]
+                #[doc = ""]
+                #[doc = $description]
+                $(
+                    #[doc = ""]
+                    #[doc = "API"]
+                    $(#[doc = concat!(
+                        "- [", $api, "](https://dev.twitch.tv/docs/api/reference/#", $api_anchor, ")"
+                    )])*
+                )?
+                $(
+                    #[doc = ""]
+                    #[doc = "EventSub"]
+                    $(#[doc = concat!(
+                        "- [`", $eventsub, "`](https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#",
+                        $eventsub_anchor, ")"
+                    )])*
+                )?
+                $(
+                    #[doc = ""]
+                    #[doc = concat!("**Deprecated**: ", $deprecated)]
+                )?
+                $(
+                    #[doc = ""]
+                    #[doc = $docs]
+                )?
+                $variant,
+            )*
+        }
+
+        impl Scope {
+            /// Every scope, in declaration order
+            pub const ALL: &'static [Scope] = &[$(Self::$variant),*];
+
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $scope,)*
+                }
+            }
+
+            /// What the scope grants, as shown on Twitch's consent screen
+            pub const fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)*
+                }
+            }
+
+            /// Short English description for consent pages, e.g. `Send chat messages`
+            ///
+            /// See [`Translations`](crate::scope::Translations) to localize it.
+            pub const fn summary(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $summary,)*
+                }
+            }
+
+            pub const fn category(&self) -> ScopeCategory {
+                match self {
+                    $(Self::$variant => ScopeCategory::$category,)*
+                }
+            }
+
+            /// Helix API endpoints that use this scope, by reference name
+            pub const fn api_endpoints(&self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$variant => &[$($($api),*)?],)*
+                }
+            }
+
+            /// EventSub subscription types that use this scope
+            pub const fn eventsub_types(&self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$variant => &[$($($eventsub),*)?],)*
+                }
+            }
+
+            /// Why the scope shouldn't be requested anymore, if deprecated
+            pub const fn deprecation(&self) -> Option<&'static str> {
+                match self {
+                    $(Self::$variant => define_scopes!(@option $($deprecated)?),)*
+                }
+            }
+
+            pub const fn is_deprecated(&self) -> bool {
+                self.deprecation().is_some()
+            }
+        }
+
+        /// Type-level [`Scope`]s, used by [`scopes!`](crate::scopes)
+        pub mod marker {
+            $(
+                #[doc = concat!("`", $scope, "`")]
+                #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+                pub struct $variant;
+
+                impl super::ScopeMarker for $variant {
+                    const SCOPE: super::Scope = super::Scope::$variant;
+                }
+            )*
+        }
+
+        impl FromStr for Scope {
+            type Err = InvalidScopeError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($scope => Ok(Self::$variant),)*
+                    _ => Err(InvalidScopeError(s.to_string())),
+                }
+            }
+        }
+    };
+
+    (@option) => {
+        None
+    };
+    (@option $value:literal) => {
+        Some($value)
+    };
+}
+
+/// Defines [`HelixEndpoint`](super::HelixEndpoint) from a single table.
+///
+/// ```text
+/// Variant => "Reference Name" ("reference-anchor") {
+///     METHOD "/path",
+///     tokens: [App, User],
+///     requires: <requirement>,
+/// }
+/// ```
+///
+/// See [`requirement!`] for the `requires` syntax.
+macro_rules! define_endpoints {
+    (
+        $(
+            $variant:ident => $name:literal ($anchor:literal) {
+                $method:ident $path:literal,
+                tokens: [$($token:ident),+ $(,)?],
+                requires: $kind:ident $([$($inner:tt)*])?,
+            }
+        )*
+    ) => {
+        /// Twitch Helix API endpoints and the authentication they require
+        ///
+        /// <https://dev.twitch.tv/docs/api/reference/>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum HelixEndpoint {
+            $(
+                #[doc = concat!("[", $name, "](https://dev.twitch.tv/docs/api/reference/#", $anchor, ")")]
+                #[doc = ""]
+                #[doc = concat!("`", stringify!($method), " ", $path, "`")]
+                $variant,
+            )*
+        }
+
+        impl HelixEndpoint {
+            pub const ALL: &'static [HelixEndpoint] = &[$(Self::$variant),*];
+
+            /// Name used in the API reference, e.g. `Get Chatters`
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)*
+                }
+            }
+
+            pub const fn reference_url(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => concat!("https://dev.twitch.tv/docs/api/reference/#", $anchor),)*
+                }
+            }
+
+            pub fn method(&self) -> Method {
+                match self {
+                    $(Self::$variant => Method::$method,)*
+                }
+            }
+
+            /// Path relative to `https://api.twitch.tv/helix`
+            pub const fn path(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $path,)*
+                }
+            }
+
+            pub const fn token_types(&self) -> &'static [TokenType] {
+                match self {
+                    $(Self::$variant => &[$(TokenType::$token),+],)*
+                }
+            }
+
+            pub const fn requirement(&self) -> Requirement {
+                match self {
+                    $(Self::$variant => requirement!($kind $([$($inner)*])?),)*
+                }
+            }
+        }
+    };
+}
+
+/// Builds a [`Requirement`](super::Requirement) expression.
+///
+/// ```text
+/// none
+/// Scope
+/// any[<requirement>, ...]
+/// all[<requirement>, ...]
+/// ```
+///
+/// e.g. `all[UserReadChat, any[ModeratorReadVips, ChannelReadVips]]`
+macro_rules! requirement {
+    (none) => {
+        Requirement::None
+    };
+    (any [$($kind:ident $([$($inner:tt)*])?),+ $(,)?]) => {
+        Requirement::AnyOf(&[$(requirement!($kind $([$($inner)*])?)),+])
+    };
+    (all [$($kind:ident $([$($inner:tt)*])?),+ $(,)?]) => {
+        Requirement::AllOf(&[$(requirement!($kind $([$($inner)*])?)),+])
+    };
+    ($scope:ident) => {
+        Requirement::Scope(Scope::$scope)
+    };
+}
+
+/// Defines [`EventSubType`](super::EventSubType) from a single table.
+///
+/// ```text
+/// Variant => "subscription.type" ("version") {
+///     condition: [field, ...],
+///     transports: [Webhook, ...],    // optional, defaults to every transport
+///     requires: <requirement>,
+/// }
+/// ```
+///
+/// See [`requirement!`] for the `requires` syntax.
+macro_rules! define_eventsub {
+    (
+        $(
+            $variant:ident => $type:literal ($version:literal) {
+                condition: [$($field:ident),+ $(,)?],
+                $(transports: [$($transport:ident),+ $(,)?],)?
+                requires: $kind:ident $([$($inner:tt)*])?,
+            }
+        )*
+    ) => {
+        /// EventSub subscription types and versions, with the authorization they require
+        ///
+        /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum EventSubType {
+            $(
+                #[doc = concat!("`", $type, "` version `", $version, "`")]
+                $variant,
+            )*
+        }
+
+        impl EventSubType {
+            pub const ALL: &'static [EventSubType] = &[$(Self::$variant),*];
+
+            /// Subscription type, e.g. `channel.follow`
+            pub const fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $type,)*
+                }
+            }
+
+            pub const fn version(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $version,)*
+                }
+            }
+
+            /// Fields of the subscription condition
+            ///
+            /// Some are alternatives or optional, e.g. `channel.raid` takes either
+            /// `from_broadcaster_user_id` or `to_broadcaster_user_id`.
+            pub const fn condition(&self) -> &'static [&'static str] {
+                match self {
+                    $(Self::$variant => &[$(stringify!($field)),+],)*
+                }
+            }
+
+            pub const fn transports(&self) -> &'static [EventSubTransport] {
+                match self {
+                    $(Self::$variant => define_eventsub!(@transports $($($transport),+)?),)*
+                }
+            }
+
+            /// Scopes the user named in the condition must have authorized
+            pub const fn requirement(&self) -> Requirement {
+                match self {
+                    $(Self::$variant => requirement!($kind $([$($inner)*])?),)*
+                }
+            }
+        }
+    };
+
+    (@transports) => {
+        &[
+            EventSubTransport::Webhook,
+            EventSubTransport::WebSocket,
+            EventSubTransport::Conduit,
+        ]
+    };
+    (@transports $($transport:ident),+) => {
+        &[$(EventSubTransport::$transport),+]
+    };
+}
@@ -1,769 +1,786 @@
+#[macro_use]
+mod macros;
+mod analyzer;
+mod consent;
+mod eventsub;
+mod helix;
+mod requirement;
+mod scopes;
 mod scopes_mut;
+mod set;
+mod typed;
 
+pub use analyzer::{ScopeAnalyzer, ScopeLint, ScopeReport};
+pub use consent::{ConsentSection, English, ScopeCategory, TranslationTable, Translations};
+pub use eventsub::{EventSubTransport, EventSubType, UnknownEventSubError};
+pub use helix::{HelixEndpoint, UnknownEndpointError};
+pub use requirement::{GrantedScopes, MissingScopes, Requirement, TokenType};
+pub use scopes::{ScopeName, Scopes};
 pub use scopes_mut::ScopesMut;
+pub use set::{ScopeSet, ScopeSetIter};
+pub use typed::{Cons, Nil, ScopeList, ScopeMarker, ScopedToken};
 
 use std::{ops::Deref, str::FromStr};
 
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
-use std::{collections::HashSet, fmt::Display};
+use std::fmt::Display;
 
-pub(crate) fn scopes_mut(scopes: &mut HashSet<Scope>) -> ScopesMut<'_> {
+pub(crate) fn scopes_mut(scopes: &mut Scopes) -> ScopesMut<'_> {
     scopes_mut::new(scopes)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Scope {
-    /// View analytics data for the Twitch Extensions owned by the authenticated account.
-    /// API
-    /// Get Extension Analytics
-    /// <https://dev.twitch.tv/docs/api/reference/#get-extension-analytics>
-    AnalyticsReadExtensions,
-    /// View analytics data for the games owned by the authenticated account.
-    /// API
-    /// Get Game Analytics
-    /// <https://dev.twitch.tv/docs/api/reference/#get-game-analytics>
-    AnalyticsReadGames,
-    /// View Bits information for a channel.
-    ///
-    /// API
-    /// Get Bits Leaderboard
-    /// <https://dev.twitch.tv/docs/api/reference/#get-bits-leaderboard>
-    ///
-    /// EventSub
-    /// Channel Cheer
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelcheer>
-    BitsRead,
-    /// Joins your channel’s chatroom as a bot user, and perform chat-related actions as that user.
-    ///
-    /// API
-    /// Send Chat Message
-    /// <https://dev.twitch.tv/docs/api/reference/#send-chat-message>
-    ///
-    /// EventSub
-    /// Channel Chat Clear
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatclear>
-    /// Channel Chat Clear User Messages
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatclear_user_messages>
-    /// Channel Chat Message
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatmessage>
-    /// Channel Chat Message Delete
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatmessage_delete>
-    /// Channel Chat Notification
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchatnotification>
-    /// Channel Chat Settings Update
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelchat_settingsupdate>
-    ChannelBot,
-    /// Manage ads schedule on a channel.
-    ///
-    /// API
-    /// Snooze Next Ad
-    /// <https://dev.twitch.tv/docs/api/reference/#snooze-next-ad>
-    ChannelManageAds,
-    /// Read the ads schedule and details on your channel.
-    ///
-    /// API
-    /// Get Ad Schedule
-    /// <https://dev.twitch.tv/docs/api/reference/#get-ad-schedule>
-    ///
-    /// EventSub
-    /// Channel Ad Break Begin
-    /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelad_breakbegin>
-    ChannelReadAds,
-    /// Manage a channel’s broadcast configuration, including updating channel configuration and managing stream markers and stream tags.
-    ///
-    /// API
-    /// Modify Channel Information
-    /// Create Stream Marker
-    /// Replace Stream Tags
-    ChannelManageBroadcast,
-    /// Read charity campaign details and user donations on your channel.
-    ///
-    /// API
-    /// Get Charity Campaign
-    /// Get Charity Campaign Donations
-    ///
-    /// EventSub
-    /// Charity Donation
-    /// Charity Campaign Start
-    /// Charity Campaign Progress
-    /// Charity Campaign Stop
-    ChannelReadCharity,
-    /// Run commercials on a channel.
-    ///
-    /// API
-    /// Start Commercial
-    ChannelEditCommercial,
-    /// View a list of users with the editor role for a channel.
-    ///
-    /// API
-    /// Get Channel Editors
-    ChannelReadEditors,
-    /// Manage a channel’s Extension configuration, including activating Extensions.
-    ///
-    /// API
-    /// Get User Active Extensions
-    /// Update User Extensions
-    ChannelManageExtensions,
-    /// View Creator Goals for a channel.
-    ///
-    /// API
-    /// Get Creator Goals
-    ///
-    /// EventSub
-    /// Goal Begin
-    /// Goal Progress
-    /// Goal End
-    ChannelReadGoals,
-    /// Read Guest Star details for your channel.
-    ///
-    /// API
-    /// Get Channel Guest Star Settings
-    /// Get Guest Star Session
-    /// Get Guest Star Invites
-    ///
-    /// EventSub
-    /// Channel Guest Star Session Begin
-    /// Channel Guest Star Session End
-    /// Channel Guest Star Guest Update
-    /// Channel Guest Star Settings Update
-    ChannelReadGuestStar,
-    /// Manage Guest Star for your channel.
-    ///
-    /// API
-    /// Update Channel Guest Star Settings
-    /// Create Guest Star Session
-    /// End Guest Star Session
-    /// Send Guest Star Invite
-    /// Delete Guest Star Invite
-    /// Assign Guest Star Slot
-    /// Update Guest Star Slot
-    /// Delete Guest Star Slot
-    /// Update Guest Star Slot Settings
-    ///
-    /// EventSub
-    /// Channel Guest Star Session Begin
-    /// Channel Guest Star Session End
-    /// Channel Guest Star Guest Update
-    /// Channel Guest Star Settings Update
-    ChannelManageGuestStar,
-    /// View Hype Train information for a channel.
-    ///
-    /// API
-    /// Get Hype Train Events
-    ///
-    /// EventSub
-    /// Hype Train Begin
-    /// Hype Train Progress
-    /// Hype Train End
-    ChannelReadHypeTrain,
-    /// Add or remove the moderator role from users in your channel.
-    ///
-    /// API
-    /// Add Channel Moderator
-    /// Remove Channel Moderator
-    /// Get Moderators
-    ChannelManageModerators,
-    /// Perform moderation actions in a channel.
-    ///
-    /// EventSub
-    /// Channel Moderate
-    /// Channel Moderate v2
-    ChannelModerate,
-    /// View a channel’s polls.
-    ///
-    /// API
-    /// Get Polls
-    ///
-    /// EventSub
-    /// Channel Poll Begin
-    /// Channel Poll Progress
-    /// Channel Poll End
-    ChannelReadPolls,
-    /// Manage a channel’s polls.
-    ///
-    /// API
-    /// Get Polls
-    /// Create Poll
-    /// End Poll
-    ///
-    /// EventSub
-    /// Channel Poll Begin
-    /// Channel Poll Progress
-    /// Channel Poll End
-    ChannelManagePolls,
-    /// View a channel’s Channel Points Predictions.
-    ///
-    /// API
-    /// Get Channel Points Predictions
-    ///
-    /// EventSub
-    /// Channel Prediction Begin
-    /// Channel Prediction Progress
-    /// Channel Prediction Lock
-    /// Channel Prediction End
-    ChannelReadPredictions,
-    /// Manage of channel’s Channel Points Predictions
-    ///
-    /// API
-    /// Get Channel Points Predictions
-    /// Create Channel Points Prediction
-    /// End Channel Points Prediction
-    ///
-    /// EventSub
-    /// Channel Prediction Begin
-    /// Channel Prediction Progress
-    /// Channel Prediction Lock
-    /// Channel Prediction End
-    ChannelManagePredictions,
-    /// Manage a channel raiding another channel.
-    ///
-    /// API
-    /// Start a raid
-    /// Cancel a raid
-    ChannelManageRaids,
-    /// View Channel Points custom rewards and their redemptions on a channel.
-    ///
-    /// API
-    /// Get Custom Reward
-    /// Get Custom Reward Redemption
-    ///
-    /// EventSub
-    /// Channel Points Automatic Reward Redemption
-    /// Channel Points Custom Reward Add
-    /// Channel Points Custom Reward Update
-    /// Channel Points Custom Reward Remove
-    /// Channel Points Custom Reward Redemption Add
-    /// Channel Points Custom Reward Redemption Update
-    ChannelReadRedemptions,
-    /// Manage Channel Points custom rewards and their redemptions on a channel.
-    ///
-    /// API
-    /// Get Custom Reward
-    /// Get Custom Reward Redemption
-    /// Create Custom Rewards
-    /// Delete Custom Reward
-    /// Update Custom Reward
-    /// Update Redemption Status
-    ///
-    /// EventSub
-    /// Channel Points Automatic Reward Redemption
-    /// Channel Points Custom Reward Add
-    /// Channel Points Custom Reward Update
-    /// Channel Points Custom Reward Remove
-    /// Channel Points Custom Reward Redemption Add
-    /// Channel Points Custom Reward Redemption Update
-    ChannelManageRedemptions,
-    /// Manage a channel’s stream schedule.
-    ///
-    /// API
-    /// Update Channel Stream Schedule
-    /// Create Channel Stream Schedule Segment
-    /// Update Channel Stream Schedule Segment
-    /// Delete Channel Stream Schedule Segment
-    ChannelManageSchedule,
-    /// View an authorized user’s stream key.
-    ///
-    /// API
-    /// Get Stream Key
-    ChannelReadStreamKey,
-    /// View a list of all subscribers to a channel and check if a user is subscribed to a channel.
-    ///
-    /// API
-    /// Get Broadcaster Subscriptions
-    ///
-    /// EventSub
-    /// Channel Subscribe
-    /// Channel Subscription End
-    /// Channel Subscription Gift
-    /// Channel Subscription Message
-    ChannelReadSubscriptions,
-    /// Manage a channel’s videos, including deleting videos.
-    ///
-    /// API
-    /// Delete Videos
-    ChannelManageVideos,
-    /// Read the list of VIPs in your channel.
-    ///
-    /// API
-    /// Get VIPs
-    ///
-    /// EventSub
-    /// Channel VIP Add
-    /// Channel VIP Remove
-    ChannelReadVips,
-    /// Add or remove the VIP role from users in your channel.
-    ///
-    /// API
-    /// Get VIPs
-    /// Add Channel VIP
-    /// Remove Channel VIP
-    ///
-    /// EventSub
-    /// Channel VIP Add
-    /// Channel VIP Remove
-    ChannelManageVips,
-    /// Manage Clips for a channel.
-    ///
-    /// API
-    /// Create Clip
-    ClipsEdit,
-    /// View a channel’s moderation data including Moderators, Bans, Timeouts, and Automod settings.
-    ///
-    /// API
-    /// Check AutoMod Status
-    /// Get Banned Users
-    /// Get Moderators
-    ///
-    /// EventSub
-    /// Channel Moderator Add
-    /// Channel Moderator Remove
-    ChannelManageClips,
-    EditorManageClips,
-    ModerationRead,
-    /// Send announcements in channels where you have the moderator role.
-    ///
-    /// API
-    /// Send Chat Announcement
-    ModeratorManageAnnouncements,
-    /// Manage messages held for review by AutoMod in channels where you are a moderator.
-    ///
-    /// API
-    /// Manage Held AutoMod Messages
-    ///
-    /// EventSub
-    /// AutoMod Message Hold
-    /// AutoMod Message Update
-    /// AutoMod Terms Update
-    ModeratorManageAutomod,
-    /// View a broadcaster’s AutoMod settings.
-    ///
-    /// API
-    /// Get AutoMod Settings
-    ///
-    /// EventSub
-    /// AutoMod Settings Update
-    ModeratorReadAutomodSettings,
-    /// Manage a broadcaster’s AutoMod settings.
-    ///
-    /// API
-    /// Update AutoMod Settings
-    ModeratorManageAutomodSettings,
-    /// Read the list of bans or unbans in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Moderate
-    /// Channel Moderate v2
-    ModeratorReadBannedUsers,
-    /// Ban and unban users.
-    ///
-    /// API
-    /// Get Banned Users
-    /// Ban User
-    /// Unban User
-    ///
-    /// EventSub
-    /// Channel Moderate
-    /// Channel Moderate v2
-    ModeratorManageBannedUsers,
-    /// View a broadcaster’s list of blocked terms.
-    ///
-    /// API
-    /// Get Blocked Terms
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorReadBlockedTerms,
-    /// Read deleted chat messages in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorReadChatMessages,
-    /// Manage a broadcaster’s list of blocked terms.
-    ///
-    /// API
-    /// Get Blocked Terms
-    /// Add Blocked Term
-    /// Remove Blocked Term
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorManageBlockedTerms,
-    /// Delete chat messages in channels where you have the moderator role
-    ///
-    /// API
-    /// Delete Chat Messages
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorManageChatMessages,
-    /// View a broadcaster’s chat room settings.
-    ///
-    /// API
-    /// Get Chat Settings
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorReadChatSettings,
-    /// Manage a broadcaster’s chat room settings.
-    ///
-    /// API
-    /// Update Chat Settings
-    ///
-    /// EventSub
-    /// Channel Moderate
-    ModeratorManageChatSettings,
-    /// View the chatters in a broadcaster’s chat room.
-    ///
-    /// API
-    /// Get Chatters
-    ModeratorReadChatters,
-    /// Read the followers of a broadcaster.
-    ///
-    /// API
-    /// Get Channel Followers
-    ///
-    /// EventSub
-    /// Channel Follow
-    ModeratorReadFollowers,
-    /// Read Guest Star details for channels where you are a Guest Star moderator.
-    ///
-    /// API
-    /// Get Channel Guest Star Settings
-    /// Get Guest Star Session
-    /// Get Guest Star Invites
-    ///
-    /// EventSub
-    /// Channel Guest Star Session Begin
-    /// Channel Guest Star Session End
-    /// Channel Guest Star Guest Update
-    /// Channel Guest Star Settings Update
-    ModeratorReadGuestStar,
-    /// Manage Guest Star for channels where you are a Guest Star moderator.
-    ///
-    /// API
-    /// Send Guest Star Invite
-    /// Delete Guest Star Invite
-    /// Assign Guest Star Slot
-    /// Update Guest Star Slot
-    /// Delete Guest Star Slot
-    /// Update Guest Star Slot Settings
-    ///
-    /// EventSub
-    /// Channel Guest Star Session Begin
-    /// Channel Guest Star Session End
-    /// Channel Guest Star Guest Update
-    /// Channel Guest Star Settings Update
-    ModeratorManageGuestStar,
-    /// Read the list of moderators in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Moderate
-    /// Channel Moderate v2
-    ModeratorReadModerators,
-    /// View a broadcaster’s Shield Mode status.
-    ///
-    /// API
-    /// Get Shield Mode Status
-    ///
-    /// EventSub
-    /// Shield Mode Begin
-    /// Shield Mode End
-    ModeratorReadShieldMode,
-    /// Manage a broadcaster’s Shield Mode status.
-    ///
-    /// API
-    /// Update Shield Mode Status
-    ///
-    /// EventSub
-    /// Shield Mode Begin
-    /// Shield Mode End
-    ModeratorManageShieldMode,
-    /// View a broadcaster’s shoutouts.
-    ///
-    /// EventSub
-    /// Shoutout Create
-    /// Shoutout Received
-    ModeratorReadShoutouts,
-    /// Manage a broadcaster’s shoutouts.
-    ///
-    /// API
-    /// Send a Shoutout
-    ///
-    /// EventSub
-    /// Shoutout Create
-    /// Shoutout Received
-    ModeratorManageShoutouts,
-    /// Read chat messages from suspicious users and see users flagged as suspicious in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Suspicious User Message
-    /// Channel Suspicious User Update
-    ModeratorReadSuspiciousUsers,
-    /// View a broadcaster’s unban requests.
-    ///
-    /// API
-    /// Get Unban Requests
-    ///
-    /// EventSub
-    /// Channel Unban Request Create
-    /// Channel Unban Request Resolve
-    /// Channel Moderate
-    ModeratorReadUnbanRequests,
-    /// Manage a broadcaster’s unban requests.
-    ///
-    /// API
-    /// Resolve Unban Requests
-    ///
-    /// EventSub
-    /// Channel Unban Request Create
-    /// Channel Unban Request Resolve
-    /// Channel Moderate
-    ModeratorManageUnbanRequests,
-    /// Read the list of VIPs in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Moderate
-    /// Channel Moderate v2
-    ModeratorReadVips,
-    /// Read warnings in channels where you have the moderator role.
-    ///
-    /// EventSub
-    /// Channel Moderate v2
-    /// Channel Warning Acknowledge
-    /// Channel Warning Send
-    ModeratorReadWarnings,
-    /// Warn users in channels where you have the moderator role.
-    ///
-    /// API
-    /// Warn Chat User
-    ///
-    /// EventSub
-    /// Channel Moderate v2
-    /// Channel Warning Acknowledge
-    /// Channel Warning Send
-    ModeratorManageWarnings,
-    ModeratorManageSuspiciousUsers,
-    /// Join a specified chat channel as your user and appear as a bot,
-    /// and perform chat-related actions as your user.
-    ///
-    /// API
-    /// Send Chat Message
-    /// <https://dev.twitch.tv/docs/api/reference/#send-chat-message>
-    ///
-    /// EventSub
-    /// Channel Chat Clear
-    /// Channel Chat Clear User Messages
-    /// Channel Chat Message
-    /// Channel Chat Message Delete
-    /// Channel Chat Notification
-    /// Channel Chat Settings Update
-    /// Channel Chat User Message Hold
-    /// Channel Chat User Message Update
-    UserBot,
-    /// Manage a user object.
-    ///
-    /// API
-    /// Update User
-    UserEdit,
-    /// View and edit a user’s broadcasting configuration, including Extension configurations.
-    ///
-    /// API
-    /// Get User Extensions
-    /// Get User Active Extensions
-    /// Update User Extensions
-    UserEditBroadcast,
-    /// View the block list of a user.
-    ///
-    /// API
-    /// Get User Block List
-    /// <https://dev.twitch.tv/docs/api/reference/#get-user-block-list>
-    UserReadBlockedUsers,
-    /// Manage the block list of a user.
-    ///
-    /// API
-    /// Block User
-    /// <https://dev.twitch.tv/docs/api/reference/#block-user>
-    /// Unblock User
-    /// <https://dev.twitch.tv/docs/api/reference/#unblock-user>
-    UserManageBlockedUsers,
-    /// View a user’s broadcasting configuration, including Extension configurations.
-    ///
-    /// API
-    /// Get Stream Markers
-    /// Get User Extensions
-    /// Get User Active Extensions
-    UserReadBroadcast,
-    /// Receive chatroom messages and informational notifications relating to a channel’s chatroom.
-    ///
-    /// EventSub
-    /// Channel Chat Clear
-    /// Channel Chat Clear User Messages
-    /// Channel Chat Message
-    /// Channel Chat Message Delete
-    /// Channel Chat Notification
-    /// Channel Chat Settings Update
-    /// Channel Chat User Message Hold
-    /// Channel Chat User Message Update
-    UserReadChat,
-    /// Update the color used for the user’s name in chat.
-    ///
-    /// API
-    /// Update User Chat Color
-    UserManageChatColor,
-    /// View a user’s email address.
-    ///
-    /// API
-    /// Get Users (optional)
-    /// Update User (optional)
-    ///
-    /// EventSub
-    /// User Update (optional)
-    UserReadEmail,
-    /// View emotes available to a user
-    ///
-    /// API
-    /// Get User Emotes
-    /// <https://dev.twitch.tv/docs/api/reference/#get-user-emotes>
-    UserReadEmotes,
-    /// View the list of channels a user follows.
-    ///
-    /// API
-    /// Get Followed Channels
-    /// Get Followed Streams
-    UserReadFollows,
-    /// Read the list of channels you have moderator privileges in.
-    ///
-    /// API
-    /// Get Moderated Channels
-    UserReadModeratedChannels,
-    /// View if an authorized user is subscribed to specific channels.
-    ///
-    /// API
-    /// Check User Subscription
-    UserReadSubscriptions,
-    /// Receive whispers sent to your user.
-    ///
-    /// EventSub
-    /// Whisper Received
-    UserReadWhispers,
-    /// Receive whispers sent to your user, and send whispers on your user’s behalf.
-    ///
-    /// API
-    /// Send Whisper
-    ///
-    /// EventSub
-    /// Whisper Received
-    UserManageWhispers,
-    /// Send chat messages to a chatroom.
-    ///
-    /// API
-    /// Send Chat Message
-    /// <https://dev.twitch.tv/docs/api/reference/#send-chat-message>
-    UserWriteChat,
-
-    /// Send chat messages to a chatroom using an IRC connection.
-    ///
-    /// <https://dev.twitch.tv/docs/chat/irc>
-    ChatEdit,
-    /// View chat messages sent in a chatroom using an IRC connection.
-    ///
-    /// <https://dev.twitch.tv/docs/chat/irc>
-    ChatRead,
-    /// The following table lists the scopes used only by PubSub.
-    /// There may be additional scopes needed for some PubSub topics, but those are not listed here.
-    /// Receive whisper messages for your user using PubSub.
-    ///
-    /// <https://dev.twitch.tv/docs/pubsub>
-    WhispersRead,
+define_scopes! {
+    AnalyticsReadExtensions => "analytics:read:extensions" {
+        description: "View analytics data for the Twitch Extensions owned by the authenticated account.",
+        summary: "View analytics for your Extensions",
+        category: Channel,
+        api: ["Get Extension Analytics" ("get-extension-analytics")],
+    }
+    AnalyticsReadGames => "analytics:read:games" {
+        description: "View analytics data for the games owned by the authenticated account.",
+        summary: "View analytics for your games",
+        category: Channel,
+        api: ["Get Game Analytics" ("get-game-analytics")],
+    }
+    BitsRead => "bits:read" {
+        description: "View Bits information for a channel.",
+        summary: "View Bits cheered in your channel",
+        category: Channel,
+        api: ["Get Bits Leaderboard" ("get-bits-leaderboard")],
+        eventsub: ["channel.bits.use" ("channelbitsuse"), "channel.cheer" ("channelcheer")],
+    }
+    ChannelBot => "channel:bot" {
+        description: "Joins your channel’s chatroom as a bot user, and perform chat-related actions as that user.",
+        summary: "Let a bot join and act in your chat",
+        category: Chat,
+        api: ["Send Chat Message" ("send-chat-message")],
+        eventsub: [
+            "channel.chat.clear" ("channelchatclear"),
+            "channel.chat.clear_user_messages" ("channelchatclear_user_messages"),
+            "channel.chat.message" ("channelchatmessage"),
+            "channel.chat.message_delete" ("channelchatmessage_delete"),
+            "channel.chat.notification" ("channelchatnotification"),
+            "channel.chat_settings.update" ("channelchat_settingsupdate"),
+        ],
+    }
+    ChannelManageAds => "channel:manage:ads" {
+        description: "Manage ads schedule on a channel.",
+        summary: "Manage your ad schedule",
+        category: Channel,
+        api: ["Snooze Next Ad" ("snooze-next-ad")],
+    }
+    ChannelReadAds => "channel:read:ads" {
+        description: "Read the ads schedule and details on your channel.",
+        summary: "View your ad schedule",
+        category: Channel,
+        api: ["Get Ad Schedule" ("get-ad-schedule")],
+        eventsub: ["channel.ad_break.begin" ("channelad_breakbegin")],
+    }
+    ChannelManageBroadcast => "channel:manage:broadcast" {
+        description: "Manage a channel’s broadcast configuration, including updating channel configuration and managing stream markers and stream tags.",
+        summary: "Update your stream title, category, markers and tags",
+        category: Channel,
+        api: [
+            "Modify Channel Information" ("modify-channel-information"),
+            "Create Stream Marker" ("create-stream-marker"),
+        ],
+    }
+    ChannelReadCharity => "channel:read:charity" {
+        description: "Read charity campaign details and user donations on your channel.",
+        summary: "View your charity campaigns and donations",
+        category: Channel,
+        api: [
+            "Get Charity Campaign" ("get-charity-campaign"),
+            "Get Charity Campaign Donations" ("get-charity-campaign-donations"),
+        ],
+        eventsub: [
+            "channel.charity_campaign.donate" ("channelcharity_campaigndonate"),
+            "channel.charity_campaign.start" ("channelcharity_campaignstart"),
+            "channel.charity_campaign.progress" ("channelcharity_campaignprogress"),
+            "channel.charity_campaign.stop" ("channelcharity_campaignstop"),
+        ],
+    }
+    ChannelEditCommercial => "channel:edit:commercial" {
+        description: "Run commercials on a channel.",
+        summary: "Run commercials on your channel",
+        category: Channel,
+        api: ["Start Commercial" ("start-commercial")],
+    }
+    ChannelReadEditors => "channel:read:editors" {
+        description: "View a list of users with the editor role for a channel.",
+        summary: "View your channel editors",
+        category: Channel,
+        api: ["Get Channel Editors" ("get-channel-editors")],
+    }
+    ChannelManageExtensions => "channel:manage:extensions" {
+        description: "Manage a channel’s Extension configuration, including activating Extensions.",
+        summary: "Manage your channel's Extensions",
+        category: Channel,
+        api: [
+            "Get User Active Extensions" ("get-user-active-extensions"),
+            "Update User Extensions" ("update-user-extensions"),
+        ],
+    }
+    ChannelReadGoals => "channel:read:goals" {
+        description: "View Creator Goals for a channel.",
+        summary: "View your Creator Goals",
+        category: Channel,
+        api: ["Get Creator Goals" ("get-creator-goals")],
+        eventsub: [
+            "channel.goal.begin" ("channelgoalbegin"),
+            "channel.goal.progress" ("channelgoalprogress"),
+            "channel.goal.end" ("channelgoalend"),
+        ],
+    }
+    ChannelReadGuestStar => "channel:read:guest_star" {
+        description: "Read Guest Star details for your channel.",
+        summary: "View your Guest Star sessions",
+        category: Channel,
+        api: [
+            "Get Channel Guest Star Settings" ("get-channel-guest-star-settings"),
+            "Get Guest Star Session" ("get-guest-star-session"),
+            "Get Guest Star Invites" ("get-guest-star-invites"),
+        ],
+        eventsub: [
+            "channel.guest_star_session.begin" ("channelguest_star_sessionbegin"),
+            "channel.guest_star_session.end" ("channelguest_star_sessionend"),
+            "channel.guest_star_guest.update" ("channelguest_star_guestupdate"),
+            "channel.guest_star_settings.update" ("channelguest_star_settingsupdate"),
+        ],
+    }
+    ChannelManageGuestStar => "channel:manage:guest_star" {
+        description: "Manage Guest Star for your channel.",
+        summary: "Manage your Guest Star sessions",
+        category: Channel,
+        api: [
+            "Update Channel Guest Star Settings" ("update-channel-guest-star-settings"),
+            "Create Guest Star Session" ("create-guest-star-session"),
+            "End Guest Star Session" ("end-guest-star-session"),
+            "Send Guest Star Invite" ("send-guest-star-invite"),
+            "Delete Guest Star Invite" ("delete-guest-star-invite"),
+            "Assign Guest Star Slot" ("assign-guest-star-slot"),
+            "Update Guest Star Slot" ("update-guest-star-slot"),
+            "Delete Guest Star Slot" ("delete-guest-star-slot"),
+            "Update Guest Star Slot Settings" ("update-guest-star-slot-settings"),
+        ],
+        eventsub: [
+            "channel.guest_star_session.begin" ("channelguest_star_sessionbegin"),
+            "channel.guest_star_session.end" ("channelguest_star_sessionend"),
+            "channel.guest_star_guest.update" ("channelguest_star_guestupdate"),
+            "channel.guest_star_settings.update" ("channelguest_star_settingsupdate"),
+        ],
+    }
+    ChannelReadHypeTrain => "channel:read:hype_train" {
+        description: "View Hype Train information for a channel.",
+        summary: "View your Hype Trains",
+        category: Channel,
+        api: ["Get Hype Train Status" ("get-hype-train-status")],
+        eventsub: [
+            "channel.hype_train.begin" ("channelhype_trainbegin"),
+            "channel.hype_train.progress" ("channelhype_trainprogress"),
+            "channel.hype_train.end" ("channelhype_trainend"),
+        ],
+    }
+    ChannelManageModerators => "channel:manage:moderators" {
+        description: "Add or remove the moderator role from users in your channel.",
+        summary: "Add or remove your moderators",
+        category: Moderation,
+        api: [
+            "Add Channel Moderator" ("add-channel-moderator"),
+            "Remove Channel Moderator" ("remove-channel-moderator"),
+            "Get Moderators" ("get-moderators"),
+        ],
+    }
+    ChannelModerate => "channel:moderate" {
+        description: "Perform moderation actions in a channel.",
+        summary: "Perform moderation actions in your channel",
+        category: Moderation,
+        eventsub: [
+            "channel.ban" ("channelban"),
+            "channel.unban" ("channelunban"),
+            "channel.moderate" ("channelmoderate"),
+        ],
+    }
+    ChannelReadPolls => "channel:read:polls" {
+        description: "View a channel’s polls.",
+        summary: "View your polls",
+        category: Channel,
+        api: ["Get Polls" ("get-polls")],
+        eventsub: [
+            "channel.poll.begin" ("channelpollbegin"),
+            "channel.poll.progress" ("channelpollprogress"),
+            "channel.poll.end" ("channelpollend"),
+        ],
+    }
+    ChannelManagePolls => "channel:manage:polls" {
+        description: "Manage a channel’s polls.",
+        summary: "Create and end polls",
+        category: Channel,
+        api: ["Get Polls" ("get-polls"), "Create Poll" ("create-poll"), "End Poll" ("end-poll")],
+        eventsub: [
+            "channel.poll.begin" ("channelpollbegin"),
+            "channel.poll.progress" ("channelpollprogress"),
+            "channel.poll.end" ("channelpollend"),
+        ],
+    }
+    ChannelReadPredictions => "channel:read:predictions" {
+        description: "View a channel’s Channel Points Predictions.",
+        summary: "View your Predictions",
+        category: Channel,
+        api: ["Get Predictions" ("get-predictions")],
+        eventsub: [
+            "channel.prediction.begin" ("channelpredictionbegin"),
+            "channel.prediction.progress" ("channelpredictionprogress"),
+            "channel.prediction.lock" ("channelpredictionlock"),
+            "channel.prediction.end" ("channelpredictionend"),
+        ],
+    }
+    ChannelManagePredictions => "channel:manage:predictions" {
+        description: "Manage of channel’s Channel Points Predictions",
+        summary: "Create, lock and resolve Predictions",
+        category: Channel,
+        api: [
+            "Get Predictions" ("get-predictions"),
+            "Create Prediction" ("create-prediction"),
+            "End Prediction" ("end-prediction"),
+        ],
+        eventsub: [
+            "channel.prediction.begin" ("channelpredictionbegin"),
+            "channel.prediction.progress" ("channelpredictionprogress"),
+            "channel.prediction.lock" ("channelpredictionlock"),
+            "channel.prediction.end" ("channelpredictionend"),
+        ],
+    }
+    ChannelManageRaids => "channel:manage:raids" {
+        description: "Manage a channel raiding another channel.",
+        summary: "Start and cancel raids",
+        category: Channel,
+        api: ["Start a raid" ("start-a-raid"), "Cancel a raid" ("cancel-a-raid")],
+    }
+    ChannelReadRedemptions => "channel:read:redemptions" {
+        description: "View Channel Points custom rewards and their redemptions on a channel.",
+        summary: "View your Channel Points rewards and redemptions",
+        category: Channel,
+        api: [
+            "Get Custom Reward" ("get-custom-reward"),
+            "Get Custom Reward Redemption" ("get-custom-reward-redemption"),
+        ],
+        eventsub: [
+            "channel.channel_points_automatic_reward_redemption.add" ("channelchannel_points_automatic_reward_redemptionadd"),
+            "channel.channel_points_custom_reward.add" ("channelchannel_points_custom_rewardadd"),
+            "channel.channel_points_custom_reward.update" ("channelchannel_points_custom_rewardupdate"),
+            "channel.channel_points_custom_reward.remove" ("channelchannel_points_custom_rewardremove"),
+            "channel.channel_points_custom_reward_redemption.add" ("channelchannel_points_custom_reward_redemptionadd"),
+            "channel.channel_points_custom_reward_redemption.update" ("channelchannel_points_custom_reward_redemptionupdate"),
+        ],
+    }
+    ChannelManageRedemptions => "channel:manage:redemptions" {
+        description: "Manage Channel Points custom rewards and their redemptions on a channel.",
+        summary: "Manage your Channel Points rewards and redemptions",
+        category: Channel,
+        api: [
+            "Get Custom Reward" ("get-custom-reward"),
+            "Get Custom Reward Redemption" ("get-custom-reward-redemption"),
+            "Create Custom Rewards" ("create-custom-rewards"),
+            "Delete Custom Reward" ("delete-custom-reward"),
+            "Update Custom Reward" ("update-custom-reward"),
+            "Update Redemption Status" ("update-redemption-status"),
+        ],
+        eventsub: [
+            "channel.channel_points_automatic_reward_redemption.add" ("channelchannel_points_automatic_reward_redemptionadd"),
+            "channel.channel_points_custom_reward.add" ("channelchannel_points_custom_rewardadd"),
+            "channel.channel_points_custom_reward.update" ("channelchannel_points_custom_rewardupdate"),
+            "channel.channel_points_custom_reward.remove" ("channelchannel_points_custom_rewardremove"),
+            "channel.channel_points_custom_reward_redemption.add" ("channelchannel_points_custom_reward_redemptionadd"),
+            "channel.channel_points_custom_reward_redemption.update" ("channelchannel_points_custom_reward_redemptionupdate"),
+        ],
+    }
+    ChannelManageSchedule => "channel:manage:schedule" {
+        description: "Manage a channel’s stream schedule.",
+        summary: "Manage your stream schedule",
+        category: Channel,
+        api: [
+            "Update Channel Stream Schedule" ("update-channel-stream-schedule"),
+            "Create Channel Stream Schedule Segment" ("create-channel-stream-schedule-segment"),
+            "Update Channel Stream Schedule Segment" ("update-channel-stream-schedule-segment"),
+            "Delete Channel Stream Schedule Segment" ("delete-channel-stream-schedule-segment"),
+        ],
+    }
+    ChannelReadStreamKey => "channel:read:stream_key" {
+        description: "View an authorized user’s stream key.",
+        summary: "View your stream key",
+        category: Channel,
+        api: ["Get Stream Key" ("get-stream-key")],
+    }
+    ChannelReadSubscriptions => "channel:read:subscriptions" {
+        description: "View a list of all subscribers to a channel and check if a user is subscribed to a channel.",
+        summary: "View your subscribers",
+        category: Channel,
+        api: ["Get Broadcaster Subscriptions" ("get-broadcaster-subscriptions")],
+        eventsub: [
+            "channel.subscribe" ("channelsubscribe"),
+            "channel.subscription.end" ("channelsubscriptionend"),
+            "channel.subscription.gift" ("channelsubscriptiongift"),
+            "channel.subscription.message" ("channelsubscriptionmessage"),
+        ],
+    }
+    ChannelManageVideos => "channel:manage:videos" {
+        description: "Manage a channel’s videos, including deleting videos.",
+        summary: "Manage and delete your videos",
+        category: Channel,
+        api: ["Delete Videos" ("delete-videos")],
+    }
+    ChannelReadVips => "channel:read:vips" {
+        description: "Read the list of VIPs in your channel.",
+        summary: "View your VIPs",
+        category: Channel,
+        api: ["Get VIPs" ("get-vips")],
+        eventsub: ["channel.vip.add" ("channelvipadd"), "channel.vip.remove" ("channelvipremove")],
+    }
+    ChannelManageVips => "channel:manage:vips" {
+        description: "Add or remove the VIP role from users in your channel.",
+        summary: "Add or remove your VIPs",
+        category: Channel,
+        api: [
+            "Get VIPs" ("get-vips"),
+            "Add Channel VIP" ("add-channel-vip"),
+            "Remove Channel VIP" ("remove-channel-vip"),
+        ],
+        eventsub: ["channel.vip.add" ("channelvipadd"), "channel.vip.remove" ("channelvipremove")],
+    }
+    ClipsEdit => "clips:edit" {
+        description: "Manage Clips for a channel.",
+        summary: "Create clips",
+        category: Channel,
+        api: ["Create Clip" ("create-clip")],
+    }
+    ChannelManageClips => "channel:manage:clips" {
+        description: "Manage Clips for a channel.",
+        summary: "Manage clips of your channel",
+        category: Channel,
+    }
+    EditorManageClips => "editor:manage:clips" {
+        description: "Manage Clips for channels where you have the editor role.",
+        summary: "Manage clips for channels you edit",
+        category: Channel,
+    }
+    ModerationRead => "moderation:read" {
+        description: "View a channel’s moderation data including Moderators, Bans, Timeouts, and Automod settings.",
+        summary: "View moderators, bans and AutoMod settings of your channel",
+        category: Moderation,
+        api: [
+            "Check AutoMod Status" ("check-automod-status"),
+            "Get Banned Users" ("get-banned-users"),
+            "Get Moderators" ("get-moderators"),
+        ],
+        eventsub: [
+            "channel.moderator.add" ("channelmoderatoradd"),
+            "channel.moderator.remove" ("channelmoderatorremove"),
+        ],
+    }
+    ModeratorManageAnnouncements => "moderator:manage:announcements" {
+        description: "Send announcements in channels where you have the moderator role.",
+        summary: "Send announcements in channels you moderate",
+        category: Moderation,
+        api: ["Send Chat Announcement" ("send-chat-announcement")],
+    }
+    ModeratorManageAutomod => "moderator:manage:automod" {
+        description: "Manage messages held for review by AutoMod in channels where you are a moderator.",
+        summary: "Review messages held by AutoMod in channels you moderate",
+        category: Moderation,
+        api: ["Manage Held AutoMod Messages" ("manage-held-automod-messages")],
+        eventsub: [
+            "automod.message.hold" ("automodmessagehold"),
+            "automod.message.update" ("automodmessageupdate"),
+            "automod.terms.update" ("automodtermsupdate"),
+        ],
+    }
+    ModeratorReadAutomodSettings => "moderator:read:automod_settings" {
+        description: "View a broadcaster’s AutoMod settings.",
+        summary: "View AutoMod settings in channels you moderate",
+        category: Moderation,
+        api: ["Get AutoMod Settings" ("get-automod-settings")],
+        eventsub: ["automod.settings.update" ("automodsettingsupdate")],
+    }
+    ModeratorManageAutomodSettings => "moderator:manage:automod_settings" {
+        description: "Manage a broadcaster’s AutoMod settings.",
+        summary: "Change AutoMod settings in channels you moderate",
+        category: Moderation,
+        api: ["Update AutoMod Settings" ("update-automod-settings")],
+    }
+    ModeratorReadBannedUsers => "moderator:read:banned_users" {
+        description: "Read the list of bans or unbans in channels where you have the moderator role.",
+        summary: "View bans in channels you moderate",
+        category: Moderation,
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorManageBannedUsers => "moderator:manage:banned_users" {
+        description: "Ban and unban users.",
+        summary: "Ban and unban users in channels you moderate",
+        category: Moderation,
+        api: [
+            "Get Banned Users" ("get-banned-users"),
+            "Ban User" ("ban-user"),
+            "Unban User" ("unban-user"),
+        ],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadBlockedTerms => "moderator:read:blocked_terms" {
+        description: "View a broadcaster’s list of blocked terms.",
+        summary: "View blocked terms in channels you moderate",
+        category: Moderation,
+        api: ["Get Blocked Terms" ("get-blocked-terms")],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadChatMessages => "moderator:read:chat_messages" {
+        description: "Read deleted chat messages in channels where you have the moderator role.",
+        summary: "View deleted messages in channels you moderate",
+        category: Moderation,
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorManageBlockedTerms => "moderator:manage:blocked_terms" {
+        description: "Manage a broadcaster’s list of blocked terms.",
+        summary: "Manage blocked terms in channels you moderate",
+        category: Moderation,
+        api: [
+            "Get Blocked Terms" ("get-blocked-terms"),
+            "Add Blocked Term" ("add-blocked-term"),
+            "Remove Blocked Term" ("remove-blocked-term"),
+        ],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorManageChatMessages => "moderator:manage:chat_messages" {
+        description: "Delete chat messages in channels where you have the moderator role",
+        summary: "Delete messages in channels you moderate",
+        category: Moderation,
+        api: ["Delete Chat Messages" ("delete-chat-messages")],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadChatSettings => "moderator:read:chat_settings" {
+        description: "View a broadcaster’s chat room settings.",
+        summary: "View chat settings in channels you moderate",
+        category: Moderation,
+        api: ["Get Chat Settings" ("get-chat-settings")],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorManageChatSettings => "moderator:manage:chat_settings" {
+        description: "Manage a broadcaster’s chat room settings.",
+        summary: "Change chat settings in channels you moderate",
+        category: Moderation,
+        api: ["Update Chat Settings" ("update-chat-settings")],
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadChatters => "moderator:read:chatters" {
+        description: "View the chatters in a broadcaster’s chat room.",
+        summary: "View who is in chat in channels you moderate",
+        category: Moderation,
+        api: ["Get Chatters" ("get-chatters")],
+    }
+    ModeratorReadFollowers => "moderator:read:followers" {
+        description: "Read the followers of a broadcaster.",
+        summary: "View followers of channels you moderate",
+        category: Moderation,
+        api: ["Get Channel Followers" ("get-channel-followers")],
+        eventsub: ["channel.follow" ("channelfollow")],
+    }
+    ModeratorReadGuestStar => "moderator:read:guest_star" {
+        description: "Read Guest Star details for channels where you are a Guest Star moderator.",
+        summary: "View Guest Star sessions you moderate",
+        category: Moderation,
+        api: [
+            "Get Channel Guest Star Settings" ("get-channel-guest-star-settings"),
+            "Get Guest Star Session" ("get-guest-star-session"),
+            "Get Guest Star Invites" ("get-guest-star-invites"),
+        ],
+        eventsub: [
+            "channel.guest_star_session.begin" ("channelguest_star_sessionbegin"),
+            "channel.guest_star_session.end" ("channelguest_star_sessionend"),
+            "channel.guest_star_guest.update" ("channelguest_star_guestupdate"),
+            "channel.guest_star_settings.update" ("channelguest_star_settingsupdate"),
+        ],
+    }
+    ModeratorManageGuestStar => "moderator:manage:guest_star" {
+        description: "Manage Guest Star for channels where you are a Guest Star moderator.",
+        summary: "Manage Guest Star sessions you moderate",
+        category: Moderation,
+        api: [
+            "Send Guest Star Invite" ("send-guest-star-invite"),
+            "Delete Guest Star Invite" ("delete-guest-star-invite"),
+            "Assign Guest Star Slot" ("assign-guest-star-slot"),
+            "Update Guest Star Slot" ("update-guest-star-slot"),
+            "Delete Guest Star Slot" ("delete-guest-star-slot"),
+            "Update Guest Star Slot Settings" ("update-guest-star-slot-settings"),
+        ],
+        eventsub: [
+            "channel.guest_star_session.begin" ("channelguest_star_sessionbegin"),
+            "channel.guest_star_session.end" ("channelguest_star_sessionend"),
+            "channel.guest_star_guest.update" ("channelguest_star_guestupdate"),
+            "channel.guest_star_settings.update" ("channelguest_star_settingsupdate"),
+        ],
+    }
+    ModeratorReadModerators => "moderator:read:moderators" {
+        description: "Read the list of moderators in channels where you have the moderator role.",
+        summary: "View moderators of channels you moderate",
+        category: Moderation,
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadShieldMode => "moderator:read:shield_mode" {
+        description: "View a broadcaster’s Shield Mode status.",
+        summary: "View Shield Mode status in channels you moderate",
+        category: Moderation,
+        api: ["Get Shield Mode Status" ("get-shield-mode-status")],
+        eventsub: [
+            "channel.shield_mode.begin" ("channelshield_modebegin"),
+            "channel.shield_mode.end" ("channelshield_modeend"),
+        ],
+    }
+    ModeratorManageShieldMode => "moderator:manage:shield_mode" {
+        description: "Manage a broadcaster’s Shield Mode status.",
+        summary: "Turn Shield Mode on or off in channels you moderate",
+        category: Moderation,
+        api: ["Update Shield Mode Status" ("update-shield-mode-status")],
+        eventsub: [
+            "channel.shield_mode.begin" ("channelshield_modebegin"),
+            "channel.shield_mode.end" ("channelshield_modeend"),
+        ],
+    }
+    ModeratorReadShoutouts => "moderator:read:shoutouts" {
+        description: "View a broadcaster’s shoutouts.",
+        summary: "View shoutouts in channels you moderate",
+        category: Moderation,
+        eventsub: [
+            "channel.shoutout.create" ("channelshoutoutcreate"),
+            "channel.shoutout.receive" ("channelshoutoutreceive"),
+        ],
+    }
+    ModeratorManageShoutouts => "moderator:manage:shoutouts" {
+        description: "Manage a broadcaster’s shoutouts.",
+        summary: "Send shoutouts in channels you moderate",
+        category: Moderation,
+        api: ["Send a Shoutout" ("send-a-shoutout")],
+        eventsub: [
+            "channel.shoutout.create" ("channelshoutoutcreate"),
+            "channel.shoutout.receive" ("channelshoutoutreceive"),
+        ],
+    }
+    ModeratorReadSuspiciousUsers => "moderator:read:suspicious_users" {
+        description: "Read chat messages from suspicious users and see users flagged as suspicious in channels where you have the moderator role.",
+        summary: "View suspicious users in channels you moderate",
+        category: Moderation,
+        eventsub: [
+            "channel.suspicious_user.message" ("channelsuspicious_usermessage"),
+            "channel.suspicious_user.update" ("channelsuspicious_userupdate"),
+        ],
+    }
+    ModeratorReadUnbanRequests => "moderator:read:unban_requests" {
+        description: "View a broadcaster’s unban requests.",
+        summary: "View unban requests in channels you moderate",
+        category: Moderation,
+        api: ["Get Unban Requests" ("get-unban-requests")],
+        eventsub: [
+            "channel.unban_request.create" ("channelunban_requestcreate"),
+            "channel.unban_request.resolve" ("channelunban_requestresolve"),
+            "channel.moderate" ("channelmoderate"),
+        ],
+    }
+    ModeratorManageUnbanRequests => "moderator:manage:unban_requests" {
+        description: "Manage a broadcaster’s unban requests.",
+        summary: "Resolve unban requests in channels you moderate",
+        category: Moderation,
+        api: ["Resolve Unban Requests" ("resolve-unban-requests")],
+        eventsub: [
+            "channel.unban_request.create" ("channelunban_requestcreate"),
+            "channel.unban_request.resolve" ("channelunban_requestresolve"),
+            "channel.moderate" ("channelmoderate"),
+        ],
+    }
+    ModeratorReadVips => "moderator:read:vips" {
+        description: "Read the list of VIPs in channels where you have the moderator role.",
+        summary: "View VIPs of channels you moderate",
+        category: Moderation,
+        eventsub: ["channel.moderate" ("channelmoderate")],
+    }
+    ModeratorReadWarnings => "moderator:read:warnings" {
+        description: "Read warnings in channels where you have the moderator role.",
+        summary: "View warnings in channels you moderate",
+        category: Moderation,
+        eventsub: [
+            "channel.moderate" ("channelmoderate"),
+            "channel.warning.acknowledge" ("channelwarningacknowledge"),
+            "channel.warning.send" ("channelwarningsend"),
+        ],
+    }
+    ModeratorManageWarnings => "moderator:manage:warnings" {
+        description: "Warn users in channels where you have the moderator role.",
+        summary: "Warn users in channels you moderate",
+        category: Moderation,
+        api: ["Warn Chat User" ("warn-chat-user")],
+        eventsub: [
+            "channel.moderate" ("channelmoderate"),
+            "channel.warning.acknowledge" ("channelwarningacknowledge"),
+            "channel.warning.send" ("channelwarningsend"),
+        ],
+    }
+    ModeratorManageSuspiciousUsers => "moderator:manage:suspicious_users" {
+        description: "Add or remove the suspicious status of users in channels where you have the moderator role.",
+        summary: "Mark users as suspicious in channels you moderate",
+        category: Moderation,
+    }
+    UserBot => "user:bot" {
+        description: "Join a specified chat channel as your user and appear as a bot, and perform chat-related actions as your user.",
+        summary: "Appear as a bot in chat",
+        category: Chat,
+        api: ["Send Chat Message" ("send-chat-message")],
+        eventsub: [
+            "channel.chat.clear" ("channelchatclear"),
+            "channel.chat.clear_user_messages" ("channelchatclear_user_messages"),
+            "channel.chat.message" ("channelchatmessage"),
+            "channel.chat.message_delete" ("channelchatmessage_delete"),
+            "channel.chat.notification" ("channelchatnotification"),
+            "channel.chat_settings.update" ("channelchat_settingsupdate"),
+            "channel.chat.user_message_hold" ("channelchatuser_message_hold"),
+            "channel.chat.user_message_update" ("channelchatuser_message_update"),
+        ],
+    }
+    UserEdit => "user:edit" {
+        description: "Manage a user object.",
+        summary: "Update your profile description",
+        category: User,
+        api: ["Update User" ("update-user")],
+    }
+    UserEditBroadcast => "user:edit:broadcast" {
+        description: "View and edit a user’s broadcasting configuration, including Extension configurations.",
+        summary: "Change your broadcast and Extension configuration",
+        category: User,
+        api: [
+            "Get User Extensions" ("get-user-extensions"),
+            "Get User Active Extensions" ("get-user-active-extensions"),
+            "Update User Extensions" ("update-user-extensions"),
+        ],
+    }
+    UserReadBlockedUsers => "user:read:blocked_users" {
+        description: "View the block list of a user.",
+        summary: "View your block list",
+        category: User,
+        api: ["Get User Block List" ("get-user-block-list")],
+    }
+    UserManageBlockedUsers => "user:manage:blocked_users" {
+        description: "Manage the block list of a user.",
+        summary: "Block and unblock users",
+        category: User,
+        api: ["Block User" ("block-user"), "Unblock User" ("unblock-user")],
+    }
+    UserReadBroadcast => "user:read:broadcast" {
+        description: "View a user’s broadcasting configuration, including Extension configurations.",
+        summary: "View your broadcast and Extension configuration",
+        category: User,
+        api: [
+            "Get Stream Markers" ("get-stream-markers"),
+            "Get User Extensions" ("get-user-extensions"),
+            "Get User Active Extensions" ("get-user-active-extensions"),
+        ],
+    }
+    UserReadChat => "user:read:chat" {
+        description: "Receive chatroom messages and informational notifications relating to a channel’s chatroom.",
+        summary: "Read chat messages",
+        category: Chat,
+        eventsub: [
+            "channel.chat.clear" ("channelchatclear"),
+            "channel.chat.clear_user_messages" ("channelchatclear_user_messages"),
+            "channel.chat.message" ("channelchatmessage"),
+            "channel.chat.message_delete" ("channelchatmessage_delete"),
+            "channel.chat.notification" ("channelchatnotification"),
+            "channel.chat_settings.update" ("channelchat_settingsupdate"),
+            "channel.chat.user_message_hold" ("channelchatuser_message_hold"),
+            "channel.chat.user_message_update" ("channelchatuser_message_update"),
+        ],
+    }
+    UserManageChatColor => "user:manage:chat_color" {
+        description: "Update the color used for the user’s name in chat.",
+        summary: "Change your name color in chat",
+        category: Chat,
+        api: ["Update User Chat Color" ("update-user-chat-color")],
+    }
+    UserReadEmail => "user:read:email" {
+        description: "View a user’s email address.",
+        summary: "View your email address",
+        category: User,
+        api: ["Get Users" ("get-users"), "Update User" ("update-user")],
+        eventsub: ["user.update" ("userupdate")],
+    }
+    UserReadEmotes => "user:read:emotes" {
+        description: "View emotes available to a user",
+        summary: "View your emotes",
+        category: User,
+        api: ["Get User Emotes" ("get-user-emotes")],
+    }
+    UserReadFollows => "user:read:follows" {
+        description: "View the list of channels a user follows.",
+        summary: "View the channels you follow",
+        category: User,
+        api: [
+            "Get Followed Channels" ("get-followed-channels"),
+            "Get Followed Streams" ("get-followed-streams"),
+        ],
+    }
+    UserReadModeratedChannels => "user:read:moderated_channels" {
+        description: "Read the list of channels you have moderator privileges in.",
+        summary: "View the channels you moderate",
+        category: User,
+        api: ["Get Moderated Channels" ("get-moderated-channels")],
+    }
+    UserReadSubscriptions => "user:read:subscriptions" {
+        description: "View if an authorized user is subscribed to specific channels.",
+        summary: "View the channels you subscribe to",
+        category: User,
+        api: ["Check User Subscription" ("check-user-subscription")],
+    }
+    UserReadWhispers => "user:read:whispers" {
+        description: "Receive whispers sent to your user.",
+        summary: "Read your whispers",
+        category: Chat,
+        eventsub: ["user.whisper.message" ("userwhispermessage")],
+    }
+    UserManageWhispers => "user:manage:whispers" {
+        description: "Receive whispers sent to your user, and send whispers on your user’s behalf.",
+        summary: "Read and send whispers",
+        category: Chat,
+        api: ["Send Whisper" ("send-whisper")],
+        eventsub: ["user.whisper.message" ("userwhispermessage")],
+    }
+    UserWriteChat => "user:write:chat" {
+        description: "Send chat messages to a chatroom.",
+        summary: "Send chat messages",
+        category: Chat,
+        api: ["Send Chat Message" ("send-chat-message")],
+    }
+    ChatEdit => "chat:edit" {
+        description: "Send chat messages to a chatroom using an IRC connection.",
+        summary: "Send chat messages",
+        category: Chat,
+        deprecated: "IRC is superseded by the Send Chat Message endpoint, use `user:write:chat`",
+        docs: "<https://dev.twitch.tv/docs/chat/irc>",
+    }
+    ChatRead => "chat:read" {
+        description: "View chat messages sent in a chatroom using an IRC connection.",
+        summary: "Read chat messages",
+        category: Chat,
+        deprecated: "IRC is superseded by EventSub chat, use `user:read:chat` with the `channel.chat.message` subscription",
+        docs: "<https://dev.twitch.tv/docs/chat/irc>",
+    }
+    WhispersRead => "whispers:read" {
+        description: "Receive whisper messages for your user using PubSub.",
+        summary: "Read your whispers",
+        category: Chat,
+        deprecated: "PubSub has been decommissioned, use `user:read:whispers` with the `user.whisper.message` EventSub subscription",
+        docs: "<https://dev.twitch.tv/docs/pubsub>",
+    }
 }
 
 impl Scope {
-    pub const fn as_str(&self) -> &'static str {
-        match self {
-            Self::AnalyticsReadExtensions => "analytics:read:extensions",
-            Self::AnalyticsReadGames => "analytics:read:games",
-            Self::BitsRead => "bits:read",
-            Self::ChannelBot => "channel:bot",
-            Self::ChannelManageAds => "channel:manage:ads",
-            Self::ChannelReadAds => "channel:read:ads",
-            Self::ChannelManageBroadcast => "channel:manage:broadcast",
-            Self::ChannelReadCharity => "channel:read:charity",
-            Self::ChannelEditCommercial => "channel:edit:commercial",
-            Self::ChannelReadEditors => "channel:read:editors",
-            Self::ChannelManageExtensions => "channel:manage:extensions",
-            Self::ChannelReadGoals => "channel:read:goals",
-            Self::ChannelReadGuestStar => "channel:read:guest_star",
-            Self::ChannelManageGuestStar => "channel:manage:guest_star",
-            Self::ChannelReadHypeTrain => "channel:read:hype_train",
-            Self::ChannelManageModerators => "channel:manage:moderators",
-            Self::ChannelModerate => "channel:moderate",
-            Self::ChannelReadPolls => "channel:read:polls",
-            Self::ChannelManagePolls => "channel:manage:polls",
-            Self::ChannelReadPredictions => "channel:read:predictions",
-            Self::ChannelManagePredictions => "channel:manage:predictions",
-            Self::ChannelManageRaids => "channel:manage:raids",
-            Self::ChannelReadRedemptions => "channel:read:redemptions",
-            Self::ChannelManageRedemptions => "channel:manage:redemptions",
-            Self::ChannelManageSchedule => "channel:manage:schedule",
-            Self::ChannelReadStreamKey => "channel:read:stream_key",
-            Self::ChannelReadSubscriptions => "channel:read:subscriptions",
-            Self::ChannelManageVideos => "channel:manage:videos",
-            Self::ChannelReadVips => "channel:read:vips",
-            Self::ChannelManageVips => "channel:manage:vips",
-            Self::ClipsEdit => "clips:edit",
-            Self::ChannelManageClips => "channel:manage:clips",
-            Self::EditorManageClips => "editor:manage:clips",
-            Self::ModerationRead => "moderation:read",
-            Self::ModeratorManageAnnouncements => "moderator:manage:announcements",
-            Self::ModeratorManageAutomod => "moderator:manage:automod",
-            Self::ModeratorReadAutomodSettings => "moderator:read:automod_settings",
-            Self::ModeratorManageAutomodSettings => "moderator:manage:automod_settings",
-            Self::ModeratorReadBannedUsers => "moderator:read:banned_users",
-            Self::ModeratorManageBannedUsers => "moderator:manage:banned_users",
-            Self::ModeratorReadBlockedTerms => "moderator:read:blocked_terms",
-            Self::ModeratorReadChatMessages => "moderator:read:chat_messages",
-            Self::ModeratorManageBlockedTerms => "moderator:manage:blocked_terms",
-            Self::ModeratorManageChatMessages => "moderator:manage:chat_messages",
-            Self::ModeratorReadChatSettings => "moderator:read:chat_settings",
-            Self::ModeratorManageChatSettings => "moderator:manage:chat_settings",
-            Self::ModeratorReadChatters => "moderator:read:chatters",
-            Self::ModeratorReadFollowers => "moderator:read:followers",
-            Self::ModeratorReadGuestStar => "moderator:read:guest_star",
-            Self::ModeratorManageGuestStar => "moderator:manage:guest_star",
-            Self::ModeratorReadModerators => "moderator:read:moderators",
-            Self::ModeratorReadShieldMode => "moderator:read:shield_mode",
-            Self::ModeratorManageShieldMode => "moderator:manage:shield_mode",
-            Self::ModeratorReadShoutouts => "moderator:read:shoutouts",
-            Self::ModeratorManageShoutouts => "moderator:manage:shoutouts",
-            Self::ModeratorReadSuspiciousUsers => "moderator:read:suspicious_users",
-            Self::ModeratorReadUnbanRequests => "moderator:read:unban_requests",
-            Self::ModeratorManageUnbanRequests => "moderator:manage:unban_requests",
-            Self::ModeratorReadVips => "moderator:read:vips",
-            Self::ModeratorReadWarnings => "moderator:read:warnings",
-            Self::ModeratorManageWarnings => "moderator:manage:warnings",
-            Self::ModeratorManageSuspiciousUsers => "moderator:manage:suspicious_users",
-            Self::UserBot => "user:bot",
-            Self::UserEdit => "user:edit",
-            Self::UserEditBroadcast => "user:edit:broadcast",
-            Self::UserReadBlockedUsers => "user:read:blocked_users",
-            Self::UserManageBlockedUsers => "user:manage:blocked_users",
-            Self::UserReadBroadcast => "user:read:broadcast",
-            Self::UserReadChat => "user:read:chat",
-            Self::UserManageChatColor => "user:manage:chat_color",
-            Self::UserReadEmail => "user:read:email",
-            Self::UserReadEmotes => "user:read:emotes",
-            Self::UserReadFollows => "user:read:follows",
-            Self::UserReadModeratedChannels => "user:read:moderated_channels",
-            Self::UserReadSubscriptions => "user:read:subscriptions",
-            Self::UserReadWhispers => "user:read:whispers",
-            Self::UserManageWhispers => "user:manage:whispers",
-            Self::UserWriteChat => "user:write:chat",
-
-            Self::WhispersRead => "whispers:read",
-
-            Self::ChatEdit => "chat:edit",
-            Self::ChatRead => "chat:read",
-        }
+    /// Position of this scope in [`ScopeSet`]'s bitset
+    pub(crate) const fn bit(self) -> u32 {
+        self as u32
     }
 }
 
@@ -790,97 +807,6 @@ impl Display for InvalidScopeError {
 
 impl std::error::Error for InvalidScopeError {}
 
-impl FromStr for Scope {
-    type Err = InvalidScopeError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "analytics:read:extensions" => Ok(Self::AnalyticsReadExtensions),
-            "analytics:read:games" => Ok(Self::AnalyticsReadGames),
-            "bits:read" => Ok(Self::BitsRead),
-            "channel:bot" => Ok(Self::ChannelBot),
-            "channel:manage:ads" => Ok(Self::ChannelManageAds),
-            "channel:read:ads" => Ok(Self::ChannelReadAds),
-            "channel:manage:broadcast" => Ok(Self::ChannelManageBroadcast),
-            "channel:read:charity" => Ok(Self::ChannelReadCharity),
-            "channel:edit:commercial" => Ok(Self::ChannelEditCommercial),
-            "channel:read:editors" => Ok(Self::ChannelReadEditors),
-            "channel:manage:extensions" => Ok(Self::ChannelManageExtensions),
-            "channel:read:goals" => Ok(Self::ChannelReadGoals),
-            "channel:read:guest_star" => Ok(Self::ChannelReadGuestStar),
-            "channel:manage:guest_star" => Ok(Self::ChannelManageGuestStar),
-            "channel:read:hype_train" => Ok(Self::ChannelReadHypeTrain),
-            "channel:manage:moderators" => Ok(Self::ChannelManageModerators),
-            "channel:moderate" => Ok(Self::ChannelModerate),
-            "channel:read:polls" => Ok(Self::ChannelReadPolls),
-            "channel:manage:polls" => Ok(Self::ChannelManagePolls),
-            "channel:read:predictions" => Ok(Self::ChannelReadPredictions),
-            "channel:manage:predictions" => Ok(Self::ChannelManagePredictions),
-            "channel:manage:raids" => Ok(Self::ChannelManageRaids),
-            "channel:read:redemptions" => Ok(Self::ChannelReadRedemptions),
-            "channel:manage:redemptions" => Ok(Self::ChannelManageRedemptions),
-            "channel:manage:schedule" => Ok(Self::ChannelManageSchedule),
-            "channel:read:stream_key" => Ok(Self::ChannelReadStreamKey),
-            "channel:read:subscriptions" => Ok(Self::ChannelReadSubscriptions),
-            "channel:manage:videos" => Ok(Self::ChannelManageVideos),
-            "channel:read:vips" => Ok(Self::ChannelReadVips),
-            "channel:manage:vips" => Ok(Self::ChannelManageVips),
-            "clips:edit" => Ok(Self::ClipsEdit),
-            "channel:manage:clips" => Ok(Self::ChannelManageClips),
-            "editor:manage:clips" => Ok(Self::EditorManageClips),
-            "moderation:read" => Ok(Self::ModerationRead),
-            "moderator:manage:announcements" => Ok(Self::ModeratorManageAnnouncements),
-            "moderator:manage:automod" => Ok(Self::ModeratorManageAutomod),
-            "moderator:read:automod_settings" => Ok(Self::ModeratorReadAutomodSettings),
-            "moderator:manage:automod_settings" => Ok(Self::ModeratorManageAutomodSettings),
-            "moderator:read:banned_users" => Ok(Self::ModeratorReadBannedUsers),
-            "moderator:manage:banned_users" => Ok(Self::ModeratorManageBannedUsers),
-            "moderator:read:blocked_terms" => Ok(Self::ModeratorReadBlockedTerms),
-            "moderator:read:chat_messages" => Ok(Self::ModeratorReadChatMessages),
-            "moderator:manage:blocked_terms" => Ok(Self::ModeratorManageBlockedTerms),
-            "moderator:manage:chat_messages" => Ok(Self::ModeratorManageChatMessages),
-            "moderator:read:chat_settings" => Ok(Self::ModeratorReadChatSettings),
-            "moderator:manage:chat_settings" => Ok(Self::ModeratorManageChatSettings),
-            "moderator:read:chatters" => Ok(Self::ModeratorReadChatters),
-            "moderator:read:followers" => Ok(Self::ModeratorReadFollowers),
-            "moderator:read:guest_star" => Ok(Self::ModeratorReadGuestStar),
-            "moderator:manage:guest_star" => Ok(Self::ModeratorManageGuestStar),
-            "moderator:read:moderators" => Ok(Self::ModeratorReadModerators),
-            "moderator:read:shield_mode" => Ok(Self::ModeratorReadShieldMode),
-            "moderator:manage:shield_mode" => Ok(Self::ModeratorManageShieldMode),
-            "moderator:read:shoutouts" => Ok(Self::ModeratorReadShoutouts),
-            "moderator:manage:shoutouts" => Ok(Self::ModeratorManageShoutouts),
-            "moderator:read:suspicious_users" => Ok(Self::ModeratorReadSuspiciousUsers),
-            "moderator:read:unban_requests" => Ok(Self::ModeratorReadUnbanRequests),
-            "moderator:manage:unban_requests" => Ok(Self::ModeratorManageUnbanRequests),
-            "moderator:read:vips" => Ok(Self::ModeratorReadVips),
-            "moderator:read:warnings" => Ok(Self::ModeratorReadWarnings),
-            "moderator:manage:warnings" => Ok(Self::ModeratorManageWarnings),
-            "moderator:manage:suspicious_users" => Ok(Self::ModeratorManageSuspiciousUsers),
-            "user:bot" => Ok(Self::UserBot),
-            "user:edit" => Ok(Self::UserEdit),
-            "user:edit:broadcast" => Ok(Self::UserEditBroadcast),
-            "user:read:blocked_users" => Ok(Self::UserReadBlockedUsers),
-            "user:manage:blocked_users" => Ok(Self::UserManageBlockedUsers),
-            "user:read:broadcast" => Ok(Self::UserReadBroadcast),
-            "user:read:chat" => Ok(Self::UserReadChat),
-            "user:manage:chat_color" => Ok(Self::UserManageChatColor),
-            "user:read:email" => Ok(Self::UserReadEmail),
-            "user:read:emotes" => Ok(Self::UserReadEmotes),
-            "user:read:follows" => Ok(Self::UserReadFollows),
-            "user:read:moderated_channels" => Ok(Self::UserReadModeratedChannels),
-            "user:read:subscriptions" => Ok(Self::UserReadSubscriptions),
-            "user:read:whispers" => Ok(Self::UserReadWhispers),
-            "user:manage:whispers" => Ok(Self::UserManageWhispers),
-            "user:write:chat" => Ok(Self::UserWriteChat),
-            "whispers:read" => Ok(Self::WhispersRead),
-            "chat:edit" => Ok(Self::ChatEdit),
-            "chat:read" => Ok(Self::ChatRead),
-            _ => Err(InvalidScopeError(s.to_string())),
-        }
-    }
-}
-
 impl AsRef<str> for Scope {
     fn as_ref(&self) -> &str {
         self.as_str()
@@ -914,3 +840,37 @@ impl<'de> Deserialize<'de> for Scope {
             .map_err(|e: InvalidScopeError| D::Error::custom(e))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Scope;
+
+    #[test]
+    fn catalog() {
+        for scope in Scope::ALL {
+            assert_eq!(Scope::from_str(scope.as_str()).unwrap(), *scope);
+            assert!(!scope.description().is_empty());
+            for ty in scope.eventsub_types() {
+                assert!(ty.contains('.'), "{scope}: {ty}");
+            }
+        }
+
+        assert_eq!(Scope::BitsRead.api_endpoints(), ["Get Bits Leaderboard"]);
+        assert_eq!(
+            Scope::BitsRead.eventsub_types(),
+            ["channel.bits.use", "channel.cheer"]
+        );
+        assert!(Scope::ChatRead.eventsub_types().is_empty());
+
+        let deprecated = Scope::ALL
+            .iter()
+            .filter(|scope| scope.is_deprecated())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            deprecated,
+            [&Scope::ChatEdit, &Scope::ChatRead, &Scope::WhispersRead]
+        );
+    }
+}
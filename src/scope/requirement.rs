@@ -0,0 +1,283 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{TokenInfo, UserToken};
+
+use super::{Scope, ScopeSet, Scopes};
+
+/// The kind of access token an endpoint accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenType {
+    /// App access token (client credentials grant flow)
+    App,
+    /// User access token
+    User,
+}
+
+impl TokenType {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::App => "app",
+            Self::User => "user",
+        }
+    }
+}
+
+impl Display for TokenType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Scopes a token must carry
+///
+/// For [`Requirement::AnyOf`], the first alternative is the one requested by the
+/// [`ScopesMut`](super::ScopesMut) helpers. An empty `AnyOf` or `AllOf` is
+/// treated like [`Requirement::None`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Requirement {
+    /// No scope needed
+    None,
+    Scope(Scope),
+    AllOf(&'static [Requirement]),
+    AnyOf(&'static [Requirement]),
+}
+
+impl Requirement {
+    pub fn is_satisfied_by(&self, scopes: &ScopeSet) -> bool {
+        match self {
+            Self::None => true,
+            Self::Scope(scope) => scopes.contains(*scope),
+            Self::AllOf(requirements) => requirements.iter().all(|r| r.is_satisfied_by(scopes)),
+            Self::AnyOf(requirements) => {
+                requirements.is_empty() || requirements.iter().any(|r| r.is_satisfied_by(scopes))
+            }
+        }
+    }
+
+    /// Smallest set of scopes that would satisfy the requirement, empty if it already is
+    ///
+    /// For [`Requirement::AnyOf`], the alternative missing the fewest scopes is
+    /// used, preferring earlier ones.
+    pub fn missing(&self, scopes: &ScopeSet) -> ScopeSet {
+        match self {
+            Self::None => ScopeSet::new(),
+            Self::Scope(scope) if scopes.contains(*scope) => ScopeSet::new(),
+            Self::Scope(scope) => ScopeSet::from(*scope),
+            Self::AllOf(requirements) => requirements
+                .iter()
+                .fold(ScopeSet::new(), |missing, r| missing | r.missing(scopes)),
+            Self::AnyOf(requirements) => requirements
+                .iter()
+                .map(|r| r.missing(scopes))
+                .min_by_key(|missing| missing.len())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Check the scopes granted to a token
+    ///
+    /// ```
+    /// use twitch_oauth_token::{
+    ///     scope::{Requirement, ScopeSet},
+    ///     Scope,
+    /// };
+    ///
+    /// const REQUIREMENT: Requirement = Requirement::AllOf(&[
+    ///     Requirement::Scope(Scope::UserReadChat),
+    ///     Requirement::AnyOf(&[
+    ///         Requirement::Scope(Scope::ModeratorReadFollowers),
+    ///         Requirement::Scope(Scope::ModerationRead),
+    ///     ]),
+    /// ]);
+    ///
+    /// let granted = ScopeSet::from(Scope::UserReadChat);
+    /// let err = REQUIREMENT.satisfied_by(&granted).unwrap_err();
+    /// assert_eq!(err.missing(), ScopeSet::from(Scope::ModeratorReadFollowers));
+    /// ```
+    pub fn satisfied_by<T>(&self, token: &T) -> Result<(), MissingScopes>
+    where
+        T: GrantedScopes + ?Sized,
+    {
+        let missing = self.missing(&token.granted_scopes());
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(MissingScopes {
+                requirement: *self,
+                missing,
+            })
+        }
+    }
+}
+
+/// `chat:read`, `chat:read and chat:edit`, `(a or b) and c`
+impl Display for Requirement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fn join(f: &mut Formatter<'_>, requirements: &[Requirement], sep: &str) -> FmtResult {
+            for (i, requirement) in requirements.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(sep)?;
+                }
+                match requirement {
+                    Requirement::AllOf(r) | Requirement::AnyOf(r) if r.len() > 1 => {
+                        write!(f, "({requirement})")?
+                    }
+                    _ => write!(f, "{requirement}")?,
+                }
+            }
+            Ok(())
+        }
+
+        match self {
+            Self::None => f.write_str("no scope"),
+            Self::Scope(scope) => f.write_str(scope.as_str()),
+            Self::AllOf(requirements) => join(f, requirements, " and "),
+            Self::AnyOf(requirements) => join(f, requirements, " or "),
+        }
+    }
+}
+
+/// Anything that carries the scopes granted to a token
+pub trait GrantedScopes {
+    fn granted_scopes(&self) -> ScopeSet;
+}
+
+impl GrantedScopes for ScopeSet {
+    fn granted_scopes(&self) -> ScopeSet {
+        *self
+    }
+}
+
+impl GrantedScopes for Scopes {
+    fn granted_scopes(&self) -> ScopeSet {
+        self.known()
+    }
+}
+
+impl GrantedScopes for UserToken {
+    fn granted_scopes(&self) -> ScopeSet {
+        self.scope.known()
+    }
+}
+
+impl GrantedScopes for TokenInfo {
+    fn granted_scopes(&self) -> ScopeSet {
+        self.scopes.known()
+    }
+}
+
+/// Returned by [`Requirement::satisfied_by`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingScopes {
+    requirement: Requirement,
+    missing: ScopeSet,
+}
+
+impl MissingScopes {
+    pub fn requirement(&self) -> Requirement {
+        self.requirement
+    }
+
+    /// Scopes to request so the requirement is met
+    pub fn missing(&self) -> ScopeSet {
+        self.missing
+    }
+}
+
+impl Display for MissingScopes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "missing scopes: {} (requires {})",
+            self.missing, self.requirement
+        )
+    }
+}
+
+impl std::error::Error for MissingScopes {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scope::{scopes_mut, HelixEndpoint, ScopeSet, Scopes},
+        Scope,
+    };
+
+    use super::{GrantedScopes, Requirement};
+
+    const NESTED: Requirement = Requirement::AllOf(&[
+        Requirement::Scope(Scope::UserReadChat),
+        Requirement::AnyOf(&[
+            Requirement::AllOf(&[
+                Requirement::Scope(Scope::UserBot),
+                Requirement::Scope(Scope::ChannelBot),
+            ]),
+            Requirement::Scope(Scope::ChannelModerate),
+        ]),
+    ]);
+
+    #[test]
+    fn missing() {
+        assert_eq!(
+            NESTED.missing(&ScopeSet::new()),
+            ScopeSet::from([Scope::UserReadChat, Scope::ChannelModerate])
+        );
+        assert_eq!(
+            NESTED.missing(&ScopeSet::from(Scope::UserBot)),
+            ScopeSet::from([Scope::UserReadChat, Scope::ChannelBot])
+        );
+        assert!(NESTED
+            .missing(&ScopeSet::from([
+                Scope::UserReadChat,
+                Scope::ChannelModerate
+            ]))
+            .is_empty());
+        assert!(Requirement::None.missing(&ScopeSet::new()).is_empty());
+
+        let empty = Requirement::AnyOf(&[]);
+        assert!(empty.is_satisfied_by(&ScopeSet::new()));
+        assert!(empty.missing(&ScopeSet::new()).is_empty());
+        assert!(empty.satisfied_by(&ScopeSet::new()).is_ok());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            NESTED.to_string(),
+            "user:read:chat and ((user:bot and channel:bot) or channel:moderate)"
+        );
+
+        let err = NESTED.satisfied_by(&ScopeSet::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "missing scopes: channel:moderate user:read:chat (requires user:read:chat and ((user:bot and channel:bot) or channel:moderate))"
+        );
+    }
+
+    #[test]
+    fn scopes_mut_requests_what_is_checked() {
+        let mut scopes = Scopes::new();
+        scopes_mut(&mut scopes).require(NESTED);
+        assert_eq!(
+            scopes.known(),
+            ScopeSet::from([Scope::UserReadChat, Scope::UserBot, Scope::ChannelBot])
+        );
+        assert!(NESTED.satisfied_by(&scopes).is_ok());
+
+        let mut scopes = Scopes::from(Scope::ChannelManageRedemptions);
+        scopes_mut(&mut scopes).endpoint(HelixEndpoint::GetCustomReward);
+        assert_eq!(
+            scopes.granted_scopes(),
+            ScopeSet::from(Scope::ChannelManageRedemptions)
+        );
+
+        for endpoint in HelixEndpoint::ALL {
+            let mut scopes = Scopes::new();
+            scopes_mut(&mut scopes).endpoint(*endpoint);
+            assert!(endpoint.requirement().satisfied_by(&scopes).is_ok());
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::{
+    borrow::Cow,
+    collections::BTreeSet,
+    convert::Infallible,
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    str::FromStr,
+};
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{ConsentSection, Scope, ScopeCategory, ScopeSet, Translations};
+
+/// Scopes granted to or requested for a token, including ones this crate doesn't know
+///
+/// Twitch adds scopes over time. Scope strings that don't parse as a [`Scope`] are
+/// kept verbatim instead of being dropped, so they survive deserialization,
+/// serialization and authorization URLs.
+///
+/// Set operations take unknown scopes into account; use [`Scopes::known`] for the
+/// [`ScopeSet`] of known scopes only.
+///
+/// ```
+/// use twitch_oauth_token::{scope::Scopes, Scope};
+///
+/// let scopes: Scopes = "chat:read channel:manage:future_feature".parse().unwrap();
+///
+/// assert!(scopes.contains(Scope::ChatRead));
+/// assert!(scopes.contains_str("channel:manage:future_feature"));
+/// assert!(!scopes.is_subset(&Scopes::from(Scope::ChatRead)));
+/// assert_eq!(scopes.len(), 2);
+/// assert_eq!(scopes.to_string(), "channel:manage:future_feature chat:read");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Scopes {
+    known: ScopeSet,
+    unknown: BTreeSet<String>,
+}
+
+impl Scopes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn known(&self) -> ScopeSet {
+        self.known
+    }
+
+    /// Scope strings that don't match a [`Scope`], sorted
+    pub fn unknown(&self) -> impl Iterator<Item = &str> {
+        self.unknown.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.known.len() + self.unknown.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.known.is_empty() && self.unknown.is_empty()
+    }
+
+    pub fn contains(&self, scope: Scope) -> bool {
+        self.known.contains(scope)
+    }
+
+    pub fn contains_str(&self, scope: &str) -> bool {
+        match Scope::from_str(scope) {
+            Ok(scope) => self.known.contains(scope),
+            Err(_) => self.unknown.contains(scope),
+        }
+    }
+
+    /// Returns whether the scope was newly inserted
+    pub fn insert(&mut self, scope: Scope) -> bool {
+        self.known.insert(scope)
+    }
+
+    /// Insert a scope string, keeping it verbatim if it isn't a known [`Scope`]
+    ///
+    /// Empty strings are ignored.
+    pub fn insert_str(&mut self, scope: &str) -> bool {
+        if scope.is_empty() {
+            return false;
+        }
+        match Scope::from_str(scope) {
+            Ok(scope) => self.known.insert(scope),
+            Err(_) => self.unknown.insert(scope.to_string()),
+        }
+    }
+
+    pub fn remove(&mut self, scope: Scope) -> bool {
+        self.known.remove(scope)
+    }
+
+    pub fn remove_str(&mut self, scope: &str) -> bool {
+        match Scope::from_str(scope) {
+            Ok(scope) => self.known.remove(scope),
+            Err(_) => self.unknown.remove(scope),
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.known.clear();
+        self.unknown.clear();
+    }
+
+    /// All scope strings, sorted
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.sorted().into_iter()
+    }
+
+    pub fn is_subset(&self, other: &Scopes) -> bool {
+        self.known.is_subset(other.known) && self.unknown.is_subset(&other.unknown)
+    }
+
+    pub fn is_superset(&self, other: &Scopes) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Scopes) -> bool {
+        self.known.is_disjoint(other.known) && self.unknown.is_disjoint(&other.unknown)
+    }
+
+    /// [`ScopeSet::consent_summary`], with unknown scopes listed under
+    /// [`ScopeCategory::Other`]
+    ///
+    /// Unknown scopes are described by [`Translations::unknown_summary`], which
+    /// defaults to the scope string itself.
+    pub fn consent_summary<'a, T>(&'a self, translations: &'a T) -> Vec<ConsentSection<'a>>
+    where
+        T: Translations + ?Sized,
+    {
+        let mut sections = self.known.consent_summary(translations);
+        if !self.unknown.is_empty() {
+            sections.push(ConsentSection {
+                category: ScopeCategory::Other,
+                title: translations.category_title(ScopeCategory::Other),
+                items: self
+                    .unknown()
+                    .map(|scope| translations.unknown_summary(scope))
+                    .collect(),
+            });
+        }
+        sections
+    }
+
+    /// All scope strings, sorted
+    fn sorted(&self) -> Vec<&str> {
+        let mut scopes = self
+            .known
+            .iter()
+            .map(|scope| scope.as_str())
+            .chain(self.unknown())
+            .collect::<Vec<_>>();
+        scopes.sort_unstable();
+        scopes
+    }
+}
+
+/// A [`Scope`] or a scope string [`Scope`] doesn't cover yet
+///
+/// Accepted by [`ScopesMut::push`](super::ScopesMut::push).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScopeName<'a> {
+    Known(Scope),
+    Other(Cow<'a, str>),
+}
+
+impl ScopeName<'_> {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Known(scope) => scope.as_str(),
+            Self::Other(scope) => scope,
+        }
+    }
+}
+
+impl From<Scope> for ScopeName<'_> {
+    fn from(scope: Scope) -> Self {
+        Self::Known(scope)
+    }
+}
+
+impl<'a> From<&'a str> for ScopeName<'a> {
+    fn from(scope: &'a str) -> Self {
+        Self::Other(Cow::Borrowed(scope))
+    }
+}
+
+impl From<String> for ScopeName<'_> {
+    fn from(scope: String) -> Self {
+        Self::Other(Cow::Owned(scope))
+    }
+}
+
+impl From<ScopeSet> for Scopes {
+    fn from(known: ScopeSet) -> Self {
+        Self {
+            known,
+            unknown: BTreeSet::new(),
+        }
+    }
+}
+
+impl From<Scope> for Scopes {
+    fn from(scope: Scope) -> Self {
+        ScopeSet::from(scope).into()
+    }
+}
+
+impl<const N: usize> From<[Scope; N]> for Scopes {
+    fn from(scopes: [Scope; N]) -> Self {
+        ScopeSet::from(scopes).into()
+    }
+}
+
+impl FromIterator<Scope> for Scopes {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        ScopeSet::from_iter(iter).into()
+    }
+}
+
+impl Extend<Scope> for Scopes {
+    fn extend<I: IntoIterator<Item = Scope>>(&mut self, iter: I) {
+        self.known.extend(iter);
+    }
+}
+
+impl Extend<ScopeSet> for Scopes {
+    fn extend<I: IntoIterator<Item = ScopeSet>>(&mut self, iter: I) {
+        for set in iter {
+            self.known |= set;
+        }
+    }
+}
+
+impl<'a> Extend<&'a Scopes> for Scopes {
+    fn extend<I: IntoIterator<Item = &'a Scopes>>(&mut self, iter: I) {
+        for scopes in iter {
+            self.known |= scopes.known;
+            self.unknown.extend(scopes.unknown.iter().cloned());
+        }
+    }
+}
+
+impl Debug for Scopes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.sorted()).finish()
+    }
+}
+
+/// Space-separated, sorted lexicographically
+impl Display for Scopes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.sorted().join(" "))
+    }
+}
+
+/// Parses space-separated scopes, keeping unknown ones
+impl FromStr for Scopes {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut scopes = Self::new();
+        for scope in s.split_whitespace() {
+            scopes.insert_str(scope);
+        }
+        Ok(scopes)
+    }
+}
+
+impl Serialize for Scopes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let scopes = self.sorted();
+        let mut seq = serializer.serialize_seq(Some(scopes.len()))?;
+        for scope in scopes {
+            seq.serialize_element(scope)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for Scopes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScopesVisitor;
+
+        impl<'de> Visitor<'de> for ScopesVisitor {
+            type Value = Scopes;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("a sequence of scopes or a space-separated scope string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Scopes, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(v.parse().unwrap_or_default())
+            }
+
+            fn visit_unit<E>(self) -> Result<Scopes, E>
+            where
+                E: serde::de::Error,
+            {
+                Ok(Scopes::new())
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Scopes, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut scopes = Scopes::new();
+                while let Some(scope) = seq.next_element::<String>()? {
+                    scopes.insert_str(&scope);
+                }
+                Ok(scopes)
+            }
+        }
+
+        deserializer.deserialize_any(ScopesVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        scope::{English, ScopeCategory},
+        Scope, ScopeSet,
+    };
+
+    use super::Scopes;
+
+    #[test]
+    fn keeps_unknown_scopes() {
+        let mut scopes = Scopes::from(Scope::ChatRead);
+        assert!(scopes.insert_str("channel:manage:future_feature"));
+        assert!(!scopes.insert_str("chat:read"));
+        assert!(!scopes.insert_str(""));
+
+        assert_eq!(scopes.len(), 2);
+        assert_eq!(scopes.known(), ScopeSet::from(Scope::ChatRead));
+        assert_eq!(
+            scopes.unknown().collect::<Vec<_>>(),
+            ["channel:manage:future_feature"]
+        );
+
+        assert!(scopes.remove_str("channel:manage:future_feature"));
+        assert_eq!(scopes, Scopes::from(Scope::ChatRead));
+    }
+
+    #[test]
+    fn set_operations_include_unknown_scopes() {
+        let granted: Scopes = "chat:read channel:manage:future_feature".parse().unwrap();
+        let known = Scopes::from(Scope::ChatRead);
+
+        assert!(known.is_subset(&granted));
+        assert!(!granted.is_subset(&known));
+        assert!(granted.is_superset(&known));
+        assert!(!granted.is_disjoint(&"channel:manage:future_feature".parse().unwrap()));
+        assert_eq!(
+            granted.iter().collect::<Vec<_>>(),
+            ["channel:manage:future_feature", "chat:read"]
+        );
+
+        let sections = granted.consent_summary(&English);
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[1].category, ScopeCategory::Other);
+        assert_eq!(sections[1].items, ["channel:manage:future_feature"]);
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let json = serde_json::json!(["user:read:email", "", "some:new:scope"]);
+        let scopes: Scopes = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&scopes).unwrap(),
+            serde_json::json!(["some:new:scope", "user:read:email"])
+        );
+        assert_eq!(
+            serde_json::from_str::<Scopes>("\"some:new:scope user:read:email\"").unwrap(),
+            scopes
+        );
+    }
+}
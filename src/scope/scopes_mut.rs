@@ -1,6 +1,4 @@
-use std::collections::HashSet;
-
-use super::Scope;
+use super::{EventSubType, HelixEndpoint, Requirement, Scope, ScopeName, Scopes};
 
 /// A builder for adding OAuth scopes, inspired by
 /// [`PathSegmentsMut`](https://docs.rs/url/latest/src/url/path_segments.rs.html#37-42).
@@ -10,10 +8,10 @@ use super::Scope;
 /// - `on_*` - EventSub subscription helpers
 #[derive(Debug)]
 pub struct ScopesMut<'a> {
-    scopes: &'a mut HashSet<Scope>,
+    scopes: &'a mut Scopes,
 }
 
-pub fn new(scopes: &mut HashSet<Scope>) -> ScopesMut<'_> {
+pub fn new(scopes: &mut Scopes) -> ScopesMut<'_> {
     ScopesMut { scopes }
 }
 
@@ -23,8 +21,12 @@ impl ScopesMut<'_> {
         self
     }
 
-    pub fn push(&mut self, s: Scope) -> &mut Self {
-        self.scopes.insert(s);
+    /// Add a [`Scope`], or a scope string including ones [`Scope`] doesn't cover yet
+    pub fn push<'a>(&mut self, scope: impl Into<ScopeName<'a>>) -> &mut Self {
+        match scope.into() {
+            ScopeName::Known(scope) => self.scopes.insert(scope),
+            ScopeName::Other(scope) => self.scopes.insert_str(&scope),
+        };
         self
     }
 
@@ -35,6 +37,47 @@ impl ScopesMut<'_> {
         self.scopes.extend(scopes);
         self
     }
+
+    pub fn append(&mut self, scopes: &Scopes) -> &mut Self {
+        self.scopes.extend([scopes]);
+        self
+    }
+
+    /// Add the scopes needed to satisfy `requirement`
+    ///
+    /// For [`Requirement::AnyOf`], nothing is added if one of the alternatives is
+    /// already present, otherwise the first alternative is used.
+    pub fn require(&mut self, requirement: Requirement) -> &mut Self {
+        match requirement {
+            Requirement::None => {}
+            Requirement::Scope(scope) => {
+                self.scopes.insert(scope);
+            }
+            Requirement::AllOf(requirements) => {
+                for requirement in requirements {
+                    self.require(*requirement);
+                }
+            }
+            Requirement::AnyOf(requirements) => {
+                if !requirement.is_satisfied_by(&self.scopes.known()) {
+                    if let Some(first) = requirements.first() {
+                        self.require(*first);
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Add the scopes needed to call `endpoint`
+    pub fn endpoint(&mut self, endpoint: HelixEndpoint) -> &mut Self {
+        self.require(endpoint.requirement())
+    }
+
+    /// Add the scopes needed to subscribe to `subscription`
+    pub fn eventsub(&mut self, subscription: EventSubType) -> &mut Self {
+        self.require(subscription.requirement())
+    }
 }
 
 // Ads
@@ -46,20 +89,17 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#start-commercial>
     pub fn start_commercial(&mut self) -> &mut Self {
-        self.push(Scope::ChannelEditCommercial);
-        self
+        self.endpoint(HelixEndpoint::StartCommercial)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-ad-schedule>
     pub fn get_ad_schedule(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadAds);
-        self
+        self.endpoint(HelixEndpoint::GetAdSchedule)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#snooze-next-ad>
     pub fn snooze_next_ad(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageAds);
-        self
+        self.endpoint(HelixEndpoint::SnoozeNextAd)
     }
 }
 
@@ -71,14 +111,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-extension-analytics>
     pub fn get_extension_analytics(&mut self) -> &mut Self {
-        self.push(Scope::AnalyticsReadExtensions);
-        self
+        self.endpoint(HelixEndpoint::GetExtensionAnalytics)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-game-analytics>
     pub fn get_game_analytics(&mut self) -> &mut Self {
-        self.push(Scope::AnalyticsReadGames);
-        self
+        self.endpoint(HelixEndpoint::GetGameAnalytics)
     }
 }
 
@@ -92,18 +130,17 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-bits-leaderboard>
     pub fn get_bits_leaderboard(&mut self) -> &mut Self {
-        self.push(Scope::BitsRead);
-        self
+        self.endpoint(HelixEndpoint::GetBitsLeaderboard)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-cheermotes>
     pub fn get_cheermotes(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetCheermotes)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-extension-transactions>
     pub fn get_extension_transactions(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetExtensionTransactions)
     }
 }
 
@@ -119,31 +156,27 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-information>
     pub fn get_channel_info(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelInformation)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#modify-channel-information>
     pub fn modify_channel_info(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageBroadcast);
-        self
+        self.endpoint(HelixEndpoint::ModifyChannelInformation)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-editors>
     pub fn get_channel_editors(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadEditors);
-        self
+        self.endpoint(HelixEndpoint::GetChannelEditors)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-followed-channels>
     pub fn get_followed_channels(&mut self) -> &mut Self {
-        self.push(Scope::UserReadFollows);
-        self
+        self.endpoint(HelixEndpoint::GetFollowedChannels)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-followers>
     pub fn get_channel_followers(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadFollowers);
-        self
+        self.endpoint(HelixEndpoint::GetChannelFollowers)
     }
 
     /// <https://dev.twitch.tv/docs/eventsub/eventsub-subscription-types/#channelban>
@@ -167,14 +200,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-custom-rewards>
     pub fn create_custom_reward(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRedemptions);
-        self
+        self.endpoint(HelixEndpoint::CreateCustomRewards)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#delete-custom-reward>
     pub fn delete_custom_reward(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRedemptions);
-        self
+        self.endpoint(HelixEndpoint::DeleteCustomReward)
     }
 
     /// Accepts `channel:read:redemptions` or `channel:manage:redemptions`.
@@ -182,8 +213,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-custom-reward>
     pub fn get_custom_reward(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadRedemptions);
-        self
+        self.endpoint(HelixEndpoint::GetCustomReward)
     }
 
     /// Accepts `channel:read:redemptions` or `channel:manage:redemptions scope`.
@@ -191,20 +221,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-custom-reward-redemption>
     pub fn get_custom_reward_redemption(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadRedemptions);
-        self
+        self.endpoint(HelixEndpoint::GetCustomRewardRedemption)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-custom-reward>
     pub fn update_custom_reward(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRedemptions);
-        self
+        self.endpoint(HelixEndpoint::UpdateCustomReward)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-redemption-status>
     pub fn update_redemption_status(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRedemptions);
-        self
+        self.endpoint(HelixEndpoint::UpdateRedemptionStatus)
     }
 }
 
@@ -216,14 +243,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-charity-campaign>
     pub fn get_charity_campaign(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadCharity);
-        self
+        self.endpoint(HelixEndpoint::GetCharityCampaign)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-charity-campaign-donations>
     pub fn get_charity_campaign_donations(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadCharity);
-        self
+        self.endpoint(HelixEndpoint::GetCharityCampaignDonations)
     }
 }
 
@@ -248,84 +273,77 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-chatters>
     pub fn get_chatters(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadChatters);
-        self
+        self.endpoint(HelixEndpoint::GetChatters)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-emotes>
     pub fn get_channel_emotes(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelEmotes)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-global-emotes>
     pub fn get_global_emotes(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetGlobalEmotes)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-emote-sets>
     pub fn get_emote_sets(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetEmoteSets)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-chat-badges>
     pub fn get_channel_chat_badges(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelChatBadges)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-global-chat-badges>
     pub fn get_global_chat_badges(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetGlobalChatBadges)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-chat-settings>
     pub fn get_chat_settings(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChatSettings)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-shared-chat-session>
     pub fn get_shard_chat_session(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetSharedChatSession)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-user-emotes>
     pub fn get_user_emotes(&mut self) -> &mut Self {
-        self.push(Scope::UserReadEmotes);
-        self
+        self.endpoint(HelixEndpoint::GetUserEmotes)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-chat-settings>
     pub fn update_chat_settings(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageChatSettings);
-        self
+        self.endpoint(HelixEndpoint::UpdateChatSettings)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#send-chat-announcement>
     pub fn send_chat_announcement(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageAnnouncements);
-        self
+        self.endpoint(HelixEndpoint::SendChatAnnouncement)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#send-a-shoutout>
     pub fn send_shoutout(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageShoutouts);
-        self
+        self.endpoint(HelixEndpoint::SendShoutout)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#send-chat-message>
     pub fn send_chat_message(&mut self) -> &mut Self {
-        self.push(Scope::UserWriteChat);
-        self
+        self.endpoint(HelixEndpoint::SendChatMessage)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-user-chat-color>
     pub fn get_user_chat_color(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetUserChatColor)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-user-chat-color>
     pub fn update_user_chat_color(&mut self) -> &mut Self {
-        self.push(Scope::UserManageChatColor);
-        self
+        self.endpoint(HelixEndpoint::UpdateUserChatColor)
     }
 }
 
@@ -337,8 +355,7 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-clip>
     pub fn create_clip(&mut self) -> &mut Self {
-        self.push(Scope::ClipsEdit);
-        self
+        self.endpoint(HelixEndpoint::CreateClip)
     }
 
     /// Accepts `editor:manage:clips` or `channel:manage:clips`.
@@ -346,13 +363,12 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#create-clip-from-vod>
     pub fn create_clip_from_vod(&mut self) -> &mut Self {
-        self.push(Scope::EditorManageClips);
-        self
+        self.endpoint(HelixEndpoint::CreateClipFromVod)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-clips>
     pub fn get_clip(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetClips)
     }
 
     /// Accepts `editor:manage:clips` or `channel:manage:clips`.
@@ -360,8 +376,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-clips-download>
     pub fn get_clips_download(&mut self) -> &mut Self {
-        self.push(Scope::EditorManageClips);
-        self
+        self.endpoint(HelixEndpoint::GetClipsDownload)
     }
 }
 
@@ -373,7 +388,7 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-content-classification-labels>
     pub fn get_content_classification_labels(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetContentClassificationLabels)
     }
 }
 
@@ -385,12 +400,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-top-games>
     pub fn get_top_games(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetTopGames)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-games>
     pub fn get_games(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetGames)
     }
 }
 
@@ -402,8 +417,7 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-creator-goals>
     pub fn get_creator_goals(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGoals);
-        self
+        self.endpoint(HelixEndpoint::GetCreatorGoals)
     }
 }
 
@@ -430,14 +444,12 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-guest-star-settings>
     pub fn get_channel_guest_star_setings(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGuestStar);
-        self
+        self.endpoint(HelixEndpoint::GetChannelGuestStarSettings)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-channel-guest-star-settings>
     pub fn update_channel_guest_star_setings(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::UpdateChannelGuestStarSettings)
     }
 
     /// Accepts `channel:read:guest_star`, `channel:manage:guest_star`,
@@ -446,20 +458,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-guest-star-session>
     pub fn get_guest_star_session(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGuestStar);
-        self
+        self.endpoint(HelixEndpoint::GetGuestStarSession)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-guest-star-session>
     pub fn create_guest_star_session(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::CreateGuestStarSession)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#end-guest-star-session>
     pub fn end_guest_star_session(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::EndGuestStarSession)
     }
 
     /// Accepts `channel:read:guest_star`, `channel:manage:guest_star`,
@@ -468,8 +477,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-guest-star-invites>
     pub fn get_guest_star_invites(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGuestStar);
-        self
+        self.endpoint(HelixEndpoint::GetGuestStarInvites)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -477,8 +485,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#send-guest-star-invite>
     pub fn send_guest_star_invite(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::SendGuestStarInvite)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -486,8 +493,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#delete-guest-star-invite>
     pub fn delete_guest_star_invite(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::DeleteGuestStarInvite)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -495,8 +501,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#assign-guest-star-slot>
     pub fn assign_guest_star_slot(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::AssignGuestStarSlot)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -504,8 +509,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#update-guest-star-slot>
     pub fn update_guest_star_slot(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::UpdateGuestStarSlot)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -513,8 +517,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#delete-guest-star-slot>
     pub fn delete_guest_star_slot(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::DeleteGuestStarSlot)
     }
 
     /// Accepts `channel:manage:guest_star` or `moderator:manage:guest_star`.
@@ -522,8 +525,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#update-guest-star-slot-settings>
     pub fn update_guest_star_slot_settings(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageGuestStar);
-        self
+        self.endpoint(HelixEndpoint::UpdateGuestStarSlotSettings)
     }
 }
 
@@ -535,8 +537,7 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-hype-train-status>
     pub fn get_hype_train_status(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadHypeTrain);
-        self
+        self.endpoint(HelixEndpoint::GetHypeTrainStatus)
     }
 }
 
@@ -572,14 +573,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#check-automod-status>
     pub fn check_automod_status(&mut self) -> &mut Self {
-        self.push(Scope::ModerationRead);
-        self
+        self.endpoint(HelixEndpoint::CheckAutomodStatus)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#manage-held-automod-messages>
     pub fn manage_held_automod_messages(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageAutomod);
-        self
+        self.endpoint(HelixEndpoint::ManageHeldAutomodMessages)
     }
 
     /// Accepts `moderator:read:automod_settings` or `moderator:manage:automod_settings`.
@@ -587,14 +586,12 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-automod-settings>
     pub fn get_automod_settings(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadAutomodSettings);
-        self
+        self.endpoint(HelixEndpoint::GetAutomodSettings)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-automod-settings>
     pub fn update_automod_settings(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageAutomodSettings);
-        self
+        self.endpoint(HelixEndpoint::UpdateAutomodSettings)
     }
 
     /// Accepts `moderation:read` or `moderator:manage:banned_users`.
@@ -602,20 +599,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-banned-users>
     pub fn get_banned_users(&mut self) -> &mut Self {
-        self.push(Scope::ModerationRead);
-        self
+        self.endpoint(HelixEndpoint::GetBannedUsers)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#ban-user>
     pub fn ban_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageBannedUsers);
-        self
+        self.endpoint(HelixEndpoint::BanUser)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#unban-user>
     pub fn unban_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageBannedUsers);
-        self
+        self.endpoint(HelixEndpoint::UnbanUser)
     }
 
     /// Accepts `moderator:read:unban_requests` or `moderator:manage:unban_requests`.
@@ -623,14 +617,12 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-unban-requests>
     pub fn get_unban_requests(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadUnbanRequests);
-        self
+        self.endpoint(HelixEndpoint::GetUnbanRequests)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#resolve-unban-requests>
     pub fn resolve_unban_requests(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageUnbanRequests);
-        self
+        self.endpoint(HelixEndpoint::ResolveUnbanRequests)
     }
 
     /// Accepts `moderator:read:blocked_terms` or `moderator:manage:blocked_terms`.
@@ -638,32 +630,27 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-blocked-terms>
     pub fn get_blocked_terms(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadBlockedTerms);
-        self
+        self.endpoint(HelixEndpoint::GetBlockedTerms)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#add-blocked-term>
     pub fn add_blocked_term(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageBlockedTerms);
-        self
+        self.endpoint(HelixEndpoint::AddBlockedTerm)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#remove-blocked-term>
     pub fn remove_blocked_term(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageBlockedTerms);
-        self
+        self.endpoint(HelixEndpoint::RemoveBlockedTerm)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#delete-chat-messages>
     pub fn delete_chat_messages(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageChatMessages);
-        self
+        self.endpoint(HelixEndpoint::DeleteChatMessages)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-moderated-channels>
     pub fn get_moderated_channels(&mut self) -> &mut Self {
-        self.push(Scope::UserReadModeratedChannels);
-        self
+        self.endpoint(HelixEndpoint::GetModeratedChannels)
     }
 
     /// Accepts `moderation:read` or `channel:manage:moderators`.
@@ -671,20 +658,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-moderators>
     pub fn get_moderators(&mut self) -> &mut Self {
-        self.push(Scope::ModerationRead);
-        self
+        self.endpoint(HelixEndpoint::GetModerators)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#add-channel-moderator>
     pub fn add_channel_moderator(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageModerators);
-        self
+        self.endpoint(HelixEndpoint::AddChannelModerator)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#remove-channel-moderator>
     pub fn remove_channel_moderator(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageModerators);
-        self
+        self.endpoint(HelixEndpoint::RemoveChannelModerator)
     }
 
     /// Accepts `channel:read:vips` or `channel:manage:vips`.
@@ -692,26 +676,22 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-vips>
     pub fn get_vips(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadVips);
-        self
+        self.endpoint(HelixEndpoint::GetVips)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#add-channel-vip>
     pub fn add_channel_vip(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageVips);
-        self
+        self.endpoint(HelixEndpoint::AddChannelVip)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#remove-channel-vip>
     pub fn remove_channel_vip(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageVips);
-        self
+        self.endpoint(HelixEndpoint::RemoveChannelVip)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-shield-mode-status>
     pub fn update_shield_mode_status(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageShieldMode);
-        self
+        self.endpoint(HelixEndpoint::UpdateShieldModeStatus)
     }
 
     /// Accepts `moderator:read:shield_mode` or `moderator:manage:shield_mode`.
@@ -719,26 +699,22 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-shield-mode-status>
     pub fn get_shield_mode_status(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadShieldMode);
-        self
+        self.endpoint(HelixEndpoint::GetShieldModeStatus)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#warn-chat-user>
     pub fn warn_chat_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageWarnings);
-        self
+        self.endpoint(HelixEndpoint::WarnChatUser)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#add-suspicious-status-to-chat-user>
     pub fn add_suspicious_status_to_chat_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageSuspiciousUsers);
-        self
+        self.endpoint(HelixEndpoint::AddSuspiciousStatusToChatUser)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#remove-suspicious-status-from-chat-user>
     pub fn remove_suspicious_status_from_chat_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageSuspiciousUsers);
-        self
+        self.endpoint(HelixEndpoint::RemoveSuspiciousStatusFromChatUser)
     }
 }
 
@@ -753,20 +729,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-polls>
     pub fn get_polls(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadPolls);
-        self
+        self.endpoint(HelixEndpoint::GetPolls)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-poll>
     pub fn create_poll(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManagePolls);
-        self
+        self.endpoint(HelixEndpoint::CreatePoll)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#end-poll>
     pub fn end_poll(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManagePolls);
-        self
+        self.endpoint(HelixEndpoint::EndPoll)
     }
 }
 
@@ -781,20 +754,17 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-predictions>
     pub fn get_predictions(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadPredictions);
-        self
+        self.endpoint(HelixEndpoint::GetPredictions)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-prediction>
     pub fn create_prediction(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManagePredictions);
-        self
+        self.endpoint(HelixEndpoint::CreatePrediction)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#end-prediction>
     pub fn end_prediction(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManagePredictions);
-        self
+        self.endpoint(HelixEndpoint::EndPrediction)
     }
 }
 
@@ -806,14 +776,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#start-a-raid>
     pub fn start_raid(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRaids);
-        self
+        self.endpoint(HelixEndpoint::StartRaid)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#cancel-a-raid>
     pub fn cancel_raid(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageRaids);
-        self
+        self.endpoint(HelixEndpoint::CancelRaid)
     }
 }
 
@@ -830,36 +798,32 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-stream-schedule>
     pub fn get_channel_stream_schedule(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelStreamSchedule)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-icalendar>
     pub fn get_channel_icalendar(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelICalendar)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-channel-stream-schedule>
     pub fn update_channel_stream_schedule(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageSchedule);
-        self
+        self.endpoint(HelixEndpoint::UpdateChannelStreamSchedule)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-channel-stream-schedule-segment>
     pub fn create_channel_stream_schedule_segment(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageSchedule);
-        self
+        self.endpoint(HelixEndpoint::CreateChannelStreamScheduleSegment)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-channel-stream-schedule-segment>
     pub fn update_channel_stream_schedule_segment(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageSchedule);
-        self
+        self.endpoint(HelixEndpoint::UpdateChannelStreamScheduleSegment)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#delete-channel-stream-schedule-segment>
     pub fn delete_channel_stream_schedule_segment(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageSchedule);
-        self
+        self.endpoint(HelixEndpoint::DeleteChannelStreamScheduleSegment)
     }
 }
 
@@ -871,12 +835,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#search-categories>
     pub fn search_categories(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::SearchCategories)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#search-channels>
     pub fn channels_search(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::SearchChannels)
     }
 }
 
@@ -892,25 +856,22 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-stream-key>
     pub fn get_stream_key(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadStreamKey);
-        self
+        self.endpoint(HelixEndpoint::GetStreamKey)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-streams>
     pub fn get_streams(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetStreams)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-followed-streams>
     pub fn get_followed_streams(&mut self) -> &mut Self {
-        self.push(Scope::UserReadFollows);
-        self
+        self.endpoint(HelixEndpoint::GetFollowedStreams)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#create-stream-marker>
     pub fn create_stream_marker(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageBroadcast);
-        self
+        self.endpoint(HelixEndpoint::CreateStreamMarker)
     }
 
     /// Accepts `user:read:broadcast` or `channel:manage:broadcast`.
@@ -918,8 +879,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-stream-markers>
     pub fn get_stream_markers(&mut self) -> &mut Self {
-        self.push(Scope::UserReadBroadcast);
-        self
+        self.endpoint(HelixEndpoint::GetStreamMarkers)
     }
 }
 
@@ -932,14 +892,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-broadcaster-subscriptions>
     pub fn get_broadcaster_subscriptions(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadSubscriptions);
-        self
+        self.endpoint(HelixEndpoint::GetBroadcasterSubscriptions)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#check-user-subscription>
     pub fn check_user_subscription(&mut self) -> &mut Self {
-        self.push(Scope::UserReadSubscriptions);
-        self
+        self.endpoint(HelixEndpoint::CheckUserSubscription)
     }
 }
 
@@ -951,12 +909,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-channel-teams>
     pub fn get_channel_teams(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetChannelTeams)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-teams>
     pub fn get_teams(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetTeams)
     }
 }
 
@@ -973,10 +931,12 @@ impl ScopesMut<'_> {
             .update_user_extensions()
     }
 
+    /// Adds `user:read:email` to include the verified email address in the response.
+    ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-users>
     pub fn get_users(&mut self) -> &mut Self {
-        self.push(Scope::UserReadEmail);
-        self
+        self.endpoint(HelixEndpoint::GetUsers)
+            .push(Scope::UserReadEmail)
     }
 
     /// Uses `user:edit`.
@@ -984,19 +944,18 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#update-user>
     pub fn update_user(&mut self) -> &mut Self {
-        self.extend([Scope::UserReadEmail, Scope::UserEdit]);
-        self
+        self.endpoint(HelixEndpoint::UpdateUser)
+            .push(Scope::UserReadEmail)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-authorization-by-user>
     pub fn get_authorization_by_user(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetAuthorizationByUser)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-user-block-list>
     pub fn get_user_block_list(&mut self) -> &mut Self {
-        self.push(Scope::UserReadBlockedUsers);
-        self
+        self.endpoint(HelixEndpoint::GetUserBlockList)
     }
 
     pub fn block_unblock_user(&mut self) -> &mut Self {
@@ -1006,14 +965,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#block-user>
     pub fn block_user(&mut self) -> &mut Self {
-        self.push(Scope::UserManageBlockedUsers);
-        self
+        self.endpoint(HelixEndpoint::BlockUser)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#unblock-user>
     pub fn unblock_user(&mut self) -> &mut Self {
-        self.push(Scope::UserManageBlockedUsers);
-        self
+        self.endpoint(HelixEndpoint::UnblockUser)
     }
 
     /// Accepts `user:read:broadcast` or `user:edit:broadcast`.
@@ -1022,8 +979,7 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-user-extensions>
     pub fn get_user_extensions(&mut self) -> &mut Self {
-        self.push(Scope::UserReadBroadcast);
-        self
+        self.endpoint(HelixEndpoint::GetUserExtensions)
     }
 
     /// Accepts `user:read:broadcast` or `user:edit:broadcast`.
@@ -1031,14 +987,12 @@ impl ScopesMut<'_> {
     ///
     /// <https://dev.twitch.tv/docs/api/reference/#get-user-active-extensions>
     pub fn get_user_active_extensions(&mut self) -> &mut Self {
-        self.push(Scope::UserReadBroadcast);
-        self
+        self.endpoint(HelixEndpoint::GetUserActiveExtensions)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#update-user-extensions>
     pub fn update_user_extensions(&mut self) -> &mut Self {
-        self.push(Scope::UserEditBroadcast);
-        self
+        self.endpoint(HelixEndpoint::UpdateUserExtensions)
     }
 }
 
@@ -1050,13 +1004,12 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#get-videos>
     pub fn get_videos(&mut self) -> &mut Self {
-        self
+        self.endpoint(HelixEndpoint::GetVideos)
     }
 
     /// <https://dev.twitch.tv/docs/api/reference/#delete-videos>
     pub fn delete_videos(&mut self) -> &mut Self {
-        self.push(Scope::ChannelManageVideos);
-        self
+        self.endpoint(HelixEndpoint::DeleteVideos)
     }
 }
 
@@ -1068,8 +1021,7 @@ impl ScopesMut<'_> {
 
     /// <https://dev.twitch.tv/docs/api/reference/#send-whisper>
     pub fn send_whisper(&mut self) -> &mut Self {
-        self.push(Scope::UserManageWhispers);
-        self
+        self.endpoint(HelixEndpoint::SendWhisper)
     }
 }
 
@@ -1113,43 +1065,37 @@ impl ScopesMut<'_> {
     /// Covers `automod.message.hold`, `automod.message.hold.v2`,
     /// `automod.message.update`, and `automod.message.update.v2`.
     pub fn on_automod_message(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageAutomod);
-        self
+        self.eventsub(EventSubType::AutomodMessageHoldV2)
     }
 
     /// Covers `automod.settings.update`.
     pub fn on_automod_settings_update(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadAutomodSettings);
-        self
+        self.eventsub(EventSubType::AutomodSettingsUpdate)
     }
 
     /// Covers `automod.terms.update`.
     pub fn on_automod_terms_update(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorManageAutomod);
-        self
+        self.eventsub(EventSubType::AutomodTermsUpdate)
     }
 
     /// Covers `channel.bits.use`.
     pub fn on_channel_bits_use(&mut self) -> &mut Self {
-        self.push(Scope::BitsRead);
-        self
+        self.eventsub(EventSubType::ChannelBitsUse)
     }
 
     /// Covers `channel.update`.
     pub fn on_channel_update(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::ChannelUpdate)
     }
 
     /// Covers `channel.follow`.
     pub fn on_channel_follow(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadFollowers);
-        self
+        self.eventsub(EventSubType::ChannelFollow)
     }
 
     /// Covers `channel.ad_break.begin`.
     pub fn on_channel_ad_break_begin(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadAds);
-        self
+        self.eventsub(EventSubType::ChannelAdBreakBegin)
     }
 
     /// Covers `channel.chat.clear`, `channel.chat.clear_user_messages`,
@@ -1157,32 +1103,28 @@ impl ScopesMut<'_> {
     /// `channel.chat.notification`, `channel.chat.settings_update`,
     /// `channel.chat.user_message_hold`, and `channel.chat.user_message_update`.
     pub fn on_channel_chat(&mut self) -> &mut Self {
-        self.push(Scope::UserReadChat);
-        self
+        self.eventsub(EventSubType::ChannelChatMessage)
     }
 
     /// Covers `channel.subscribe`, `channel.subscription.end`,
     /// `channel.subscription.gift`, and `channel.subscription.message`.
     pub fn on_channel_subscription(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadSubscriptions);
-        self
+        self.eventsub(EventSubType::ChannelSubscribe)
     }
 
     /// Covers `channel.cheer`.
     pub fn on_channel_cheer(&mut self) -> &mut Self {
-        self.push(Scope::BitsRead);
-        self
+        self.eventsub(EventSubType::ChannelCheer)
     }
 
     /// Covers `channel.raid`.
     pub fn on_channel_raid(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::ChannelRaid)
     }
 
     /// Covers `channel.ban` and `channel.unban`.
     pub fn on_channel_ban(&mut self) -> &mut Self {
-        self.push(Scope::ChannelModerate);
-        self
+        self.eventsub(EventSubType::ChannelBan)
     }
 
     /// Covers `channel.unban_request.create` and `channel.unban_request.resolve`.
@@ -1190,8 +1132,7 @@ impl ScopesMut<'_> {
     /// Accepts `moderator:read:unban_requests` or `moderator:manage:unban_requests`.
     /// Uses `moderator:read:unban_requests`.
     pub fn on_channel_unban_request(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadUnbanRequests);
-        self
+        self.eventsub(EventSubType::ChannelUnbanRequestCreate)
     }
 
     /// Covers `channel.moderate` and `channel.moderate.v2`.
@@ -1199,24 +1140,14 @@ impl ScopesMut<'_> {
     /// Uses `moderator:read:blocked_terms`, `moderator:read:chat_settings`,
     /// `moderator:read:unban_requests`, `moderator:read:banned_users`,
     /// `moderator:read:chat_messages`, `moderator:read:moderators`,
-    /// and `moderator:read:vips`.
+    /// `moderator:read:vips`, and `moderator:read:warnings`.
     pub fn on_channel_moderate(&mut self) -> &mut Self {
-        self.extend([
-            Scope::ModeratorReadBlockedTerms,
-            Scope::ModeratorReadChatSettings,
-            Scope::ModeratorReadUnbanRequests,
-            Scope::ModeratorReadBannedUsers,
-            Scope::ModeratorReadChatMessages,
-            Scope::ModeratorReadModerators,
-            Scope::ModeratorReadVips,
-        ]);
-        self
+        self.eventsub(EventSubType::ChannelModerateV2)
     }
 
     /// Covers `channel.moderator.add` and `channel.moderator.remove`.
     pub fn on_channel_moderator(&mut self) -> &mut Self {
-        self.push(Scope::ModerationRead);
-        self
+        self.eventsub(EventSubType::ChannelModeratorAdd)
     }
 
     /// Covers `channel.guest_star_session.begin`, `channel.guest_star_session.end`,
@@ -1226,8 +1157,7 @@ impl ScopesMut<'_> {
     /// `moderator:read:guest_star`, or `moderator:manage:guest_star`.
     /// Uses `channel:read:guest_star`.
     pub fn on_channel_guest_star(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGuestStar);
-        self
+        self.eventsub(EventSubType::ChannelGuestStarSessionBegin)
     }
 
     /// Covers `channel.channel_points_automatic_reward_redemption.add`,
@@ -1241,8 +1171,7 @@ impl ScopesMut<'_> {
     /// Accepts `channel:read:redemptions` or `channel:manage:redemptions`.
     /// Uses `channel:read:redemptions`.
     pub fn on_channel_channel_points(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadRedemptions);
-        self
+        self.eventsub(EventSubType::ChannelPointsCustomRewardRedemptionAdd)
     }
 
     /// Covers `channel.poll.begin`, `channel.poll.progress`, and `channel.poll.end`.
@@ -1250,8 +1179,7 @@ impl ScopesMut<'_> {
     /// Accepts `channel:read:polls` or `channel:manage:polls`.
     /// Uses `channel:read:polls`.
     pub fn on_channel_poll(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadPolls);
-        self
+        self.eventsub(EventSubType::ChannelPollBegin)
     }
 
     /// Covers `channel.prediction.begin`, `channel.prediction.progress`,
@@ -1260,14 +1188,12 @@ impl ScopesMut<'_> {
     /// Accepts `channel:read:predictions` or `channel:manage:predictions`.
     /// Uses `channel:read:predictions`.
     pub fn on_channel_prediction(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadPredictions);
-        self
+        self.eventsub(EventSubType::ChannelPredictionBegin)
     }
 
     /// Covers `channel.suspicious_user.message` and `channel.suspicious_user.update`.
     pub fn on_channel_suspicious_user(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadSuspiciousUsers);
-        self
+        self.eventsub(EventSubType::ChannelSuspiciousUserMessage)
     }
 
     /// Covers `channel.vip.add` and `channel.vip.remove`.
@@ -1275,8 +1201,7 @@ impl ScopesMut<'_> {
     /// Accepts `channel:read:vips` or `channel:manage:vips`.
     /// Uses `channel:read:vips`.
     pub fn on_channel_vip(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadVips);
-        self
+        self.eventsub(EventSubType::ChannelVipAdd)
     }
 
     /// Covers `channel.warning.acknowledge` and `channel.warning.send`.
@@ -1284,15 +1209,13 @@ impl ScopesMut<'_> {
     /// Accepts `moderator:read:warnings` or `moderator:manage:warnings`.
     /// Uses `moderator:read:warnings`.
     pub fn on_channel_warning(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadWarnings);
-        self
+        self.eventsub(EventSubType::ChannelWarningSend)
     }
 
     /// Covers `channel.hype_train.begin`, `channel.hype_train.progress`,
     /// and `channel.hype_train.end`.
     pub fn on_channel_hype_train(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadHypeTrain);
-        self
+        self.eventsub(EventSubType::ChannelHypeTrainBeginV2)
     }
 
     /// Covers `channel.charity_campaign.donate`,
@@ -1300,14 +1223,13 @@ impl ScopesMut<'_> {
     /// `channel.charity_campaign.progress`,
     /// and `channel.charity_campaign.stop`.
     pub fn on_channel_charity_campaign(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadCharity);
-        self
+        self.eventsub(EventSubType::ChannelCharityCampaignDonate)
     }
 
     /// Covers `channel.shared_chat.begin`, `channel.shared_chat.update`,
     /// and `channel.shared_chat.end`.
     pub fn on_channel_shared_chat(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::ChannelSharedChatBegin)
     }
 
     /// Covers `channel.shield_mode.begin` and `channel.shield_mode.end`.
@@ -1315,8 +1237,7 @@ impl ScopesMut<'_> {
     /// Accepts `moderator:read:shield_mode` or `moderator:manage:shield_mode`.
     /// Uses `moderator:read:shield_mode`.
     pub fn on_channel_shield_mode(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadShieldMode);
-        self
+        self.eventsub(EventSubType::ChannelShieldModeBegin)
     }
 
     /// Covers `channel.shoutout.create` and `channel.shoutout.receive`.
@@ -1324,23 +1245,22 @@ impl ScopesMut<'_> {
     /// Accepts `moderator:read:shoutouts` or `moderator:manage:shoutouts`.
     /// Uses `moderator:read:shoutouts`.
     pub fn on_channel_shoutout(&mut self) -> &mut Self {
-        self.push(Scope::ModeratorReadShoutouts);
-        self
+        self.eventsub(EventSubType::ChannelShoutoutCreate)
     }
 
     /// Covers `conduit.shard.disabled`.
     pub fn on_conduit_shard_disabled(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::ConduitShardDisabled)
     }
 
     /// Covers `drop.entitlement.grant`.
     pub fn on_drop_entitlement_grant(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::DropEntitlementGrant)
     }
 
     /// Covers `extension.bits_transaction.create`.
     pub fn on_extension_bits_transaction_create(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::ExtensionBitsTransactionCreate)
     }
 }
 
@@ -1348,8 +1268,7 @@ impl ScopesMut<'_> {
 impl ScopesMut<'_> {
     /// Covers `channel.goal.begin`, `channel.goal.progress`, and `channel.goal.end`.
     pub fn on_channel_goal(&mut self) -> &mut Self {
-        self.push(Scope::ChannelReadGoals);
-        self
+        self.eventsub(EventSubType::ChannelGoalBegin)
     }
 }
 
@@ -1357,7 +1276,7 @@ impl ScopesMut<'_> {
 impl ScopesMut<'_> {
     /// Covers `stream.online` and `stream.offline`.
     pub fn on_stream(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::StreamOnline)
     }
 }
 
@@ -1365,14 +1284,14 @@ impl ScopesMut<'_> {
 impl ScopesMut<'_> {
     /// Covers `user.authorization.grant` and `user.authorization.revoke`.
     pub fn on_user_authorization(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::UserAuthorizationGrant)
     }
 
     /// Covers `user.update`.
     ///
     /// If you have the `user:read:email` scope, the notification will include the email field.
     pub fn on_user_update(&mut self) -> &mut Self {
-        self
+        self.eventsub(EventSubType::UserUpdate)
     }
 
     /// Covers `user.whisper.message`.
@@ -1380,7 +1299,6 @@ impl ScopesMut<'_> {
     /// Accepts `user:read:whispers` or `user:manage:whispers`.
     /// Uses `user:read:whispers`.
     pub fn on_user_whisper_message(&mut self) -> &mut Self {
-        self.push(Scope::UserReadWhispers);
-        self
+        self.eventsub(EventSubType::UserWhisperMessage)
     }
 }
@@ -0,0 +1,415 @@
+use std::{
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign},
+    str::FromStr,
+};
+
+use serde::{
+    de::{SeqAccess, Visitor},
+    ser::SerializeSeq,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{InvalidScopeError, Scope};
+
+/// A set of [`Scope`]s stored as a bitset
+///
+/// `ScopeSet` is `Copy`, compares in constant time and always renders in the
+/// same order, so it can be used as a map key or embedded in URLs.
+///
+/// ```
+/// use twitch_oauth_token::{scope::ScopeSet, Scope};
+///
+/// let granted: ScopeSet = "chat:read chat:edit user:read:email".parse().unwrap();
+/// let required = ScopeSet::from([Scope::ChatRead, Scope::ChatEdit]);
+///
+/// assert!(required.is_subset(granted));
+/// assert_eq!(granted - required, ScopeSet::from(Scope::UserReadEmail));
+/// assert_eq!(required.to_string(), "chat:edit chat:read");
+/// ```
+///
+/// # Encoding
+///
+/// [`ScopeSet::bits`] and [`ScopeSet::from_bits`] expose the raw bitset for
+/// compact storage. Bit positions follow the declaration order of [`Scope`],
+/// new scopes are only ever appended.
+///
+/// # Serde
+///
+/// Serializes as a sorted sequence of scope strings. Deserializes from either a
+/// sequence or a space-separated string.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ScopeSet(u128);
+
+const _: () = assert!(Scope::ALL.len() <= u128::BITS as usize);
+
+const MASK: u128 = if Scope::ALL.len() == u128::BITS as usize {
+    u128::MAX
+} else {
+    (1 << Scope::ALL.len()) - 1
+};
+
+impl ScopeSet {
+    pub const EMPTY: Self = Self(0);
+
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Every known scope
+    pub const fn all() -> Self {
+        Self(MASK)
+    }
+
+    pub const fn bits(self) -> u128 {
+        self.0
+    }
+
+    /// Build a set from [`ScopeSet::bits`], ignoring bits that don't map to a scope
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits & MASK)
+    }
+
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(self, scope: Scope) -> bool {
+        self.0 & (1 << scope.bit()) != 0
+    }
+
+    /// Returns whether the scope was newly inserted
+    pub fn insert(&mut self, scope: Scope) -> bool {
+        let inserted = !self.contains(scope);
+        self.0 |= 1 << scope.bit();
+        inserted
+    }
+
+    /// Returns whether the scope was present
+    pub fn remove(&mut self, scope: Scope) -> bool {
+        let removed = self.contains(scope);
+        self.0 &= !(1 << scope.bit());
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.0 = 0;
+    }
+
+    pub const fn with(self, scope: Scope) -> Self {
+        Self(self.0 | (1 << scope.bit()))
+    }
+
+    pub const fn without(self, scope: Scope) -> Self {
+        Self(self.0 & !(1 << scope.bit()))
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
+    pub const fn is_subset(self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    pub const fn is_superset(self, other: Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub const fn is_disjoint(self, other: Self) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    /// Iterate in declaration order of [`Scope`]
+    pub fn iter(self) -> ScopeSetIter {
+        ScopeSetIter(self.0)
+    }
+
+    /// Scope strings in lexicographic order
+    fn sorted(self) -> Vec<&'static str> {
+        let mut scopes = self.iter().map(|scope| scope.as_str()).collect::<Vec<_>>();
+        scopes.sort_unstable();
+        scopes
+    }
+}
+
+/// Iterator over the scopes in a [`ScopeSet`]
+#[derive(Debug, Clone)]
+pub struct ScopeSetIter(u128);
+
+impl Iterator for ScopeSetIter {
+    type Item = Scope;
+
+    fn next(&mut self) -> Option<Scope> {
+        if self.0 == 0 {
+            return None;
+        }
+        let bit = self.0.trailing_zeros();
+        self.0 &= self.0 - 1;
+        Some(Scope::ALL[bit as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.0.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for ScopeSetIter {}
+impl FusedIterator for ScopeSetIter {}
+
+impl IntoIterator for ScopeSet {
+    type Item = Scope;
+    type IntoIter = ScopeSetIter;
+
+    fn into_iter(self) -> ScopeSetIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ScopeSet {
+    type Item = Scope;
+    type IntoIter = ScopeSetIter;
+
+    fn into_iter(self) -> ScopeSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Scope> for ScopeSet {
+    fn from_iter<I: IntoIterator<Item = Scope>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Scope> for ScopeSet {
+    fn extend<I: IntoIterator<Item = Scope>>(&mut self, iter: I) {
+        for scope in iter {
+            self.insert(scope);
+        }
+    }
+}
+
+impl From<Scope> for ScopeSet {
+    fn from(scope: Scope) -> Self {
+        Self::new().with(scope)
+    }
+}
+
+impl<const N: usize> From<[Scope; N]> for ScopeSet {
+    fn from(scopes: [Scope; N]) -> Self {
+        scopes.into_iter().collect()
+    }
+}
+
+impl BitOr for ScopeSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ScopeSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for ScopeSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ScopeSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for ScopeSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ScopeSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl BitXor for ScopeSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ScopeSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Debug for ScopeSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_set().entries(self.sorted()).finish()
+    }
+}
+
+/// Space-separated, sorted lexicographically
+impl Display for ScopeSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str(&self.sorted().join(" "))
+    }
+}
+
+/// Parses space-separated scopes, as used in OAuth URLs and form bodies
+impl FromStr for ScopeSet {
+    type Err = InvalidScopeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split_whitespace().map(Scope::from_str).collect()
+    }
+}
+
+impl Serialize for ScopeSet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let scopes = self.sorted();
+        let mut seq = serializer.serialize_seq(Some(scopes.len()))?;
+        for scope in scopes {
+            seq.serialize_element(scope)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for ScopeSet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScopeSetVisitor;
+
+        impl<'de> Visitor<'de> for ScopeSetVisitor {
+            type Value = ScopeSet;
+
+            fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+                f.write_str("a sequence of scopes or a space-separated scope string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<ScopeSet, E>
+            where
+                E: serde::de::Error,
+            {
+                v.parse().map_err(E::custom)
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<ScopeSet, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut set = ScopeSet::new();
+                while let Some(scope) = seq.next_element::<Scope>()? {
+                    set.insert(scope);
+                }
+                Ok(set)
+            }
+        }
+
+        deserializer.deserialize_any(ScopeSetVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Scope;
+
+    use super::ScopeSet;
+
+    #[test]
+    fn bit_positions_follow_declaration_order() {
+        for (i, scope) in Scope::ALL.iter().enumerate() {
+            assert_eq!(scope.bit() as usize, i);
+        }
+        assert_eq!(ScopeSet::all().len(), Scope::ALL.len());
+        assert_eq!(ScopeSet::from_bits(u128::MAX), ScopeSet::all());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a = ScopeSet::from([Scope::ChatRead, Scope::ChatEdit]);
+        let b = ScopeSet::from([Scope::ChatRead, Scope::UserReadEmail]);
+
+        assert_eq!(
+            a | b,
+            ScopeSet::from([Scope::ChatRead, Scope::ChatEdit, Scope::UserReadEmail])
+        );
+        assert_eq!(a & b, ScopeSet::from(Scope::ChatRead));
+        assert_eq!(a - b, ScopeSet::from(Scope::ChatEdit));
+        assert_eq!(
+            a ^ b,
+            ScopeSet::from([Scope::ChatEdit, Scope::UserReadEmail])
+        );
+        assert!((a & b).is_subset(a));
+        assert!(a.is_superset(a & b));
+        assert!((a - b).is_disjoint(b));
+
+        let mut c = ScopeSet::new();
+        assert!(c.insert(Scope::ChatRead));
+        assert!(!c.insert(Scope::ChatRead));
+        assert!(c.remove(Scope::ChatRead));
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn display_is_sorted_and_round_trips() {
+        let set: ScopeSet = "user:read:email  chat:read\tchannel:bot".parse().unwrap();
+        assert_eq!(set.to_string(), "channel:bot chat:read user:read:email");
+        assert_eq!(set.to_string().parse::<ScopeSet>().unwrap(), set);
+
+        assert!("chat:read unknown:scope".parse::<ScopeSet>().is_err());
+        assert_eq!("".parse::<ScopeSet>().unwrap(), ScopeSet::EMPTY);
+    }
+
+    #[test]
+    fn serde() {
+        let set = ScopeSet::from([Scope::UserReadEmail, Scope::ChatRead]);
+
+        let json = serde_json::to_value(set).unwrap();
+        assert_eq!(json, serde_json::json!(["chat:read", "user:read:email"]));
+        assert_eq!(serde_json::from_value::<ScopeSet>(json).unwrap(), set);
+
+        let from_str: ScopeSet = serde_json::from_str("\"user:read:email chat:read\"").unwrap();
+        assert_eq!(from_str, set);
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::Deref,
+};
+
+use crate::{error, Error, UserToken};
+
+use super::{Scope, ScopeSet};
+
+/// A [`Scope`] as a type, see [`marker`](super::marker)
+pub trait ScopeMarker {
+    const SCOPE: Scope;
+}
+
+/// A set of scopes known at compile time, built with [`scopes!`](crate::scopes)
+pub trait ScopeList {
+    const SCOPES: ScopeSet;
+}
+
+/// The empty [`ScopeList`]
+#[derive(Debug)]
+pub enum Nil {}
+
+/// A [`ScopeList`] of `H` followed by `T`
+pub struct Cons<H, T>(PhantomData<fn() -> (H, T)>);
+
+impl ScopeList for Nil {
+    const SCOPES: ScopeSet = ScopeSet::EMPTY;
+}
+
+impl<H: ScopeMarker, T: ScopeList> ScopeList for Cons<H, T> {
+    const SCOPES: ScopeSet = T::SCOPES.with(H::SCOPE);
+}
+
+/// Names a [`ScopeList`] type from [`Scope`] variants
+///
+/// ```
+/// use twitch_oauth_token::{scope::ScopeList, scopes, Scope, ScopeSet};
+///
+/// type Moderation = scopes![ModeratorManageBannedUsers, ModeratorReadChatters];
+///
+/// assert_eq!(
+///     Moderation::SCOPES,
+///     ScopeSet::from([Scope::ModeratorManageBannedUsers, Scope::ModeratorReadChatters])
+/// );
+/// ```
+#[macro_export]
+macro_rules! scopes {
+    () => {
+        $crate::scope::Nil
+    };
+    ($scope:ident $(, $rest:ident)* $(,)?) => {
+        $crate::scope::Cons<$crate::scope::marker::$scope, $crate::scopes![$($rest),*]>
+    };
+}
+
+/// A [`UserToken`] checked to carry every scope in `S`
+///
+/// Functions can take a `ScopedToken` to require scopes in their signature, so
+/// an under-privileged token is rejected once, where it is created.
+///
+/// ```
+/// use twitch_oauth_token::{scope::ScopedToken, scopes, UserToken};
+///
+/// type Moderator = scopes![ModeratorManageBannedUsers, ModeratorReadChatters];
+///
+/// fn ban(token: &ScopedToken<scopes![ModeratorManageBannedUsers]>) {
+///     // ...
+/// }
+///
+/// fn run(token: UserToken) -> Result<(), twitch_oauth_token::Error> {
+///     let token = ScopedToken::<Moderator>::new(token)?;
+///     ban(&token.clone().narrow());
+///     Ok(())
+/// }
+/// ```
+///
+/// Narrowing to scopes the token wasn't checked for doesn't compile:
+///
+/// ```compile_fail
+/// use twitch_oauth_token::{scope::ScopedToken, scopes};
+///
+/// fn widen(token: ScopedToken<scopes![ModeratorReadChatters]>) {
+///     let _: ScopedToken<scopes![ModeratorManageBannedUsers]> = token.narrow();
+/// }
+/// # let _ = widen as fn(_);
+/// ```
+pub struct ScopedToken<S> {
+    token: UserToken,
+    scopes: PhantomData<fn() -> S>,
+}
+
+impl<S: ScopeList> ScopedToken<S> {
+    /// Returns a scope error if `token` lacks any scope in `S`
+    pub fn new(token: UserToken) -> Result<Self, Error> {
+        let missing = S::SCOPES - token.scope.known();
+        if !missing.is_empty() {
+            return Err(error::scope::insufficient(missing));
+        }
+        Ok(Self {
+            token,
+            scopes: PhantomData,
+        })
+    }
+
+    /// The scopes guaranteed by `S`
+    pub const fn scopes() -> ScopeSet {
+        S::SCOPES
+    }
+
+    /// Forget scopes, keeping a subset of `S`
+    ///
+    /// Checked at compile time; `T` may list the scopes in any order.
+    pub fn narrow<T: ScopeList>(self) -> ScopedToken<T> {
+        const {
+            assert!(
+                T::SCOPES.is_subset(S::SCOPES),
+                "narrowed scopes must be a subset of the token's scopes"
+            )
+        };
+        ScopedToken {
+            token: self.token,
+            scopes: PhantomData,
+        }
+    }
+}
+
+impl<S> ScopedToken<S> {
+    pub fn token(&self) -> &UserToken {
+        &self.token
+    }
+
+    pub fn into_inner(self) -> UserToken {
+        self.token
+    }
+}
+
+impl<S> Deref for ScopedToken<S> {
+    type Target = UserToken;
+
+    fn deref(&self) -> &UserToken {
+        &self.token
+    }
+}
+
+impl<S> Clone for ScopedToken<S> {
+    fn clone(&self) -> Self {
+        Self {
+            token: self.token.clone(),
+            scopes: PhantomData,
+        }
+    }
+}
+
+impl<S> Debug for ScopedToken<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("ScopedToken").field(&self.token).finish()
+    }
+}
+
+impl<S: ScopeList> TryFrom<UserToken> for ScopedToken<S> {
+    type Error = Error;
+
+    fn try_from(token: UserToken) -> Result<Self, Error> {
+        Self::new(token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{scope::ScopeList, Scope, ScopeSet, UserToken};
+
+    use super::ScopedToken;
+
+    fn token(scope: &str) -> UserToken {
+        serde_json::from_value(json!({
+            "access_token": "d19bb4cb705d1f0",
+            "refresh_token": "",
+            "expires_in": 14124,
+            "scope": scope,
+            "token_type": "bearer"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn scope_lists() {
+        assert!(<scopes![]>::SCOPES.is_empty());
+        assert_eq!(
+            <scopes![ChatRead, UserBot, ChatRead]>::SCOPES,
+            ScopeSet::from([Scope::ChatRead, Scope::UserBot])
+        );
+    }
+
+    #[test]
+    fn checked_on_creation() {
+        type Moderator = scopes![ModeratorManageBannedUsers, ModeratorReadChatters];
+
+        let err = ScopedToken::<Moderator>::new(token("moderator:read:chatters")).unwrap_err();
+        assert!(err.is_scope_error());
+        assert_eq!(
+            err.message(),
+            Some("token is missing scopes: moderator:manage:banned_users")
+        );
+
+        let scoped = ScopedToken::<Moderator>::try_from(token(
+            "moderator:read:chatters moderator:manage:banned_users user:bot",
+        ))
+        .unwrap();
+        assert_eq!(ScopedToken::<Moderator>::scopes().len(), 2);
+
+        let narrowed: ScopedToken<scopes![ModeratorReadChatters]> = scoped.narrow();
+        assert!(narrowed.scope.contains(Scope::UserBot));
+    }
+}
@@ -0,0 +1,261 @@
+//! Tracing spans and metrics for every operation
+//!
+//! Each operation runs in a `twitch_oauth` span with `operation`, `client_id`,
+//! `status` (set by [`TwitchOauth::send`](crate::TwitchOauth::send)) and `token`,
+//! a [`fingerprint`](crate::redact::fingerprint) of the token it acts on. Metrics require the `metrics`
+//! feature, see "Metrics" in the crate docs.
+
+use std::future::Future;
+
+use tracing::{debug, field::Empty, warn, Instrument};
+
+use crate::{interceptor::Operation, ClientId, Error};
+
+/// Run an operation in its span, recording its outcome and latency
+///
+/// `token` is a [`fingerprint`] of the token the operation acts on.
+pub(crate) async fn observe<T, F>(
+    operation: Operation,
+    client_id: &ClientId,
+    token: Option<String>,
+    future: F,
+) -> Result<T, Error>
+where
+    F: Future<Output = Result<T, Error>>,
+{
+    let span = tracing::info_span!(
+        "twitch_oauth",
+        operation = operation.as_str(),
+        client_id = %client_id,
+        status = Empty,
+        token = Empty,
+    );
+    if let Some(token) = token {
+        span.record("token", token);
+    }
+
+    #[cfg(feature = "metrics")]
+    let start = std::time::Instant::now();
+
+    let result = future.instrument(span.clone()).await;
+
+    #[cfg(feature = "metrics")]
+    record(operation, start.elapsed(), result.as_ref().err());
+
+    match &result {
+        Ok(_) => debug!(parent: &span, "operation succeeded"),
+        Err(e) => warn!(parent: &span, kind = e.kind().name(), error = %e, "operation failed"),
+    }
+
+    result
+}
+
+#[cfg(feature = "metrics")]
+fn record(operation: Operation, elapsed: std::time::Duration, error: Option<&Error>) {
+    let outcome = if error.is_some() { "error" } else { "success" };
+
+    metrics::counter!(
+        "twitch_oauth_requests_total",
+        "operation" => operation.as_str(),
+        "outcome" => outcome,
+    )
+    .increment(1);
+
+    metrics::histogram!(
+        "twitch_oauth_request_duration_seconds",
+        "operation" => operation.as_str(),
+        "outcome" => outcome,
+    )
+    .record(elapsed.as_secs_f64());
+
+    if let Some(error) = error {
+        metrics::counter!(
+            "twitch_oauth_errors_total",
+            "operation" => operation.as_str(),
+            "kind" => error.kind().name(),
+        )
+        .increment(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::Write,
+        str::FromStr,
+        sync::{Arc, Mutex},
+    };
+
+    use futures::executor::block_on;
+    use http::StatusCode;
+
+    use crate::{
+        redact::fingerprint,
+        transport::{from_fn, BoxError, HttpResponse},
+        AccessToken, RedirectUrl, RefreshToken, TwitchOauth, UserAuth,
+    };
+
+    fn oauth() -> TwitchOauth<UserAuth> {
+        TwitchOauth::new("client_id", "s3cr3t")
+            .with_transport(from_fn(|request| async move {
+                let body = String::from_utf8(request.body().clone())?;
+                let (status, json) = if request.method() == "GET" {
+                    (
+                        StatusCode::UNAUTHORIZED,
+                        r#"{"status":401,"message":"invalid access token"}"#,
+                    )
+                } else if body.contains("grant_type=refresh_token") {
+                    (
+                        StatusCode::OK,
+                        r#"{"access_token":"leaked-access-token","expires_in":"soon"}"#,
+                    )
+                } else {
+                    (
+                        StatusCode::OK,
+                        r#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#,
+                    )
+                };
+                let mut resp = HttpResponse::new(json.as_bytes().to_vec());
+                *resp.status_mut() = status;
+                Ok::<_, BoxError>(resp)
+            }))
+            .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000").unwrap())
+    }
+
+    #[derive(Clone, Default)]
+    struct Logs(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Logs {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn spans_without_secrets() {
+        let logs = Logs::default();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer({
+                let logs = logs.clone();
+                move || logs.clone()
+            })
+            .finish();
+
+        let oauth = oauth();
+        let err = tracing::subscriber::with_default(subscriber, || {
+            block_on(async {
+                oauth.app_access_token().await.unwrap();
+                oauth
+                    .validate_access_token(&AccessToken::from("my-access-token"))
+                    .await
+                    .unwrap_err();
+                oauth
+                    .refresh_access_token(RefreshToken::from("my-refresh-token"))
+                    .await
+                    .unwrap_err()
+            })
+        });
+
+        assert!(err.is_decode());
+        assert!(!err.raw().unwrap().contains("leaked-access-token"));
+
+        let logs = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        assert!(logs.contains("operation=\"client_credentials\" client_id=client_id status=200"));
+        assert!(logs.contains(&format!(
+            "operation=\"validate\" client_id=client_id token=\"{}\" status=401",
+            fingerprint("my-access-token")
+        )));
+        assert!(logs.contains("kind=\"decode\""));
+        for secret in [
+            "s3cr3t",
+            "my-access-token",
+            "my-refresh-token",
+            "leaked-access-token",
+            "jostpf5q0puzmxmkba9iyug38kjtg",
+        ] {
+            assert!(!logs.contains(secret), "{secret} in {logs}");
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn records_operations() {
+        use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+        use crate::AuthorizationCode;
+
+        let recorder = DebuggingRecorder::new();
+        let snapshotter = recorder.snapshotter();
+
+        let oauth = oauth();
+        metrics::with_local_recorder(&recorder, || {
+            block_on(async {
+                oauth.app_access_token().await.unwrap();
+                oauth
+                    .validate_access_token(&AccessToken::from("token"))
+                    .await
+                    .unwrap_err();
+                oauth
+                    .exchange_code(AuthorizationCode::from("code"), "state".to_string())
+                    .await
+                    .unwrap_err();
+            })
+        });
+
+        let mut counters = Vec::new();
+        let mut histograms = 0;
+        for (key, _, _, value) in snapshotter.snapshot().into_vec() {
+            let labels: Vec<_> = key
+                .key()
+                .labels()
+                .map(|label| format!("{}={}", label.key(), label.value()))
+                .collect();
+            match value {
+                DebugValue::Counter(count) => {
+                    counters.push((key.key().name().to_string(), labels.join(","), count))
+                }
+                DebugValue::Histogram(values) => histograms += values.len(),
+                DebugValue::Gauge(_) => {}
+            }
+        }
+        counters.sort();
+
+        assert_eq!(histograms, 3);
+        assert_eq!(
+            counters,
+            [
+                (
+                    "twitch_oauth_errors_total".to_string(),
+                    "operation=exchange_code,kind=csrf_token_mismatch".to_string(),
+                    1
+                ),
+                (
+                    "twitch_oauth_errors_total".to_string(),
+                    "operation=validate,kind=oauth_error".to_string(),
+                    1
+                ),
+                (
+                    "twitch_oauth_requests_total".to_string(),
+                    "operation=client_credentials,outcome=success".to_string(),
+                    1
+                ),
+                (
+                    "twitch_oauth_requests_total".to_string(),
+                    "operation=exchange_code,outcome=error".to_string(),
+                    1
+                ),
+                (
+                    "twitch_oauth_requests_total".to_string(),
+                    "operation=validate,outcome=error".to_string(),
+                    1
+                ),
+            ]
+        );
+    }
+}
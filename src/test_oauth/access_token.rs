@@ -1,11 +1,11 @@
-use std::{collections::HashSet, ops::Deref};
+use std::ops::Deref;
 
-use asknothingx2_util::api::{preset, IntoRequestBuilder, Method};
-use reqwest::Client;
+use asknothingx2_util::api::preset;
 
 use crate::{
     error,
-    scope::{scopes_mut, Scope, ScopesMut},
+    scope::{scopes_mut, Scopes, ScopesMut},
+    transport::{HttpRequest, HttpResponse, HttpTransport, IntoHttpRequest},
     types::GrantType,
     AuthUrl, ClientId, ClientSecret, Error,
 };
@@ -16,7 +16,7 @@ pub struct TestAccessToken<'a> {
     client_secret: &'a ClientSecret,
     grant_type: GrantType,
     user_id: Option<&'a str>,
-    scopes: HashSet<Scope>,
+    scopes: Scopes,
     auth_url: AuthUrl,
 }
 
@@ -26,7 +26,7 @@ impl<'a> TestAccessToken<'a> {
         client_secret: &'a ClientSecret,
         grant_type: GrantType,
         user_id: Option<&'a str>,
-        scopes: HashSet<Scope>,
+        scopes: Scopes,
         auth_url: AuthUrl,
     ) -> Self {
         Self {
@@ -47,16 +47,12 @@ impl<'a> TestAccessToken<'a> {
         let client = preset::testing("twitch-oauth-test/1.0").build().unwrap();
         let resp = send(self, &client).await?;
 
-        crate::oauth::decode_response(resp).await
+        crate::oauth::decode_response(resp)
     }
 }
 
-impl IntoRequestBuilder for TestAccessToken<'_> {
-    type Error = Error;
-    fn into_request_builder(
-        self,
-        client: &reqwest::Client,
-    ) -> Result<reqwest::RequestBuilder, Self::Error> {
+impl IntoHttpRequest for TestAccessToken<'_> {
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
         let mut url = self.auth_url.to_url();
 
         let mut params = vec![
@@ -71,49 +67,35 @@ impl IntoRequestBuilder for TestAccessToken<'_> {
             params.push(("user_id", user_id));
         }
 
-        let scopes = self
-            .scopes
-            .clone()
-            .into_iter()
-            .map(String::from)
-            .collect::<Vec<String>>()
-            .join(" ");
+        let scopes = self.scopes.to_string();
 
         if !scopes.is_empty() {
             params.push(("scope", &scopes));
         }
 
         url.query_pairs_mut().extend_pairs(params);
-        Ok(client.request(Method::POST, url))
+        http::Request::post(url.as_str())
+            .body(Vec::new())
+            .map_err(error::validation::form_data)
     }
 }
 
-pub async fn send<T>(request: T, client: &Client) -> Result<reqwest::Response, T::Error>
+async fn send<T>(request: T, transport: &dyn HttpTransport) -> Result<HttpResponse, Error>
 where
-    T: IntoRequestBuilder<Error = Error>,
+    T: IntoHttpRequest,
 {
-    let resp = request
-        .into_request_builder(client)?
-        .send()
+    let resp = transport
+        .send(request.into_http_request()?)
         .await
         .map_err(error::network::request)?;
 
     if !resp.status().is_success() {
         let status = resp.status();
-        match resp.text().await {
-            Ok(body) => {
-                return Err(Error::with_message(
-                    error::Kind::OAuthError,
-                    format!("HTTP {status}: {body}"),
-                ));
-            }
-            Err(e) => {
-                return Err(Error::with_message(
-                    error::Kind::OAuthError,
-                    format!("HTTP {status} - Failed to read error response: {e}"),
-                ))
-            }
-        }
+        let body = String::from_utf8_lossy(resp.body());
+        return Err(Error::with_message(
+            error::Kind::OAuthError,
+            format!("HTTP {status}: {body}"),
+        ));
     }
 
     Ok(resp)
@@ -2,7 +2,7 @@ use asknothingx2_util::api;
 use serde::de::DeserializeOwned;
 use url::Url;
 
-use crate::{error, Error};
+use crate::{error, transport::HttpTransport, Error};
 
 use super::response::{Client, MockData, User};
 
@@ -46,14 +46,16 @@ impl MockApiUnits {
     }
 
     async fn send_json<T: DeserializeOwned>(&self, url: Url) -> Result<MockData<T>, Error> {
+        let request = http::Request::get(url.as_str())
+            .body(Vec::new())
+            .map_err(error::network::request)?;
         let resp = self
             .client
-            .get(url)
-            .send()
+            .send(request)
             .await
             .map_err(error::network::request)?;
 
-        crate::oauth::decode_response(resp).await
+        crate::oauth::decode_response(resp)
     }
 }
 
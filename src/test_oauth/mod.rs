@@ -48,15 +48,14 @@ mod access_token;
 
 pub use access_token::TestAccessToken;
 
-use std::collections::HashSet;
 use std::str::FromStr;
 
 use asknothingx2_util::api::preset;
 use url::Url;
 
 use crate::{
-    oauth::OauthFlow, request::ClientCredentialsRequest, types::GrantType, AuthUrl, Error,
-    TokenUrl, TwitchOauth,
+    oauth::OauthFlow, request::ClientCredentialsRequest, scope::Scopes, types::GrantType, AuthUrl,
+    Error, TokenUrl, TwitchOauth,
 };
 
 #[derive(Debug)]
@@ -101,7 +100,7 @@ where
             self.oauth.client_secret(),
             GrantType::UserToken,
             Some(user_id),
-            HashSet::new(),
+            Scopes::new(),
             AuthUrl::from_str(self.test_env.user_auth_url().as_ref()).unwrap(),
         )
     }
@@ -118,7 +117,7 @@ where
             ))
             .await?;
 
-        crate::oauth::decode_response(resp).await
+        crate::oauth::decode_response(resp)
     }
 }
 
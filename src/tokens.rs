@@ -1,8 +1,25 @@
 use asknothingx2_util::oauth::ClientId;
-use chrono::Utc;
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::{scope::Scope, AccessToken, RefreshToken};
+use crate::{clock, scope::Scopes, AccessToken, RefreshToken};
+
+/// Lifetime of refresh tokens issued to public clients (30 days)
+///
+/// <https://dev.twitch.tv/docs/authentication/refresh-tokens/#how-to-use-a-refresh-token>
+pub const PUBLIC_REFRESH_TOKEN_LIFETIME: i64 = 30 * 24 * 60 * 60;
+
+/// The kind of client a [`UserToken`] was issued to
+///
+/// Confidential clients authenticate with a `client_secret` and their refresh
+/// tokens don't expire. Public clients (e.g. the device code grant flow) have no
+/// secret, and their refresh tokens are single-use and expire after 30 days.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClientType {
+    #[default]
+    Confidential,
+    Public,
+}
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct UserToken {
@@ -10,16 +27,37 @@ pub struct UserToken {
     pub expires_in: u64,
     pub token_type: String,
     pub refresh_token: RefreshToken,
-    #[serde(default, deserialize_with = "deserialize_scopes")]
-    pub scope: Vec<Scope>,
+    #[serde(default)]
+    pub scope: Scopes,
     #[serde(default = "default_created_at")]
     pub created_at: i64,
+    #[serde(default)]
+    pub client_type: ClientType,
 }
 
 impl UserToken {
     pub fn is_expired(&self) -> bool {
-        let now = Utc::now().timestamp();
-        now >= (self.created_at + self.expires_in as i64)
+        clock::now() >= (self.created_at + self.expires_in as i64)
+    }
+
+    /// When the refresh token stops being accepted, as a unix timestamp
+    ///
+    /// Returns `None` for confidential clients, whose refresh tokens don't expire.
+    pub fn refresh_token_expires_at(&self) -> Option<i64> {
+        match self.client_type {
+            ClientType::Confidential => None,
+            ClientType::Public => Some(self.created_at + PUBLIC_REFRESH_TOKEN_LIFETIME),
+        }
+    }
+
+    pub fn is_refresh_token_expired(&self) -> bool {
+        self.refresh_token_expires_at()
+            .is_some_and(|expires_at| clock::now() >= expires_at)
+    }
+
+    pub(crate) fn with_client_type(mut self, client_type: ClientType) -> Self {
+        self.client_type = client_type;
+        self
     }
 }
 
@@ -30,12 +68,14 @@ impl Serialize for UserToken {
     {
         use serde::ser::SerializeStruct;
 
-        let mut state = serializer.serialize_struct("Token", 5)?;
+        let mut state = serializer.serialize_struct("Token", 7)?;
         state.serialize_field("access_token", &self.access_token)?;
         state.serialize_field("expires_in", &self.expires_in)?;
         state.serialize_field("token_type", &self.token_type)?;
         state.serialize_field("refresh_token", &self.refresh_token)?;
         state.serialize_field("scope", &self.scope)?;
+        state.serialize_field("created_at", &self.created_at)?;
+        state.serialize_field("client_type", &self.client_type)?;
         state.end()
     }
 }
@@ -45,8 +85,8 @@ impl Serialize for UserToken {
 pub struct TokenInfo {
     pub client_id: ClientId,
     pub login: String,
-    #[serde(default, deserialize_with = "deserialize_scopes")]
-    pub scopes: Vec<Scope>,
+    #[serde(default)]
+    pub scopes: Scopes,
     pub user_id: String,
     pub expires_in: u64,
 }
@@ -60,25 +100,17 @@ pub struct AppToken {
 }
 
 pub fn default_created_at() -> i64 {
-    Utc::now().timestamp()
-}
-
-fn deserialize_scopes<'de, D>(deserializer: D) -> Result<Vec<Scope>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let scopes: Vec<String> = Vec::deserialize(deserializer)?;
-    Ok(scopes
-        .into_iter()
-        .filter_map(|s| (!s.is_empty()).then(|| s.parse().ok()).flatten())
-        .collect())
+    clock::now()
 }
 
 #[cfg(test)]
 mod tests {
     use serde_json::json;
 
-    use crate::{TokenInfo, UserToken};
+    use crate::{
+        tokens::{ClientType, PUBLIC_REFRESH_TOKEN_LIFETIME},
+        TokenInfo, UserToken,
+    };
 
     #[test]
     fn user_token_deserialize_custom_scope() {
@@ -129,4 +161,59 @@ mod tests {
         let token: TokenInfo = serde_json::from_value(json).unwrap();
         assert_eq!(token.scopes.len(), 0);
     }
+
+    #[test]
+    fn public_client_refresh_token_expiry() {
+        let json = json!({
+            "access_token":"d19bb4cb705d1f0",
+            "refresh_token":"",
+            "expires_in":86399,
+            "scope":[],
+            "token_type":"bearer"
+        });
+
+        let token: UserToken = serde_json::from_value(json).unwrap();
+        assert_eq!(token.client_type, ClientType::Confidential);
+        assert_eq!(token.refresh_token_expires_at(), None);
+        assert!(!token.is_refresh_token_expired());
+
+        let mut token = token.with_client_type(ClientType::Public);
+        assert_eq!(
+            token.refresh_token_expires_at(),
+            Some(token.created_at + PUBLIC_REFRESH_TOKEN_LIFETIME)
+        );
+        assert!(!token.is_refresh_token_expired());
+
+        token.created_at -= PUBLIC_REFRESH_TOKEN_LIFETIME;
+        assert!(token.is_refresh_token_expired());
+
+        let value = serde_json::to_value(&token).unwrap();
+        assert_eq!(value["client_type"], "public");
+    }
+
+    #[test]
+    fn user_token_round_trip_keeps_expiry() {
+        let json = json!({
+            "access_token":"d19bb4cb705d1f0",
+            "refresh_token":"",
+            "expires_in":86399,
+            "scope":[],
+            "token_type":"bearer"
+        });
+
+        let mut token = serde_json::from_value::<UserToken>(json)
+            .unwrap()
+            .with_client_type(ClientType::Public);
+        token.created_at -= 86400;
+        assert!(token.is_expired());
+
+        let restored: UserToken =
+            serde_json::from_str(&serde_json::to_string(&token).unwrap()).unwrap();
+        assert_eq!(restored.created_at, token.created_at);
+        assert!(restored.is_expired());
+        assert_eq!(
+            restored.refresh_token_expires_at(),
+            token.refresh_token_expires_at()
+        );
+    }
 }
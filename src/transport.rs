@@ -0,0 +1,248 @@
+//! Pluggable HTTP transport
+//!
+//! Every OAuth request is built as an [`HttpRequest`] and handed to an
+//! [`HttpTransport`], which returns the status, headers and body as an
+//! [`HttpResponse`]. [`reqwest::Client`] is the default transport; hyper, a tower
+//! service or an in-memory fake can be used instead with
+//! [`TwitchOauth::with_transport`](crate::TwitchOauth::with_transport).
+//!
+//! # In-memory transport
+//! ```
+//! use twitch_oauth_token::{
+//!     transport::{self, HttpResponse},
+//!     TwitchOauth,
+//! };
+//!
+//! # async fn run() -> Result<(), twitch_oauth_token::Error> {
+//! let oauth = TwitchOauth::new("client_id", "client_secret").with_transport(
+//!     transport::from_fn(|request| async move {
+//!         assert_eq!(request.uri(), "https://id.twitch.tv/oauth2/token");
+//!         Ok(HttpResponse::new(
+//!             br#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#
+//!                 .to_vec(),
+//!         ))
+//!     }),
+//! );
+//!
+//! let token = oauth.app_access_token().await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+};
+
+use crate::{interceptor::Operation, Error};
+
+/// Request handed to an [`HttpTransport`]
+pub type HttpRequest = http::Request<Vec<u8>>;
+
+/// Response returned by an [`HttpTransport`]
+pub type HttpResponse = http::Response<Vec<u8>>;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Sends [`HttpRequest`]s
+///
+/// Non-success statuses are returned as responses, not errors; errors are for
+/// requests that got no response at all. They are reported as request errors
+/// (see [`Error::is_request_error`]).
+pub trait HttpTransport: Send + Sync {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, BoxError>>;
+}
+
+impl HttpTransport for reqwest::Client {
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, BoxError>> {
+        Box::pin(async move {
+            let resp = self.execute(reqwest::Request::try_from(request)?).await?;
+
+            let status = resp.status();
+            let version = resp.version();
+            let headers = resp.headers().clone();
+            let body = resp.bytes().await?;
+
+            let mut response = HttpResponse::new(body.to_vec());
+            *response.status_mut() = status;
+            *response.version_mut() = version;
+            *response.headers_mut() = headers;
+            Ok(response)
+        })
+    }
+}
+
+impl<T> HttpTransport for Arc<T>
+where
+    T: HttpTransport + ?Sized,
+{
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, BoxError>> {
+        (**self).send(request)
+    }
+}
+
+/// Converts a value into an [`HttpRequest`], see [`TwitchOauth::send`](crate::TwitchOauth::send)
+pub trait IntoHttpRequest {
+    fn into_http_request(self) -> Result<HttpRequest, Error>;
+
+    /// Reported to [`Interceptor`](crate::interceptor::Interceptor)s
+    fn operation(&self) -> Operation {
+        Operation::Other
+    }
+}
+
+impl IntoHttpRequest for HttpRequest {
+    fn into_http_request(self) -> Result<HttpRequest, Error> {
+        Ok(self)
+    }
+}
+
+/// Create a transport from an async function, see [`FromFn`]
+pub fn from_fn<F, Fut>(f: F) -> FromFn<F>
+where
+    F: Fn(HttpRequest) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<HttpResponse, BoxError>> + Send + 'static,
+{
+    FromFn(f)
+}
+
+/// Transport calling an async function, e.g. an in-memory fake in tests
+#[derive(Clone)]
+pub struct FromFn<F>(F);
+
+impl<F, Fut> HttpTransport for FromFn<F>
+where
+    F: Fn(HttpRequest) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<HttpResponse, BoxError>> + Send + 'static,
+{
+    fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, BoxError>> {
+        Box::pin((self.0)(request))
+    }
+}
+
+impl<F> Debug for FromFn<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("FromFn").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        str::FromStr,
+        sync::{Arc, Mutex},
+    };
+
+    use http::{header::CONTENT_TYPE, StatusCode};
+
+    use crate::{AccessToken, AppToken, RefreshToken, TokenUrl, TwitchOauth, WithMetadata};
+
+    use super::{from_fn, BoxError, HttpRequest, HttpResponse};
+
+    fn fake(
+        status: StatusCode,
+        body: &'static str,
+    ) -> (
+        Arc<Mutex<Vec<HttpRequest>>>,
+        impl super::HttpTransport + 'static,
+    ) {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        let transport = from_fn(move |request: HttpRequest| {
+            seen.lock().unwrap().push(request);
+            async move {
+                let mut response = HttpResponse::new(body.as_bytes().to_vec());
+                *response.status_mut() = status;
+                Ok::<_, BoxError>(response)
+            }
+        });
+        (requests, transport)
+    }
+
+    #[tokio::test]
+    async fn sends_through_transport() {
+        let (requests, transport) = fake(
+            StatusCode::OK,
+            r#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#,
+        );
+        let oauth = TwitchOauth::new("client_id", "client_secret").with_transport(transport);
+
+        let token = oauth.app_access_token().await.unwrap();
+        assert_eq!(token.access_token.secret(), "jostpf5q0puzmxmkba9iyug38kjtg");
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].method(), "POST");
+        assert_eq!(requests[0].uri(), "https://id.twitch.tv/oauth2/token");
+        assert_eq!(
+            requests[0].headers()[CONTENT_TYPE],
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(
+            requests[0].body(),
+            b"client_id=client_id&client_secret=client_secret&grant_type=client_credentials"
+        );
+    }
+
+    #[tokio::test]
+    async fn owned_requests() {
+        let (requests, transport) = fake(
+            StatusCode::OK,
+            r#"{"access_token":"jostpf5q0puzmxmkba9iyug38kjtg","expires_in":5011271,"token_type":"bearer"}"#,
+        );
+        let oauth = TwitchOauth::new("client_id", "client_secret").with_transport(transport);
+
+        let request = oauth.app_access_token_request().into_owned();
+        let retry = request.clone();
+        let handle = tokio::spawn({
+            let oauth = oauth.clone();
+            async move { WithMetadata::<AppToken>::from_response(oauth.send(request).await?) }
+        });
+        let token = handle.await.unwrap().unwrap();
+        assert_eq!(token.access_token.secret(), "jostpf5q0puzmxmkba9iyug38kjtg");
+        oauth.send(retry).await.unwrap();
+
+        // Reconfiguring a clone leaves the original untouched
+        let custom = oauth
+            .clone()
+            .with_token_url(TokenUrl::from_str("http://localhost/token").unwrap());
+        custom
+            .send(
+                custom
+                    .refresh_request(RefreshToken::from("refresh"))
+                    .into_owned(),
+            )
+            .await
+            .unwrap();
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[0].body(), requests[1].body());
+        assert_eq!(requests[1].uri(), "https://id.twitch.tv/oauth2/token");
+        assert_eq!(requests[2].uri(), "http://localhost/token");
+    }
+
+    #[tokio::test]
+    async fn errors() {
+        let (_, transport) = fake(
+            StatusCode::UNAUTHORIZED,
+            r#"{"status":401,"message":"invalid access token"}"#,
+        );
+        let oauth = TwitchOauth::new("client_id", "client_secret").with_transport(transport);
+
+        let err = oauth
+            .validate_access_token(&AccessToken::from("token"))
+            .await
+            .unwrap_err();
+        assert!(err.is_oauth_error());
+        assert_eq!(err.status_code(), Some(401));
+
+        let oauth = oauth.with_transport(from_fn(|_| async {
+            Err::<HttpResponse, BoxError>("connection refused".into())
+        }));
+        let err = oauth.app_access_token().await.unwrap_err();
+        assert!(err.is_request_error());
+    }
+}
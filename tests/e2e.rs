@@ -2,7 +2,7 @@
 mod help;
 
 use help::{config, server, setup, token};
-use twitch_oauth_token::AuthCallback;
+use twitch_oauth_token::{AuthCallback, ClientType, DeviceSessionManager};
 
 #[tokio::test]
 async fn client_credentials_grant_flow() {
@@ -48,6 +48,69 @@ async fn refresh_access_token() {
     assert_eq!(token.access_token.secret(), token::access().secret());
 }
 
+#[tokio::test]
+async fn device_code_grant_flow() {
+    let (server, oauth) = setup::oauth_server().await;
+
+    server::device_code_grant_flow(&server, "user%3Awrite%3Achat").await;
+
+    let mut device = oauth.device_flow();
+    device.scopes_mut().send_chat_message();
+
+    let resp = device.request().await.unwrap();
+    assert_eq!(token::device_code(), resp.device_code.secret());
+
+    let token = device.poll(resp).await.unwrap();
+    assert_eq!(token::access().secret(), token.access_token.secret());
+    assert_eq!(token.client_type, ClientType::Public);
+}
+
+// Without the `tokio` feature, poll loops run on threads outside the tokio
+// runtime the reqwest transport needs.
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn device_session_manager() {
+    let (server, oauth) = setup::oauth_server().await;
+
+    server::device_code_grant_flow(&server, "user%3Awrite%3Achat").await;
+
+    let (manager, mut events) = DeviceSessionManager::new(oauth.device_flow(), 1);
+
+    let mut device = oauth.device_flow();
+    device.scopes_mut().send_chat_message();
+
+    let resp = manager.start("streamer-1", device.clone()).await.unwrap();
+    assert_eq!(token::device_code(), resp.device_code.secret());
+
+    let err = manager.start("streamer-2", device).await.unwrap_err();
+    assert!(err.is_device_code_error());
+
+    let event = events.recv().await.unwrap();
+    assert_eq!(event.id, "streamer-1");
+    assert_eq!(
+        token::access().secret(),
+        event.result.unwrap().access_token.secret()
+    );
+    assert!(manager.is_empty());
+    assert!(!manager.cancel("streamer-1"));
+}
+
+#[tokio::test]
+async fn refresh_public_access_token() {
+    let (server, oauth) = setup::oauth_server().await;
+
+    server::refresh_public_access_token(&server).await;
+
+    let token = oauth
+        .device_flow()
+        .refresh_access_token(token::refresh())
+        .await
+        .unwrap();
+    assert_eq!(token.access_token.secret(), token::access().secret());
+    assert_eq!(token.client_type, ClientType::Public);
+    assert!(token.refresh_token_expires_at().is_some());
+}
+
 #[tokio::test]
 pub async fn validate_access_token() {
     let (server, oauth) = setup::oauth_server().await;
@@ -66,3 +129,115 @@ pub async fn revoke_access_token() {
 
     oauth.revoke_access_token(&token::access()).await.unwrap();
 }
+
+#[cfg(feature = "login")]
+#[tokio::test]
+async fn interactive_login() {
+    let (server, oauth) = setup::oauth_server_with_redirect(help::url::loopback()).await;
+    server::authorization_code_grant_flow(&server, &oauth, "").await;
+    let redirect = oauth.get_redirect_uri().to_url();
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let token = oauth
+        .interactive_login()
+        .with_url_handler(move |auth_url| {
+            let state = auth_url
+                .query_pairs()
+                .find(|(k, _)| k == "state")
+                .map(|(_, v)| v.to_string())
+                .unwrap();
+            let callback = redirect
+                .join(&format!("/?code={}&state={}", token::code(), state))
+                .unwrap();
+            let favicon = redirect.join("/favicon.ico").unwrap();
+
+            tokio::spawn(async move {
+                let client = config::client();
+                let favicon = client.get(favicon).send().await.unwrap();
+                assert_eq!(404, favicon.status().as_u16());
+
+                let resp = client.get(callback).send().await.unwrap();
+                let _ = tx.send((resp.status().as_u16(), resp.text().await.unwrap()));
+            });
+        })
+        .with_success_page("logged in")
+        .with_timeout(std::time::Duration::from_secs(5))
+        .run()
+        .await
+        .unwrap();
+
+    assert_eq!(token::access().secret(), token.access_token.secret());
+    assert_eq!((200, "logged in".to_string()), rx.await.unwrap());
+}
+
+#[cfg(feature = "login")]
+#[tokio::test]
+async fn interactive_login_idle_connection() {
+    let (server, oauth) = setup::oauth_server_with_redirect(help::url::loopback()).await;
+    server::authorization_code_grant_flow(&server, &oauth, "").await;
+    let redirect = oauth.get_redirect_uri().to_url();
+
+    let token = oauth
+        .interactive_login()
+        .with_url_handler(move |auth_url| {
+            let state = auth_url
+                .query_pairs()
+                .find(|(k, _)| k == "state")
+                .map(|(_, v)| v.to_string())
+                .unwrap();
+            let callback = redirect
+                .join(&format!("/?code={}&state={}", token::code(), state))
+                .unwrap();
+            let addr = format!(
+                "{}:{}",
+                redirect.host_str().unwrap(),
+                redirect.port().unwrap()
+            );
+
+            tokio::spawn(async move {
+                // Like a browser preconnect: connected first, never sends a request
+                let idle = tokio::net::TcpStream::connect(addr).await.unwrap();
+                let resp = config::client().get(callback).send().await.unwrap();
+                assert_eq!(200, resp.status().as_u16());
+                drop(idle);
+            });
+        })
+        .with_timeout(std::time::Duration::from_secs(10))
+        .run()
+        .await
+        .unwrap();
+
+    assert_eq!(token::access().secret(), token.access_token.secret());
+}
+
+#[cfg(feature = "login")]
+#[tokio::test]
+async fn interactive_login_denied() {
+    use twitch_oauth_token::TwitchOauth;
+
+    let oauth = TwitchOauth::new(config::client_id(), config::client_secret())
+        .with_client(config::client())
+        .with_redirect_uri(help::url::loopback());
+    let callback = oauth
+        .get_redirect_uri()
+        .to_url()
+        .join("/?error=access_denied&error_description=<denied>")
+        .unwrap();
+
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    let err = oauth
+        .interactive_login()
+        .with_url_handler(move |_| {
+            tokio::spawn(async move {
+                let resp = config::client().get(callback).send().await.unwrap();
+                let _ = tx.send(resp.text().await.unwrap());
+            });
+        })
+        .with_failure_page("failed: {error}")
+        .run()
+        .await
+        .unwrap_err();
+
+    assert!(err.is_oauth_error());
+    assert_eq!("failed: &lt;denied&gt;", rx.await.unwrap());
+}
@@ -1,5 +1,7 @@
 pub mod url {
-    use twitch_oauth_token::{AuthUrl, RedirectUrl, RevocationUrl, TokenUrl, ValidateUrl};
+    use twitch_oauth_token::{
+        AuthUrl, DeviceUrl, RedirectUrl, RevocationUrl, TokenUrl, ValidateUrl,
+    };
 
     use std::str::FromStr;
 
@@ -15,6 +17,12 @@ pub mod url {
         RedirectUrl::from_str("http://localhost:3000").unwrap()
     }
 
+    /// Redirect URI on a free loopback port, for tests that listen on it
+    pub fn loopback() -> RedirectUrl {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        RedirectUrl::from_str(&format!("http://{}", listener.local_addr().unwrap())).unwrap()
+    }
+
     pub fn revoke(url: &str) -> RevocationUrl {
         RevocationUrl::from_str(&format!("{url}/oauth2/revoke")).unwrap()
     }
@@ -22,6 +30,10 @@ pub mod url {
     pub fn validate(url: &str) -> ValidateUrl {
         ValidateUrl::from_str(&format!("{url}/oauth2/validate")).unwrap()
     }
+
+    pub fn device(url: &str) -> DeviceUrl {
+        DeviceUrl::from_str(&format!("{url}/oauth2/device")).unwrap()
+    }
 }
 
 pub mod token {
@@ -38,16 +50,26 @@ pub mod token {
     pub fn code() -> String {
         "gulfwdmys5lsm6qyz4xiz9q32l10".to_string()
     }
+
+    pub fn device_code() -> String {
+        "ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91".to_string()
+    }
 }
 
 #[cfg(feature = "test")]
 pub mod setup {
-    use twitch_oauth_token::{TwitchOauth, UserAuth};
+    use twitch_oauth_token::{RedirectUrl, TwitchOauth, UserAuth};
     use wiremock::MockServer;
 
     use super::{config, url};
 
     pub async fn oauth_server() -> (MockServer, TwitchOauth<UserAuth>) {
+        oauth_server_with_redirect(url::redirect()).await
+    }
+
+    pub async fn oauth_server_with_redirect(
+        redirect: RedirectUrl,
+    ) -> (MockServer, TwitchOauth<UserAuth>) {
         let server = MockServer::start().await;
 
         let oauth = TwitchOauth::new(config::client_id(), config::client_secret())
@@ -56,7 +78,8 @@ pub mod setup {
             .with_auth_url(url::auth(&server.uri()))
             .with_validate_url(url::validate(&server.uri()))
             .with_revoke_url(url::revoke(&server.uri()))
-            .with_redirect_uri(url::redirect());
+            .with_device_url(url::device(&server.uri()))
+            .with_redirect_uri(redirect);
 
         (server, oauth)
     }
@@ -80,6 +103,7 @@ pub mod config {
 }
 
 pub mod validate {
+    use twitch_oauth_token::RedirectUrl;
 
     use super::config;
 
@@ -91,8 +115,12 @@ pub mod validate {
         format!("client_secret={}", config::client_secret())
     }
 
-    pub fn form_redirect() -> String {
-        "redirect_uri=http%3A%2F%2Flocalhost%3A3000".to_string()
+    pub fn form_redirect(redirect: &RedirectUrl) -> String {
+        format!(
+            "redirect_uri={}",
+            ::url::form_urlencoded::byte_serialize(redirect.as_str().as_bytes())
+                .collect::<String>()
+        )
     }
 
     pub fn form_grant_type(kind: &str) -> String {
@@ -109,6 +137,14 @@ pub mod validate {
     pub fn form_token(token: &str) -> String {
         format!("token={token}")
     }
+
+    pub fn form_device_code(code: &str) -> String {
+        format!("device_code={code}")
+    }
+
+    pub fn form_scopes(scopes: &str) -> String {
+        format!("scopes={scopes}")
+    }
 }
 
 pub mod server {
@@ -116,10 +152,10 @@ pub mod server {
     use twitch_oauth_token::{TwitchOauth, UserAuth};
     use wiremock::{
         matchers::{body_string_contains, header, method, path, query_param},
-        Mock, MockServer, ResponseTemplate,
+        Mock, MockServer, Request, ResponseTemplate,
     };
 
-    use super::{config, token, url, validate};
+    use super::{config, token, validate};
 
     pub async fn client_credentials(server: &MockServer) {
         Mock::given(method("POST"))
@@ -155,7 +191,10 @@ pub mod server {
         Mock::given(method("GET"))
             .and(path("/oauth2/authorize"))
             .and(query_param("client_id", config::client_id()))
-            .and(query_param("redirect_uri", url::redirect().to_string()))
+            .and(query_param(
+                "redirect_uri",
+                oauth.get_redirect_uri().to_string(),
+            ))
             .and(query_param("response_type", "code"))
             .and(query_param("state", state.clone()))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
@@ -176,7 +215,9 @@ pub mod server {
             .and(body_string_contains(validate::form_grant_type(
                 "authorization_code",
             )))
-            .and(body_string_contains(validate::form_redirect()))
+            .and(body_string_contains(validate::form_redirect(
+                oauth.get_redirect_uri(),
+            )))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "access_token": token::access(),
                 "expires_in": 14124,
@@ -220,6 +261,90 @@ pub mod server {
             .await;
     }
 
+    pub async fn refresh_public_access_token(server: &MockServer) {
+        Mock::given(method("POST"))
+            .and(path("/oauth2/token"))
+            .and(header("accept", "application/json"))
+            .and(header("content-type", "application/x-www-form-urlencoded"))
+            .and(body_string_contains(validate::form_client_id()))
+            .and(|req: &Request| !String::from_utf8_lossy(&req.body).contains("client_secret"))
+            .and(body_string_contains(validate::form_grant_type(
+                "refresh_token",
+            )))
+            .and(body_string_contains(validate::form_refresh_token()))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": token::access(),
+                "expires_in": 14124,
+                "refresh_token": token::refresh(),
+                "scope": [
+                    "channel:moderate",
+                    "chat:edit",
+                    "chat:read"
+                ],
+                "token_type": "bearer"
+            })))
+            .mount(server)
+            .await;
+    }
+
+    pub async fn device_code_grant_flow(server: &MockServer, scopes: &str) {
+        Mock::given(method("POST"))
+            .and(path("/oauth2/device"))
+            .and(header("accept", "application/json"))
+            .and(header("content-type", "application/x-www-form-urlencoded"))
+            .and(body_string_contains(validate::form_client_id()))
+            .and(body_string_contains(validate::form_scopes(scopes)))
+            .and(|req: &Request| {
+                !String::from_utf8_lossy(&req.body).contains("client_secret")
+            })
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "device_code": token::device_code(),
+                "expires_in": 1800,
+                "interval": 0,
+                "user_code": "ABCDEFGH",
+                "verification_uri": "https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH"
+            })))
+            .mount(server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth2/token"))
+            .and(body_string_contains(validate::form_device_code(
+                &token::device_code(),
+            )))
+            .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+                "status": 400,
+                "message": "authorization_pending"
+            })))
+            .up_to_n_times(1)
+            .mount(server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth2/token"))
+            .and(header("accept", "application/json"))
+            .and(header("content-type", "application/x-www-form-urlencoded"))
+            .and(body_string_contains(validate::form_client_id()))
+            .and(body_string_contains(validate::form_scopes(scopes)))
+            .and(body_string_contains(validate::form_device_code(
+                &token::device_code(),
+            )))
+            .and(body_string_contains(validate::form_grant_type(
+                "urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Adevice_code",
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "access_token": token::access(),
+                "expires_in": 14124,
+                "refresh_token": token::refresh(),
+                "scope": [
+                    "user:write:chat"
+                ],
+                "token_type": "bearer"
+            })))
+            .mount(server)
+            .await;
+    }
+
     pub async fn validate_access_token(server: &MockServer) {
         Mock::given(method("GET"))
             .and(path("oauth2/validate"))
@@ -1,6 +1,9 @@
 use std::str::FromStr;
 
-use twitch_oauth_token::{AuthorizationCode, RedirectUrl, TwitchOauth};
+use serde_json::json;
+use twitch_oauth_token::{
+    AuthorizationCode, ClientId, DeviceAuthResponse, RedirectUrl, Scope, TwitchOauth, UserToken,
+};
 
 #[tokio::test]
 async fn csrf_validation_failure() {
@@ -19,3 +22,86 @@ async fn csrf_validation_failure() {
         Some("CSRF token validation failed - possible security issue")
     );
 }
+
+#[test]
+fn device_auth_response_round_trip() {
+    let resp: DeviceAuthResponse = serde_json::from_value(json!({
+        "device_code": "ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91",
+        "expires_in": 1800,
+        "interval": 5,
+        "user_code": "ABCDEFGH",
+        "verification_uri": "https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH",
+        "created_at": 1700000000
+    }))
+    .unwrap();
+
+    let value = serde_json::to_value(&resp).unwrap();
+    assert_eq!(value["created_at"], 1700000000);
+    assert_eq!(
+        value["device_code"],
+        "ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91"
+    );
+
+    let restored: DeviceAuthResponse = serde_json::from_value(value).unwrap();
+    assert_eq!(restored.created_at, resp.created_at);
+    assert_eq!(restored.expires_at(), 1700000000 + 1800);
+    assert!(restored.is_expired());
+}
+
+#[tokio::test]
+async fn device_auth_resume_expired_session() {
+    let resp: DeviceAuthResponse = serde_json::from_value(json!({
+        "device_code": "ike3GM8QIdYZs43KdrWPIO36LofILoCyFEzjlQ91",
+        "expires_in": 1800,
+        "interval": 5,
+        "user_code": "ABCDEFGH",
+        "verification_uri": "https://www.twitch.tv/activate?public=true&device-code=ABCDEFGH",
+        "created_at": 1700000000
+    }))
+    .unwrap();
+
+    let device = TwitchOauth::device_auth(ClientId::from("client_id"));
+    let error = device.resume(resp).await.unwrap_err();
+
+    assert!(error.is_device_code_error());
+    assert_eq!(error.message(), Some("device code expired"));
+}
+
+#[test]
+fn unknown_scopes_round_trip() {
+    let token: UserToken = serde_json::from_value(json!({
+        "access_token": "d19bb4cb705d1f0",
+        "refresh_token": "",
+        "expires_in": 86399,
+        "scope": ["chat:read", "channel:manage:future_feature"],
+        "token_type": "bearer"
+    }))
+    .unwrap();
+
+    assert!(token.scope.contains(Scope::ChatRead));
+    assert!(token.scope.contains_str("channel:manage:future_feature"));
+
+    let value = serde_json::to_value(&token).unwrap();
+    assert_eq!(
+        value["scope"],
+        json!(["channel:manage:future_feature", "chat:read"])
+    );
+
+    let oauth = TwitchOauth::new("client_id", "client_secret")
+        .with_redirect_uri(RedirectUrl::from_str("http://localhost:3000").unwrap());
+    let mut auth_request = oauth.authorization_url();
+    auth_request
+        .scopes_mut()
+        .append(&token.scope)
+        .push("user:read:future_feature");
+
+    let url = auth_request.url();
+    let scope = url
+        .query_pairs()
+        .find(|(k, _)| k == "scope")
+        .map(|(_, v)| v.to_string());
+    assert_eq!(
+        scope.as_deref(),
+        Some("channel:manage:future_feature chat:read user:read:future_feature")
+    );
+}